@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why an acknowledgement of data packets is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AcknowledgementError {
+    /// The agent id is empty.
+    EmptyAgentId,
+    /// The capture session id is empty.
+    EmptyCaptureSessionId,
+    /// The range of sequence numbers starts below `0` or is empty.
+    InvalidRange { start: i64, end: i64 },
+    /// No range of sequence numbers is set.
+    NoRanges,
+}
+
+impl fmt::Display for AcknowledgementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AcknowledgementError::EmptyAgentId => write!(f, "the agent id cannot be empty"),
+            AcknowledgementError::EmptyCaptureSessionId => write!(f, "the capture session id cannot be empty"),
+            AcknowledgementError::InvalidRange { start, end } => write!(f, "`{start}..{end}` is not a range of sequence numbers, it has to start at 0 or above and end after its start"),
+            AcknowledgementError::NoRanges => write!(f, "at least one range of sequence numbers has to be set"),
+        }
+    }
+}
+
+impl Error for AcknowledgementError { }
\ No newline at end of file
@@ -0,0 +1,225 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_config_error::AgentConfigError;
+
+
+const DATA_TYPE: &str = "agent_config";
+const VERSION: u32 = 1;
+
+/// The snap length agents capture whole packets with, unless configured otherwise.
+pub const DEFAULT_SNAP_LENGTH: i64 = 65_535;
+/// The largest snap length, the one libpcap caps captures at.
+pub const MAX_SNAP_LENGTH: i64 = 262_144;
+/// The number of data packets agents upload at once, unless configured otherwise.
+pub const DEFAULT_UPLOAD_BATCH_SIZE: i64 = 100;
+pub const MAX_UPLOAD_BATCH_SIZE: i64 = 10_000;
+
+/// A capture configuration the hub pushes to an agent, which applies it without being redeployed
+/// and answers with an `AgentConfigAckDTO`.
+///
+/// Configurations are numbered by the hub, so that acknowledgements tell which one the agent runs.
+/// Decoding does not validate them, so that an agent can report every error of a configuration it rejects.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgentConfigDTO {
+    agent_id: String,
+    config_version: i64,
+    interfaces: Vec<String>,
+    bpf_filter: Option<String>,
+    snap_length: i64,
+    promiscuous: bool,
+    sampling_rate: i64,
+    upload_batch_size: i64,
+}
+impl API for AgentConfigDTO { }
+
+impl AgentConfigDTO {
+    /// The configuration numbered `config_version` capturing every packet of `interfaces` whole, in non-promiscuous mode,
+    /// which the `with_` methods change.
+    pub fn new(agent_id: &str, config_version: i64, interfaces: &[&str]) -> Self {
+        AgentConfigDTO {
+            agent_id: agent_id.into(),
+            config_version,
+            interfaces: interfaces.iter().map(|interface| interface.to_string()).collect(),
+            bpf_filter: None,
+            snap_length: DEFAULT_SNAP_LENGTH,
+            promiscuous: false,
+            sampling_rate: 1,
+            upload_batch_size: DEFAULT_UPLOAD_BATCH_SIZE,
+        }
+    }
+
+    /// Captures only the packets matching the BPF filter expression `bpf_filter`, e.g. `tcp port 80`.
+    pub fn with_bpf_filter(self, bpf_filter: &str) -> Self {
+        AgentConfigDTO { bpf_filter: Some(bpf_filter.into()), ..self }
+    }
+
+    /// Captures the first `snap_length` bytes of each packet.
+    pub fn with_snap_length(self, snap_length: i64) -> Self {
+        AgentConfigDTO { snap_length, ..self }
+    }
+
+    pub fn with_promiscuous(self, promiscuous: bool) -> Self {
+        AgentConfigDTO { promiscuous, ..self }
+    }
+
+    /// Captures one packet out of `sampling_rate`.
+    pub fn with_sampling_rate(self, sampling_rate: i64) -> Self {
+        AgentConfigDTO { sampling_rate, ..self }
+    }
+
+    /// Uploads data packets by batches of `upload_batch_size`.
+    pub fn with_upload_batch_size(self, upload_batch_size: i64) -> Self {
+        AgentConfigDTO { upload_batch_size, ..self }
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_config_version(&self) -> i64 {
+        self.config_version
+    }
+
+    pub fn get_interfaces(&self) -> &[String] {
+        &self.interfaces
+    }
+
+    pub fn get_bpf_filter(&self) -> Option<&str> {
+        self.bpf_filter.as_deref()
+    }
+
+    pub fn get_snap_length(&self) -> i64 {
+        self.snap_length
+    }
+
+    pub fn is_promiscuous(&self) -> bool {
+        self.promiscuous
+    }
+
+    pub fn get_sampling_rate(&self) -> i64 {
+        self.sampling_rate
+    }
+
+    pub fn get_upload_batch_size(&self) -> i64 {
+        self.upload_batch_size
+    }
+
+    /// Every error of the configuration, empty if it is valid. The BPF filter expression is only checked not to be empty,
+    /// the agent compiles it for its interfaces.
+    pub fn get_errors(&self) -> Vec<AgentConfigError> {
+        let mut errors = Vec::new();
+        if self.agent_id.is_empty() {
+            errors.push(AgentConfigError::EmptyAgentId);
+        }
+        if self.config_version < 0 {
+            errors.push(AgentConfigError::NegativeConfigVersion(self.config_version));
+        }
+
+        if self.interfaces.is_empty() {
+            errors.push(AgentConfigError::NoInterfaces);
+        }
+        if self.interfaces.iter().any(String::is_empty) {
+            errors.push(AgentConfigError::EmptyInterfaceName);
+        }
+        for (index, interface) in self.interfaces.iter().enumerate() {
+            if !interface.is_empty() && self.interfaces[..index].contains(interface) {
+                errors.push(AgentConfigError::DuplicateInterface(interface.clone()));
+            }
+        }
+
+        if self.bpf_filter.as_deref().is_some_and(|bpf_filter| bpf_filter.trim().is_empty()) {
+            errors.push(AgentConfigError::EmptyBpfFilter);
+        }
+        if !(1..=MAX_SNAP_LENGTH).contains(&self.snap_length) {
+            errors.push(AgentConfigError::InvalidSnapLength { snap_length: self.snap_length, max_snap_length: MAX_SNAP_LENGTH });
+        }
+        if self.sampling_rate < 1 {
+            errors.push(AgentConfigError::InvalidSamplingRate(self.sampling_rate));
+        }
+        if !(1..=MAX_UPLOAD_BATCH_SIZE).contains(&self.upload_batch_size) {
+            errors.push(AgentConfigError::InvalidUploadBatchSize {
+                upload_batch_size: self.upload_batch_size,
+                max_upload_batch_size: MAX_UPLOAD_BATCH_SIZE,
+            });
+        }
+
+        errors
+    }
+
+    /// Checks the configuration is valid, reporting its first error, e.g. before the hub pushes it.
+    pub fn validate(&self) -> Result<(), AgentConfigError> {
+        match self.get_errors().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use crate::api::agent_config::AgentConfigDTO;
+    use crate::api::agent_config::DEFAULT_SNAP_LENGTH;
+    use crate::api::agent_config::MAX_SNAP_LENGTH;
+    use crate::api::agent_config::MAX_UPLOAD_BATCH_SIZE;
+    use crate::api::agent_config_error::AgentConfigError;
+
+    fn get_config() -> AgentConfigDTO {
+        AgentConfigDTO::new("agent-1", 7, &["eth0", "eth1"])
+            .with_bpf_filter("tcp port 80")
+            .with_snap_length(1500)
+            .with_promiscuous(true)
+            .with_sampling_rate(10)
+            .with_upload_batch_size(500)
+    }
+
+    #[test]
+    fn endec_agent_config() {
+        let config = get_config();
+        assert_eq!(config, AgentConfigDTO::decode(&config.encode()));
+        assert_eq!(config.validate(), Ok(()));
+
+        let config = AgentConfigDTO::new("agent-1", 0, &["eth0"]);
+        assert_eq!(config.get_snap_length(), DEFAULT_SNAP_LENGTH);
+        assert!(!config.is_promiscuous());
+        assert_eq!(config, AgentConfigDTO::decode(&config.encode()));
+    }
+
+    #[test]
+    fn invalid_agent_config_is_decoded_to_report_every_error() {
+        let config = AgentConfigDTO::new("agent-1", 7, &["eth0", "", "eth0"])
+            .with_bpf_filter(" ")
+            .with_snap_length(MAX_SNAP_LENGTH + 1)
+            .with_sampling_rate(0)
+            .with_upload_batch_size(0);
+
+        let decoded = AgentConfigDTO::decode(&config.encode());
+        assert_eq!(decoded, config);
+        assert_eq!(decoded.get_errors(), vec![
+            AgentConfigError::EmptyInterfaceName,
+            AgentConfigError::DuplicateInterface("eth0".into()),
+            AgentConfigError::EmptyBpfFilter,
+            AgentConfigError::InvalidSnapLength { snap_length: MAX_SNAP_LENGTH + 1, max_snap_length: MAX_SNAP_LENGTH },
+            AgentConfigError::InvalidSamplingRate(0),
+            AgentConfigError::InvalidUploadBatchSize { upload_batch_size: 0, max_upload_batch_size: MAX_UPLOAD_BATCH_SIZE },
+        ]);
+        assert_eq!(decoded.validate(), Err(AgentConfigError::EmptyInterfaceName));
+        assert_eq!(AgentConfigDTO::new("agent-1", 7, &[]).validate(), Err(AgentConfigError::NoInterfaces));
+    }
+
+    #[test]
+    fn test_getting_data_types() {
+        let config = get_config();
+        assert_eq!(config.get_type(), AgentConfigDTO::get_data_type());
+        assert_eq!(config.get_type(), super::DATA_TYPE);
+    }
+}
\ No newline at end of file
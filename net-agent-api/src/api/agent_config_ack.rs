@@ -0,0 +1,144 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_config::AgentConfigDTO;
+use super::agent_config_error::AgentConfigError;
+use super::config_error::ConfigErrorDTO;
+
+
+const DATA_TYPE: &str = "agent_config_ack";
+const VERSION: u32 = 1;
+
+/// Sent by an agent in answer to an `AgentConfigDTO`: the configuration is applied if no error is listed,
+/// otherwise the agent keeps running the configuration it had.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgentConfigAckDTO {
+    agent_id: String,
+    config_version: i64,
+    errors: Vec<ConfigErrorDTO>,
+}
+impl API for AgentConfigAckDTO { }
+
+impl AgentConfigAckDTO {
+    pub fn new(agent_id: &str, config_version: i64, errors: &[ConfigErrorDTO]) -> Self {
+        AgentConfigAckDTO {
+            agent_id: agent_id.into(),
+            config_version,
+            errors: errors.to_vec(),
+        }
+    }
+
+    /// Answers `config`, rejecting it with its errors if it is invalid.
+    /// Fails when `config` has no agent id or a negative version, as its ack would not be valid either.
+    pub fn for_config(config: &AgentConfigDTO) -> Result<Self, AgentConfigError> {
+        let ack = AgentConfigAckDTO {
+            agent_id: config.get_agent_id().into(),
+            config_version: config.get_config_version(),
+            errors: config.get_errors().iter().map(ConfigErrorDTO::from).collect(),
+        };
+        ack.validate()?;
+        Ok(ack)
+    }
+
+    /// Adds the errors the agent found applying the configuration, e.g. an interface its host does not have.
+    pub fn with_errors(mut self, errors: &[ConfigErrorDTO]) -> Self {
+        self.errors.extend_from_slice(errors);
+        self
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_config_version(&self) -> i64 {
+        self.config_version
+    }
+
+    pub fn get_errors(&self) -> &[ConfigErrorDTO] {
+        &self.errors
+    }
+
+    /// Whether the agent applied the configuration.
+    pub fn is_accepted(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Checks the agent id is set and the configuration version is not negative. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AgentConfigError> {
+        if self.agent_id.is_empty() {
+            return Err(AgentConfigError::EmptyAgentId);
+        }
+
+        match self.config_version < 0 {
+            true => Err(AgentConfigError::NegativeConfigVersion(self.config_version)),
+            false => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::agent_config::AgentConfigDTO;
+    use crate::api::agent_config_ack::AgentConfigAckDTO;
+    use crate::api::agent_config_error::AgentConfigError;
+    use crate::api::config_error::ConfigErrorDTO;
+
+    #[test]
+    fn endec_agent_config_ack() {
+        let ack = AgentConfigAckDTO::new("agent-1", 7, &[ConfigErrorDTO::new("interfaces", "the interface `eth9` does not exist")]);
+        assert_eq!(ack, AgentConfigAckDTO::decode(&ack.encode()));
+
+        let ack = AgentConfigAckDTO::new("agent-1", 7, &[]);
+        assert_eq!(ack, AgentConfigAckDTO::decode(&ack.encode()));
+    }
+
+    #[test]
+    fn valid_config_is_accepted() {
+        let ack = AgentConfigAckDTO::for_config(&AgentConfigDTO::new("agent-1", 7, &["eth0"])).unwrap();
+        assert_eq!(ack.get_config_version(), 7);
+        assert!(ack.is_accepted());
+
+        let ack = ack.with_errors(&[ConfigErrorDTO::new("interfaces", "the interface `eth0` does not exist")]);
+        assert!(!ack.is_accepted());
+    }
+
+    #[test]
+    fn invalid_config_is_rejected_with_its_errors() {
+        let config = AgentConfigDTO::new("agent-1", 7, &["eth0"]).with_sampling_rate(0).with_upload_batch_size(-1);
+        let ack = AgentConfigAckDTO::for_config(&config).unwrap();
+
+        assert!(!ack.is_accepted());
+        assert_eq!(
+            ack.get_errors().iter().map(ConfigErrorDTO::get_field).collect::<Vec<_>>(),
+            ["sampling_rate", "upload_batch_size"],
+        );
+    }
+
+    #[test]
+    fn config_without_a_valid_ack_is_not_answered() {
+        assert_eq!(AgentConfigAckDTO::for_config(&AgentConfigDTO::new("", 7, &["eth0"])), Err(AgentConfigError::EmptyAgentId));
+        assert_eq!(
+            AgentConfigAckDTO::for_config(&AgentConfigDTO::new("agent-1", -1, &["eth0"])),
+            Err(AgentConfigError::NegativeConfigVersion(-1)),
+        );
+    }
+
+    #[test]
+    fn invalid_agent_config_ack_is_not_decoded() {
+        assert_eq!(AgentConfigAckDTO::new("", 7, &[]).validate(), Err(AgentConfigError::EmptyAgentId));
+
+        let error = AgentConfigAckDTO::try_decode(&AgentConfigAckDTO::new("agent-1", -1, &[]).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AgentConfigError::NegativeConfigVersion(-1).to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why a configuration pushed to an agent, or its acknowledgement, is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AgentConfigError {
+    /// The agent id is empty.
+    EmptyAgentId,
+    /// The configuration version is negative.
+    NegativeConfigVersion(i64),
+    /// No interface to capture on is set.
+    NoInterfaces,
+    /// An interface name is empty.
+    EmptyInterfaceName,
+    /// An interface is set more than once.
+    DuplicateInterface(String),
+    /// The BPF filter expression is set but empty.
+    EmptyBpfFilter,
+    /// The snap length is not positive or exceeds the maximum snap length.
+    InvalidSnapLength { snap_length: i64, max_snap_length: i64 },
+    /// The sampling rate is not positive.
+    InvalidSamplingRate(i64),
+    /// The upload batch size is not positive or exceeds the maximum batch size.
+    InvalidUploadBatchSize { upload_batch_size: i64, max_upload_batch_size: i64 },
+}
+
+impl AgentConfigError {
+    /// The field of the configuration the error is about.
+    pub fn get_field(&self) -> &'static str {
+        match self {
+            AgentConfigError::EmptyAgentId => "agent_id",
+            AgentConfigError::NegativeConfigVersion(_) => "config_version",
+            AgentConfigError::NoInterfaces | AgentConfigError::EmptyInterfaceName | AgentConfigError::DuplicateInterface(_) => "interfaces",
+            AgentConfigError::EmptyBpfFilter => "bpf_filter",
+            AgentConfigError::InvalidSnapLength { .. } => "snap_length",
+            AgentConfigError::InvalidSamplingRate(_) => "sampling_rate",
+            AgentConfigError::InvalidUploadBatchSize { .. } => "upload_batch_size",
+        }
+    }
+}
+
+impl fmt::Display for AgentConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgentConfigError::EmptyAgentId => write!(f, "the agent id cannot be empty"),
+            AgentConfigError::NegativeConfigVersion(config_version) => write!(f, "the configuration version cannot be negative, it is {config_version}"),
+            AgentConfigError::NoInterfaces => write!(f, "at least one interface to capture on has to be set"),
+            AgentConfigError::EmptyInterfaceName => write!(f, "the interface name cannot be empty"),
+            AgentConfigError::DuplicateInterface(interface_name) => write!(f, "the interface `{interface_name}` is set more than once"),
+            AgentConfigError::EmptyBpfFilter => write!(f, "the BPF filter expression cannot be empty"),
+            AgentConfigError::InvalidSnapLength { snap_length, max_snap_length } => write!(f, "the snap length has to be between 1 and {max_snap_length}, it is {snap_length}"),
+            AgentConfigError::InvalidSamplingRate(sampling_rate) => write!(f, "the sampling rate has to be positive, it is {sampling_rate}"),
+            AgentConfigError::InvalidUploadBatchSize { upload_batch_size, max_upload_batch_size } => write!(
+                f,
+                "the upload batch size has to be between 1 and {max_upload_batch_size}, it is {upload_batch_size}",
+            ),
+        }
+    }
+}
+
+impl Error for AgentConfigError { }
\ No newline at end of file
@@ -0,0 +1,146 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_health_error::AgentHealthError;
+use super::agent_heartbeat::AgentHeartbeatDTO;
+use super::capture_stats::CaptureStatsDTO;
+
+
+const DATA_TYPE: &str = "agent_health";
+const VERSION: u32 = 1;
+
+/// Reported periodically by an agent: its heartbeat, the resources it uses and the capture stats of each of its interfaces,
+/// so that the hub can tell whether the agent keeps up with the traffic or drops packets.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgentHealthDTO {
+    heartbeat: AgentHeartbeatDTO,
+    /// In thousandths of a CPU core, above 1000 for an agent using several cores.
+    cpu_usage: i64,
+    /// In bytes.
+    memory_used: i64,
+    capture_stats: Vec<CaptureStatsDTO>,
+}
+impl API for AgentHealthDTO { }
+
+impl AgentHealthDTO {
+    /// The health report of the agent sending `heartbeat`, using `cpu_usage` thousandths of a CPU core and `memory_used` bytes.
+    pub fn new(heartbeat: AgentHeartbeatDTO, cpu_usage: i64, memory_used: i64, capture_stats: &[CaptureStatsDTO]) -> Self {
+        AgentHealthDTO {
+            heartbeat,
+            cpu_usage,
+            memory_used,
+            capture_stats: capture_stats.to_vec(),
+        }
+    }
+
+    pub fn get_heartbeat(&self) -> &AgentHeartbeatDTO {
+        &self.heartbeat
+    }
+
+    /// The CPU usage in thousandths of a core, e.g. `1500` for one core and a half.
+    pub fn get_cpu_usage(&self) -> i64 {
+        self.cpu_usage
+    }
+
+    /// The memory used in bytes.
+    pub fn get_memory_used(&self) -> i64 {
+        self.memory_used
+    }
+
+    pub fn get_capture_stats(&self) -> &[CaptureStatsDTO] {
+        &self.capture_stats
+    }
+
+    /// The capture stats of the interface named `interface_name`, if the agent captures on it.
+    pub fn get_interface_stats(&self, interface_name: &str) -> Option<&CaptureStatsDTO> {
+        self.capture_stats.iter().find(|stats| stats.get_interface_name() == interface_name)
+    }
+
+    /// The packets lost on all interfaces, by the kernel or by the agent, capped at `i64::MAX`.
+    pub fn get_packets_dropped(&self) -> i64 {
+        self.capture_stats.iter().map(CaptureStatsDTO::get_packets_dropped).fold(0, i64::saturating_add)
+    }
+
+    /// Checks the CPU usage and memory used are not negative, nor are the counters of the heartbeat and capture stats.
+    /// Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AgentHealthError> {
+        self.heartbeat.validate()?;
+        self.capture_stats.iter().try_for_each(CaptureStatsDTO::validate)?;
+
+        match (self.cpu_usage, self.memory_used) {
+            (cpu_usage, _) if cpu_usage < 0 => Err(AgentHealthError::NegativeCpuUsage(cpu_usage)),
+            (_, memory_used) if memory_used < 0 => Err(AgentHealthError::NegativeMemoryUsed(memory_used)),
+            _ => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::timestamp::Timestamp;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::agent_health::AgentHealthDTO;
+    use crate::api::agent_health_error::AgentHealthError;
+    use crate::api::agent_heartbeat::AgentHeartbeatDTO;
+    use crate::api::capture_stats::CaptureStatsDTO;
+
+    fn get_heartbeat() -> AgentHeartbeatDTO {
+        AgentHeartbeatDTO::new("agent-1", "0.1.4", Timestamp::from_millis(1_700_000_000_000).unwrap(), Duration::from_secs(3600))
+    }
+
+    fn get_health(cpu_usage: i64, memory_used: i64) -> AgentHealthDTO {
+        let capture_stats = [CaptureStatsDTO::new("eth0", 123_456, 12, 3), CaptureStatsDTO::new("eth1", 789, 0, 1)];
+        AgentHealthDTO::new(get_heartbeat(), cpu_usage, memory_used, &capture_stats)
+    }
+
+    #[test]
+    fn endec_agent_health() {
+        let health = get_health(1500, 64 * 1024 * 1024);
+        assert_eq!(health, AgentHealthDTO::decode(&health.encode()));
+
+        let health = AgentHealthDTO::new(get_heartbeat(), 0, 0, &[]);
+        assert_eq!(health, AgentHealthDTO::decode(&health.encode()));
+    }
+
+    #[test]
+    fn health_sums_up_the_packets_dropped() {
+        let health = get_health(1500, 64 * 1024 * 1024);
+        assert_eq!(health.get_packets_dropped(), 16);
+        let capture_stats = [CaptureStatsDTO::new("eth0", i64::MAX, i64::MAX, 0), CaptureStatsDTO::new("eth1", 789, 0, 1)];
+        assert_eq!(AgentHealthDTO::new(get_heartbeat(), 0, 0, &capture_stats).get_packets_dropped(), i64::MAX);
+        assert_eq!(health.get_interface_stats("eth1").map(CaptureStatsDTO::get_packets_seen), Some(789));
+        assert_eq!(health.get_interface_stats("wlan0"), None);
+    }
+
+    #[test]
+    fn invalid_agent_health_is_not_decoded() {
+        assert_eq!(get_health(-1, 0).validate(), Err(AgentHealthError::NegativeCpuUsage(-1)));
+
+        let error = AgentHealthDTO::try_decode(&get_health(0, -1).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AgentHealthError::NegativeMemoryUsed(-1).to_string()));
+
+        let health = AgentHealthDTO::new(get_heartbeat(), 0, 0, &[CaptureStatsDTO::new("", 0, 0, 0)]);
+        let error = AgentHealthDTO::try_decode(&health.encode()).unwrap_err();
+        assert_eq!(error.get_field(), "capture_stats[0]");
+    }
+
+    #[test]
+    fn test_getting_data_types() {
+        let health = get_health(1500, 64 * 1024 * 1024);
+        assert_eq!(health.get_type(), AgentHealthDTO::get_data_type());
+        assert_eq!(health.get_type(), super::DATA_TYPE);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why a heartbeat or a health report of an agent is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AgentHealthError {
+    /// The agent id is empty.
+    EmptyAgentId,
+    /// The agent version is empty.
+    EmptyAgentVersion,
+    /// The uptime is negative.
+    NegativeUptime(i64),
+    /// The interface name of capture stats is empty.
+    EmptyInterfaceName,
+    /// A packet counter of capture stats is negative.
+    NegativePacketCount { counter: &'static str, count: i64 },
+    /// The CPU usage is negative.
+    NegativeCpuUsage(i64),
+    /// The memory used is negative.
+    NegativeMemoryUsed(i64),
+}
+
+impl fmt::Display for AgentHealthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgentHealthError::EmptyAgentId => write!(f, "the agent id cannot be empty"),
+            AgentHealthError::EmptyAgentVersion => write!(f, "the agent version cannot be empty"),
+            AgentHealthError::NegativeUptime(uptime) => write!(f, "the uptime cannot be negative, it is {uptime}"),
+            AgentHealthError::EmptyInterfaceName => write!(f, "the interface name cannot be empty"),
+            AgentHealthError::NegativePacketCount { counter, count } => write!(f, "the count of {counter} cannot be negative, it is {count}"),
+            AgentHealthError::NegativeCpuUsage(cpu_usage) => write!(f, "the CPU usage cannot be negative, it is {cpu_usage}"),
+            AgentHealthError::NegativeMemoryUsed(memory_used) => write!(f, "the memory used cannot be negative, it is {memory_used}"),
+        }
+    }
+}
+
+impl Error for AgentHealthError { }
\ No newline at end of file
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use net_core_api::core::api::API;
+
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_health_error::AgentHealthError;
+
+
+const DATA_TYPE: &str = "agent_heartbeat";
+const VERSION: u32 = 1;
+
+/// Sent periodically by an agent, even when it captures nothing, so that the hub can tell an idle agent from a dead one.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgentHeartbeatDTO {
+    agent_id: String,
+    agent_version: String,
+    sent_at: Timestamp,
+    /// In milliseconds.
+    uptime: i64,
+}
+impl API for AgentHeartbeatDTO { }
+
+impl AgentHeartbeatDTO {
+    /// The heartbeat `agent_id`, running version `agent_version` of the agent for `uptime`, sends at `sent_at`.
+    pub fn new(agent_id: &str, agent_version: &str, sent_at: Timestamp, uptime: Duration) -> Self {
+        AgentHeartbeatDTO {
+            agent_id: agent_id.into(),
+            agent_version: agent_version.into(),
+            sent_at,
+            uptime: uptime.as_millis().try_into().unwrap_or(i64::MAX),
+        }
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_agent_version(&self) -> &str {
+        &self.agent_version
+    }
+
+    pub fn get_sent_at(&self) -> Timestamp {
+        self.sent_at
+    }
+
+    /// How long the agent had been running when it sent the heartbeat, to the millisecond.
+    pub fn get_uptime(&self) -> Duration {
+        Duration::from_millis(self.uptime.max(0) as u64)
+    }
+
+    /// Whether no heartbeat following this one is expected to have been sent by `now` if the agent were alive,
+    /// agents sending one every `interval`. A heartbeat sent after `now`, by an agent whose clock is ahead, is not late.
+    pub fn is_late(&self, now: Timestamp, interval: Duration) -> bool {
+        now > self.sent_at && now.abs_diff(self.sent_at) > interval
+    }
+
+    /// Checks the agent id and version are set and the uptime is not negative. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AgentHealthError> {
+        if self.agent_id.is_empty() {
+            return Err(AgentHealthError::EmptyAgentId);
+        }
+        if self.agent_version.is_empty() {
+            return Err(AgentHealthError::EmptyAgentVersion);
+        }
+
+        match self.uptime < 0 {
+            true => Err(AgentHealthError::NegativeUptime(self.uptime)),
+            false => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::timestamp::Timestamp;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::agent_health_error::AgentHealthError;
+    use crate::api::agent_heartbeat::AgentHeartbeatDTO;
+
+    fn get_sent_at() -> Timestamp {
+        Timestamp::from_millis(1_700_000_000_000).unwrap()
+    }
+
+    #[test]
+    fn endec_agent_heartbeat() {
+        let heartbeat = AgentHeartbeatDTO::new("agent-1", "0.1.4", get_sent_at(), Duration::from_secs(3600));
+        assert_eq!(heartbeat, AgentHeartbeatDTO::decode(&heartbeat.encode()));
+        assert_eq!(heartbeat.get_uptime(), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn heartbeat_is_late_once_the_next_one_is_due() {
+        let heartbeat = AgentHeartbeatDTO::new("agent-1", "0.1.4", get_sent_at(), Duration::from_secs(3600));
+        let interval = Duration::from_secs(30);
+
+        assert!(!heartbeat.is_late(Timestamp::from_millis(1_700_000_030_000).unwrap(), interval));
+        assert!(heartbeat.is_late(Timestamp::from_millis(1_700_000_030_001).unwrap(), interval));
+        assert!(!heartbeat.is_late(Timestamp::from_millis(1_699_999_000_000).unwrap(), interval));
+    }
+
+    #[test]
+    fn invalid_agent_heartbeat_is_not_decoded() {
+        let heartbeat = AgentHeartbeatDTO::new("agent-1", "", get_sent_at(), Duration::ZERO);
+        assert_eq!(heartbeat.validate(), Err(AgentHealthError::EmptyAgentVersion));
+
+        let heartbeat = AgentHeartbeatDTO::new("", "0.1.4", get_sent_at(), Duration::ZERO);
+        let error = AgentHeartbeatDTO::try_decode(&heartbeat.encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AgentHealthError::EmptyAgentId.to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::capture_header_error::CaptureHeaderError;
+
+
+const DATA_TYPE: &str = "capture_header";
+const VERSION: u32 = 1;
+
+/// Tells which agent, host and interface captured a data packet, in which capture session,
+/// and where the packet stands in that session, so that the hub can attribute traffic and detect lost packets.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureHeaderDTO {
+    agent_id: String,
+    hostname: Option<String>,
+    interface_name: Option<String>,
+    capture_session_id: String,
+    sequence_number: i64,
+    link_type: Option<i64>,
+}
+impl API for CaptureHeaderDTO { }
+
+impl CaptureHeaderDTO {
+    /// The header of the packet numbered `sequence_number` in the capture session `capture_session_id` of `agent_id`,
+    /// which the `with_` methods complete.
+    pub fn new(agent_id: &str, capture_session_id: &str, sequence_number: i64) -> Self {
+        CaptureHeaderDTO {
+            agent_id: agent_id.into(),
+            hostname: None,
+            interface_name: None,
+            capture_session_id: capture_session_id.into(),
+            sequence_number,
+            link_type: None,
+        }
+    }
+
+    pub fn with_hostname(self, hostname: &str) -> Self {
+        CaptureHeaderDTO { hostname: Some(hostname.into()), ..self }
+    }
+
+    pub fn with_interface_name(self, interface_name: &str) -> Self {
+        CaptureHeaderDTO { interface_name: Some(interface_name.into()), ..self }
+    }
+
+    /// Sets the link-layer header type of the interface, as numbered by pcap, e.g. `1` for Ethernet.
+    pub fn with_link_type(self, link_type: i64) -> Self {
+        CaptureHeaderDTO { link_type: Some(link_type), ..self }
+    }
+
+    /// The header of the packet numbered `sequence_number` in the same capture session.
+    pub fn with_sequence_number(self, sequence_number: i64) -> Self {
+        CaptureHeaderDTO { sequence_number, ..self }
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_hostname(&self) -> Option<&str> {
+        self.hostname.as_deref()
+    }
+
+    pub fn get_interface_name(&self) -> Option<&str> {
+        self.interface_name.as_deref()
+    }
+
+    pub fn get_capture_session_id(&self) -> &str {
+        &self.capture_session_id
+    }
+
+    /// The number of the packet in its capture session, counted from `0`.
+    pub fn get_sequence_number(&self) -> i64 {
+        self.sequence_number
+    }
+
+    pub fn get_link_type(&self) -> Option<i64> {
+        self.link_type
+    }
+
+    /// The header of the packet following this one in the same capture session, `None` if this one is numbered `i64::MAX`.
+    pub fn get_next(&self) -> Option<Self> {
+        let sequence_number = self.sequence_number.checked_add(1)?;
+        Some(CaptureHeaderDTO { sequence_number, ..self.clone() })
+    }
+
+    /// Whether both headers belong to the same capture session of the same agent.
+    pub fn is_same_session(&self, other: &CaptureHeaderDTO) -> bool {
+        self.agent_id == other.agent_id && self.capture_session_id == other.capture_session_id
+    }
+
+    /// The sequence numbers of the packets lost between `previous`, the last packet received from the session,
+    /// and this one. Empty when this packet follows `previous` or is not newer than it,
+    /// and when the headers belong to different sessions, which start their own numbering.
+    pub fn get_missing_since(&self, previous: &CaptureHeaderDTO) -> Range<i64> {
+        match self.is_same_session(previous) && self.sequence_number > previous.sequence_number {
+            true => previous.sequence_number + 1..self.sequence_number,
+            false => self.sequence_number..self.sequence_number,
+        }
+    }
+
+    /// Checks the agent and capture session ids are set and the sequence number and link type are not negative.
+    /// Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), CaptureHeaderError> {
+        if self.agent_id.is_empty() {
+            return Err(CaptureHeaderError::EmptyAgentId);
+        }
+        if self.capture_session_id.is_empty() {
+            return Err(CaptureHeaderError::EmptyCaptureSessionId);
+        }
+
+        match self.link_type {
+            _ if self.sequence_number < 0 => Err(CaptureHeaderError::NegativeSequenceNumber(self.sequence_number)),
+            Some(link_type) if link_type < 0 => Err(CaptureHeaderError::NegativeLinkType(link_type)),
+            _ => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::capture_header::CaptureHeaderDTO;
+    use crate::api::capture_header_error::CaptureHeaderError;
+
+
+    fn get_header(sequence_number: i64) -> CaptureHeaderDTO {
+        CaptureHeaderDTO::new("agent-1", "session-1", sequence_number)
+            .with_hostname("probe-1.local")
+            .with_interface_name("eth0")
+            .with_link_type(1)
+    }
+
+    #[test]
+    fn endec_capture_header() {
+        let header = get_header(42);
+        assert_eq!(header, CaptureHeaderDTO::decode(&header.encode()));
+
+        let header = CaptureHeaderDTO::new("agent-1", "session-1", 0);
+        assert_eq!(header.get_hostname(), None);
+        assert_eq!(header, CaptureHeaderDTO::decode(&header.encode()));
+    }
+
+    #[test]
+    fn headers_tell_the_packets_missing_in_between() {
+        let previous = get_header(41);
+
+        assert_eq!(previous.get_next(), Some(get_header(42)));
+        assert!(get_header(42).get_missing_since(&previous).is_empty());
+        assert_eq!(get_header(45).get_missing_since(&previous), 42..45);
+        assert!(get_header(41).get_missing_since(&previous).is_empty());
+        assert!(get_header(40).get_missing_since(&previous).is_empty());
+
+        let other_session = CaptureHeaderDTO::new("agent-1", "session-2", 45);
+        assert!(!other_session.is_same_session(&previous));
+        assert!(other_session.get_missing_since(&previous).is_empty());
+    }
+
+    #[test]
+    fn invalid_capture_header_is_not_decoded() {
+        assert_eq!(CaptureHeaderDTO::new("", "session-1", 0).validate(), Err(CaptureHeaderError::EmptyAgentId));
+        assert_eq!(CaptureHeaderDTO::new("agent-1", "", 0).validate(), Err(CaptureHeaderError::EmptyCaptureSessionId));
+        assert_eq!(CaptureHeaderDTO::new("agent-1", "session-1", 0).with_link_type(-1).validate(), Err(CaptureHeaderError::NegativeLinkType(-1)));
+
+        let error = CaptureHeaderDTO::try_decode(&CaptureHeaderDTO::new("agent-1", "session-1", -1).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(CaptureHeaderError::NegativeSequenceNumber(-1).to_string()));
+    }
+
+    #[test]
+    fn sequence_numbers_do_not_wrap_around() {
+        assert_eq!(get_header(i64::MAX).get_next(), None);
+    }
+}
\ No newline at end of file
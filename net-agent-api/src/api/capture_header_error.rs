@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why the capture header of a data packet is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CaptureHeaderError {
+    /// The agent id is empty.
+    EmptyAgentId,
+    /// The capture session id is empty.
+    EmptyCaptureSessionId,
+    /// The sequence number is negative.
+    NegativeSequenceNumber(i64),
+    /// The link type is negative.
+    NegativeLinkType(i64),
+}
+
+impl fmt::Display for CaptureHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureHeaderError::EmptyAgentId => write!(f, "the agent id cannot be empty"),
+            CaptureHeaderError::EmptyCaptureSessionId => write!(f, "the capture session id cannot be empty"),
+            CaptureHeaderError::NegativeSequenceNumber(sequence_number) => write!(f, "the sequence number cannot be negative, it is {sequence_number}"),
+            CaptureHeaderError::NegativeLinkType(link_type) => write!(f, "the link type cannot be negative, it is {link_type}"),
+        }
+    }
+}
+
+impl Error for CaptureHeaderError { }
\ No newline at end of file
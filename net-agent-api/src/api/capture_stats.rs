@@ -0,0 +1,103 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_health_error::AgentHealthError;
+
+
+const DATA_TYPE: &str = "capture_stats";
+const VERSION: u32 = 1;
+
+/// The packets an agent saw on an interface since its capture started, and how many of them it lost,
+/// either because the kernel dropped them before the agent read them or because the agent's buffer was full.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureStatsDTO {
+    interface_name: String,
+    packets_seen: i64,
+    packets_dropped_by_kernel: i64,
+    packets_dropped_by_agent: i64,
+}
+impl API for CaptureStatsDTO { }
+
+impl CaptureStatsDTO {
+    pub fn new(interface_name: &str, packets_seen: i64, packets_dropped_by_kernel: i64, packets_dropped_by_agent: i64) -> Self {
+        CaptureStatsDTO {
+            interface_name: interface_name.into(),
+            packets_seen,
+            packets_dropped_by_kernel,
+            packets_dropped_by_agent,
+        }
+    }
+
+    pub fn get_interface_name(&self) -> &str {
+        &self.interface_name
+    }
+
+    pub fn get_packets_seen(&self) -> i64 {
+        self.packets_seen
+    }
+
+    pub fn get_packets_dropped_by_kernel(&self) -> i64 {
+        self.packets_dropped_by_kernel
+    }
+
+    pub fn get_packets_dropped_by_agent(&self) -> i64 {
+        self.packets_dropped_by_agent
+    }
+
+    /// The packets lost, by the kernel or by the agent, capped at `i64::MAX`.
+    pub fn get_packets_dropped(&self) -> i64 {
+        self.packets_dropped_by_kernel.saturating_add(self.packets_dropped_by_agent)
+    }
+
+    /// Checks the interface name is set and no counter is negative. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AgentHealthError> {
+        if self.interface_name.is_empty() {
+            return Err(AgentHealthError::EmptyInterfaceName);
+        }
+
+        [
+            ("packets seen", self.packets_seen),
+            ("packets dropped by the kernel", self.packets_dropped_by_kernel),
+            ("packets dropped by the agent", self.packets_dropped_by_agent),
+        ]
+            .into_iter()
+            .find(|(_, count)| *count < 0)
+            .map_or(Ok(()), |(counter, count)| Err(AgentHealthError::NegativePacketCount { counter, count }))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::agent_health_error::AgentHealthError;
+    use crate::api::capture_stats::CaptureStatsDTO;
+
+    #[test]
+    fn endec_capture_stats() {
+        let stats = CaptureStatsDTO::new("eth0", 123_456, 12, 3);
+        assert_eq!(stats, CaptureStatsDTO::decode(&stats.encode()));
+        assert_eq!(stats.get_packets_dropped(), 15);
+        assert_eq!(CaptureStatsDTO::new("eth0", i64::MAX, i64::MAX, 1).get_packets_dropped(), i64::MAX);
+    }
+
+    #[test]
+    fn invalid_capture_stats_is_not_decoded() {
+        assert_eq!(CaptureStatsDTO::new("", 0, 0, 0).validate(), Err(AgentHealthError::EmptyInterfaceName));
+
+        let error = CaptureStatsDTO::try_decode(&CaptureStatsDTO::new("eth0", 10, 0, -1).encode()).unwrap_err();
+        assert_eq!(
+            error.get_kind(),
+            &DecodeErrorKind::Invalid(AgentHealthError::NegativePacketCount { counter: "packets dropped by the agent", count: -1 }.to_string()),
+        );
+    }
+}
\ No newline at end of file
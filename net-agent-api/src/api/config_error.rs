@@ -0,0 +1,67 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::agent_config_error::AgentConfigError;
+
+
+const DATA_TYPE: &str = "config_error";
+const VERSION: u32 = 1;
+
+/// Why an agent rejected a field of the configuration pushed to it.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigErrorDTO {
+    field: String,
+    message: String,
+}
+impl API for ConfigErrorDTO { }
+
+impl ConfigErrorDTO {
+    /// An error of the configuration field `field`, e.g. `interfaces` when the agent's host has no such interface.
+    pub fn new(field: &str, message: &str) -> Self {
+        ConfigErrorDTO {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<&AgentConfigError> for ConfigErrorDTO {
+    fn from(error: &AgentConfigError) -> Self {
+        ConfigErrorDTO::new(error.get_field(), &error.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use crate::api::agent_config_error::AgentConfigError;
+    use crate::api::config_error::ConfigErrorDTO;
+
+    #[test]
+    fn endec_config_error() {
+        let error = ConfigErrorDTO::new("interfaces", "the interface `eth9` does not exist");
+        assert_eq!(error, ConfigErrorDTO::decode(&error.encode()));
+    }
+
+    #[test]
+    fn config_errors_name_the_field_they_are_about() {
+        let error = ConfigErrorDTO::from(&AgentConfigError::InvalidSamplingRate(0));
+        assert_eq!(error.get_field(), "sampling_rate");
+        assert_eq!(error.get_message(), "the sampling rate has to be positive, it is 0");
+    }
+}
\ No newline at end of file
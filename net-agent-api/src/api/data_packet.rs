@@ -1,81 +1,63 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_api::core::compression::Compression;
 
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+
+use super::capture_header::CaptureHeaderDTO;
 
 
 const DATA_TYPE: &str = "data_packet";
+const VERSION: u32 = 3;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataPacketDTO {
-    data: Vec<u8>
+    /// The captured bytes, compressed on the wire with `codec`, if any.
+    #[ion(compressed_by = codec)]
+    data: Vec<u8>,
+    header: Option<CaptureHeaderDTO>,
+    #[ion(as_string)]
+    codec: Option<Compression>,
 }
 impl API for DataPacketDTO { }
 
 impl DataPacketDTO {
     pub fn new (data: &[u8]) -> Self {
         DataPacketDTO {
-            data: data.into()
+            data: data.into(),
+            header: None,
+            codec: None,
         }
     }
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn new_with_header(data: &[u8], header: CaptureHeaderDTO) -> Self {
+        DataPacketDTO {
+            header: Some(header),
+            ..DataPacketDTO::new(data)
+        }
     }
-}
-
-impl Encoder for DataPacketDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
 
-        writer.set_field_name("data");
-        writer.write_blob(&self.data).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
+    /// Compresses the data with `compression` when the packet is encoded, it is decompressed when decoded.
+    pub fn with_compression(self, compression: Compression) -> Self {
+        DataPacketDTO { codec: Some(compression), ..self }
     }
-}
-
-impl Decoder for DataPacketDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_blob().unwrap();
-        let data = binding.as_slice();
 
-        DataPacketDTO::new(
-            data
-        )
+    /// The data as it was captured, decompressed if it was sent compressed.
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-}
 
-impl Typed for DataPacketDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+    /// The codec the data is sent compressed with, unset if it is sent as is.
+    pub fn get_compression(&self) -> Option<Compression> {
+        self.codec
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// Who captured the packet and where it stands in its capture session, unset for agents which predate it.
+    pub fn get_header(&self) -> Option<&CaptureHeaderDTO> {
+        self.header.as_ref()
     }
 }
 
@@ -91,7 +73,11 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::typed_api::Typed;
 
+    use net_codec_api::core::compression::Compression;
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
 
+    use crate::api::capture_header::CaptureHeaderDTO;
     use crate::api::data_packet::DataPacketDTO;
 
     #[test]
@@ -115,6 +101,56 @@ mod tests {
         assert_eq!(data_packet, DataPacketDTO::decode(&data_packet.encode()));
     }
 
+    #[test]
+    fn endec_data_packet_with_header() {
+        const DATA: &[u8] = "SOME_RAW_PCAP".as_bytes();
+        let header = CaptureHeaderDTO::new("agent-1", "session-1", 42).with_interface_name("eth0");
+        let data_packet = DataPacketDTO::new_with_header(DATA, header.clone());
+
+        let decoded = DataPacketDTO::decode(&data_packet.encode());
+        assert_eq!(decoded.get_header(), Some(&header));
+        assert_eq!(decoded, data_packet);
+    }
+
+    #[test]
+    fn endec_compressed_data_packet() {
+        let data = "SOME_RAW_PCAP".repeat(64);
+        for compression in Compression::ALL {
+            let data_packet = DataPacketDTO::new(data.as_bytes()).with_compression(compression);
+            let encoded = data_packet.encode();
+            assert!(encoded.len() < data.len());
+
+            let decoded = DataPacketDTO::decode(&encoded);
+            assert_eq!(decoded.get_data(), data.as_bytes());
+            assert_eq!(decoded.get_compression(), Some(compression));
+        }
+        assert_eq!(DataPacketDTO::new(data.as_bytes()).get_compression(), None);
+    }
+
+    #[test]
+    fn try_decode_data_packet_with_unknown_codec() {
+        let error = DataPacketDTO::try_decode(r#"v3::{data: {{AQID}}, codec: "gzip"}"#.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "codec");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn try_decode_data_packet_with_invalid_header() {
+        let data_packet = DataPacketDTO::new_with_header(&[1, 2, 3], CaptureHeaderDTO::new("agent-1", "", 0));
+        let error = DataPacketDTO::try_decode(&data_packet.encode()).unwrap_err();
+        assert_eq!(error.get_field(), "header");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn try_decode_truncated_data_packet() {
+        const DATA: &[u8] = "SOME_RAW_PCAP".as_bytes();
+        let data_packet: DataPacketDTO = DataPacketDTO::new(DATA);
+        let encoded = data_packet.encode();
+        let error = DataPacketDTO::try_decode(&encoded[..encoded.len() - 1]).unwrap_err();
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Malformed(_)));
+    }
+
     #[test]
     fn test_getting_data_types() {
         const DATA: &[u8] = "SOME_RAW_PCAP".as_bytes();
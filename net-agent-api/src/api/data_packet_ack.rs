@@ -0,0 +1,119 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::acknowledgement_error::AcknowledgementError;
+use super::capture_header::CaptureHeaderDTO;
+use super::sequence_range::SequenceRangeDTO;
+
+
+const DATA_TYPE: &str = "data_packet_ack";
+const VERSION: u32 = 1;
+
+/// Sent by the hub to tell an agent which data packets of a capture session it received,
+/// so that the agent can stop tracking them for retransmission.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataPacketAckDTO {
+    agent_id: String,
+    capture_session_id: String,
+    ranges: Vec<SequenceRangeDTO>,
+}
+impl API for DataPacketAckDTO { }
+
+impl DataPacketAckDTO {
+    pub fn new(agent_id: &str, capture_session_id: &str, ranges: &[SequenceRangeDTO]) -> Self {
+        DataPacketAckDTO {
+            agent_id: agent_id.into(),
+            capture_session_id: capture_session_id.into(),
+            ranges: ranges.to_vec(),
+        }
+    }
+
+    /// Acknowledges the packets numbered `sequence_numbers` in the capture session of `header`.
+    pub fn for_received(header: &CaptureHeaderDTO, sequence_numbers: impl IntoIterator<Item = i64>) -> Self {
+        DataPacketAckDTO {
+            agent_id: header.get_agent_id().into(),
+            capture_session_id: header.get_capture_session_id().into(),
+            ranges: SequenceRangeDTO::from_sequence_numbers(sequence_numbers),
+        }
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_capture_session_id(&self) -> &str {
+        &self.capture_session_id
+    }
+
+    pub fn get_ranges(&self) -> &[SequenceRangeDTO] {
+        &self.ranges
+    }
+
+    /// Whether the packet numbered `sequence_number` is acknowledged.
+    pub fn acknowledges(&self, sequence_number: i64) -> bool {
+        self.ranges.iter().any(|range| range.contains(sequence_number))
+    }
+
+    /// Checks the agent and capture session ids are set and at least one range is. Decoding runs it as well,
+    /// after validating each range.
+    pub fn validate(&self) -> Result<(), AcknowledgementError> {
+        if self.agent_id.is_empty() {
+            return Err(AcknowledgementError::EmptyAgentId);
+        }
+        if self.capture_session_id.is_empty() {
+            return Err(AcknowledgementError::EmptyCaptureSessionId);
+        }
+
+        match self.ranges.is_empty() {
+            true => Err(AcknowledgementError::NoRanges),
+            false => self.ranges.iter().try_for_each(SequenceRangeDTO::validate),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::acknowledgement_error::AcknowledgementError;
+    use crate::api::capture_header::CaptureHeaderDTO;
+    use crate::api::data_packet_ack::DataPacketAckDTO;
+    use crate::api::sequence_range::SequenceRangeDTO;
+
+    #[test]
+    fn endec_data_packet_ack() {
+        let ack = DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(0, 42), SequenceRangeDTO::new(45, 46)]);
+        assert_eq!(ack, DataPacketAckDTO::decode(&ack.encode()));
+    }
+
+    #[test]
+    fn received_packets_are_acknowledged() {
+        let header = CaptureHeaderDTO::new("agent-1", "session-1", 0);
+        let ack = DataPacketAckDTO::for_received(&header, [0, 1, 2, 4]);
+
+        assert_eq!(ack.get_capture_session_id(), "session-1");
+        assert_eq!(ack.get_ranges(), [SequenceRangeDTO::new(0, 3), SequenceRangeDTO::new(4, 5)]);
+        assert!(ack.acknowledges(2));
+        assert!(!ack.acknowledges(3));
+    }
+
+    #[test]
+    fn invalid_data_packet_ack_is_not_decoded() {
+        assert_eq!(DataPacketAckDTO::new("", "session-1", &[SequenceRangeDTO::new(0, 1)]).validate(), Err(AcknowledgementError::EmptyAgentId));
+        assert_eq!(DataPacketAckDTO::new("agent-1", "session-1", &[]).validate(), Err(AcknowledgementError::NoRanges));
+
+        let ack = DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(0, 1), SequenceRangeDTO::new(3, 2)]);
+        let error = DataPacketAckDTO::try_decode(&ack.encode()).unwrap_err();
+        assert_eq!(error.get_field(), "ranges[1]");
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AcknowledgementError::InvalidRange { start: 3, end: 2 }.to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::acknowledgement_error::AcknowledgementError;
+use super::capture_header::CaptureHeaderDTO;
+use super::sequence_range::SequenceRangeDTO;
+
+
+const DATA_TYPE: &str = "data_packet_nack";
+const VERSION: u32 = 1;
+
+/// Sent by the hub to ask an agent to resend data packets of a capture session,
+/// which were lost on the way or could not be decoded.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataPacketNackDTO {
+    agent_id: String,
+    capture_session_id: String,
+    ranges: Vec<SequenceRangeDTO>,
+    reason: Option<String>,
+}
+impl API for DataPacketNackDTO { }
+
+impl DataPacketNackDTO {
+    pub fn new(agent_id: &str, capture_session_id: &str, ranges: &[SequenceRangeDTO]) -> Self {
+        DataPacketNackDTO {
+            agent_id: agent_id.into(),
+            capture_session_id: capture_session_id.into(),
+            ranges: ranges.to_vec(),
+            reason: None,
+        }
+    }
+
+    /// Asks for the packets lost between `previous`, the last packet received from the capture session, and `header`,
+    /// or `None` if none were.
+    pub fn for_missing(header: &CaptureHeaderDTO, previous: &CaptureHeaderDTO) -> Option<Self> {
+        let missing = header.get_missing_since(previous);
+        match missing.is_empty() {
+            true => None,
+            false => Some(DataPacketNackDTO::new(header.get_agent_id(), header.get_capture_session_id(), &[missing.into()])),
+        }
+    }
+
+    /// Tells the agent why the packets have to be resent, e.g. to log it.
+    pub fn with_reason(self, reason: &str) -> Self {
+        DataPacketNackDTO { reason: Some(reason.into()), ..self }
+    }
+
+    pub fn get_agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn get_capture_session_id(&self) -> &str {
+        &self.capture_session_id
+    }
+
+    pub fn get_ranges(&self) -> &[SequenceRangeDTO] {
+        &self.ranges
+    }
+
+    pub fn get_reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Whether the packet numbered `sequence_number` has to be resent.
+    pub fn rejects(&self, sequence_number: i64) -> bool {
+        self.ranges.iter().any(|range| range.contains(sequence_number))
+    }
+
+    /// Checks the agent and capture session ids are set and at least one range is. Decoding runs it as well,
+    /// after validating each range.
+    pub fn validate(&self) -> Result<(), AcknowledgementError> {
+        if self.agent_id.is_empty() {
+            return Err(AcknowledgementError::EmptyAgentId);
+        }
+        if self.capture_session_id.is_empty() {
+            return Err(AcknowledgementError::EmptyCaptureSessionId);
+        }
+
+        match self.ranges.is_empty() {
+            true => Err(AcknowledgementError::NoRanges),
+            false => self.ranges.iter().try_for_each(SequenceRangeDTO::validate),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::acknowledgement_error::AcknowledgementError;
+    use crate::api::capture_header::CaptureHeaderDTO;
+    use crate::api::data_packet_nack::DataPacketNackDTO;
+    use crate::api::sequence_range::SequenceRangeDTO;
+
+    #[test]
+    fn endec_data_packet_nack() {
+        let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(42, 45)]).with_reason("lost");
+        assert_eq!(nack, DataPacketNackDTO::decode(&nack.encode()));
+
+        let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(42, 45)]);
+        assert_eq!(nack.get_reason(), None);
+        assert_eq!(nack, DataPacketNackDTO::decode(&nack.encode()));
+    }
+
+    #[test]
+    fn missing_packets_are_rejected() {
+        let previous = CaptureHeaderDTO::new("agent-1", "session-1", 41);
+
+        let nack = DataPacketNackDTO::for_missing(&CaptureHeaderDTO::new("agent-1", "session-1", 45), &previous).unwrap();
+        assert_eq!(nack.get_ranges(), [SequenceRangeDTO::new(42, 45)]);
+        assert!(nack.rejects(44));
+        assert!(!nack.rejects(45));
+
+        assert_eq!(DataPacketNackDTO::for_missing(&previous.get_next().unwrap(), &previous), None);
+    }
+
+    #[test]
+    fn invalid_data_packet_nack_is_not_decoded() {
+        assert_eq!(DataPacketNackDTO::new("agent-1", "", &[SequenceRangeDTO::new(0, 1)]).validate(), Err(AcknowledgementError::EmptyCaptureSessionId));
+
+        let error = DataPacketNackDTO::try_decode(&DataPacketNackDTO::new("agent-1", "session-1", &[]).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AcknowledgementError::NoRanges.to_string()));
+    }
+}
\ No newline at end of file
@@ -1 +1,16 @@
-pub mod data_packet;
\ No newline at end of file
+pub mod acknowledgement_error;
+pub mod agent_config;
+pub mod agent_config_ack;
+pub mod agent_config_error;
+pub mod agent_health;
+pub mod agent_health_error;
+pub mod agent_heartbeat;
+pub mod capture_header;
+pub mod capture_header_error;
+pub mod capture_stats;
+pub mod config_error;
+pub mod data_packet;
+pub mod data_packet_ack;
+pub mod data_packet_nack;
+pub mod resend_tracker;
+pub mod sequence_range;
\ No newline at end of file
@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::capture_header::CaptureHeaderDTO;
+use super::data_packet_ack::DataPacketAckDTO;
+use super::data_packet_nack::DataPacketNackDTO;
+use super::sequence_range::SequenceRangeDTO;
+
+
+/// Where a data packet sent by the agent stands until the hub acknowledges it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PacketState {
+    /// Sent, last at the given instant, and not acknowledged yet.
+    Sent(Instant),
+    /// Rejected by the hub, to be resent right away.
+    Rejected,
+}
+
+/// Numbers the data packets an agent sends in a capture session and tracks the ones the hub has not acknowledged,
+/// to tell which of them have to be resent.
+///
+/// A packet is tracked from the moment it is sent. It is resent when the hub rejects it with a `DataPacketNackDTO`
+/// or when it has not been acknowledged `resend_after` after it was last sent, and forgotten once a `DataPacketAckDTO`
+/// acknowledges it. Acknowledgements for other capture sessions are ignored.
+///
+/// The tracker only deals with sequence numbers, the agent keeps the data of the packets until they are acknowledged.
+#[derive(Debug, Clone)]
+pub struct ResendTracker {
+    header: CaptureHeaderDTO,
+    next_sequence_number: Option<i64>,
+    resend_after: Duration,
+    pending: BTreeMap<i64, PacketState>,
+}
+
+impl ResendTracker {
+    /// Starts numbering the packets of the capture session of `first_header` from its sequence number.
+    pub fn new(first_header: CaptureHeaderDTO, resend_after: Duration) -> Self {
+        ResendTracker {
+            next_sequence_number: Some(first_header.get_sequence_number()),
+            header: first_header,
+            resend_after,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// The header of the next packet, which is tracked as sent at `now`,
+    /// or `None` once the capture session sent a packet numbered `i64::MAX` and has no sequence numbers left.
+    pub fn send(&mut self, now: Instant) -> Option<CaptureHeaderDTO> {
+        let sequence_number = self.next_sequence_number?;
+        self.next_sequence_number = sequence_number.checked_add(1);
+        self.pending.insert(sequence_number, PacketState::Sent(now));
+        Some(self.header.clone().with_sequence_number(sequence_number))
+    }
+
+    /// Stops tracking the packets `ack` acknowledges and tells how many of them were still tracked.
+    /// Invalid ranges, which only an ack built without being decoded may hold, are ignored.
+    pub fn acknowledge(&mut self, ack: &DataPacketAckDTO) -> usize {
+        match self.is_own_session(ack.get_agent_id(), ack.get_capture_session_id()) {
+            true => get_valid_ranges(ack.get_ranges())
+                .map(|range| {
+                    let acknowledged: Vec<i64> = self.pending.range(range.get_range()).map(|(sequence_number, _)| *sequence_number).collect();
+                    acknowledged.iter().for_each(|sequence_number| {
+                        self.pending.remove(sequence_number);
+                    });
+                    acknowledged.len()
+                })
+                .sum(),
+            false => 0,
+        }
+    }
+
+    /// Marks the tracked packets `nack` rejects to be resent and tells how many there are.
+    /// Packets which were acknowledged already or never sent are not resent, and invalid ranges are ignored.
+    pub fn reject(&mut self, nack: &DataPacketNackDTO) -> usize {
+        match self.is_own_session(nack.get_agent_id(), nack.get_capture_session_id()) {
+            true => get_valid_ranges(nack.get_ranges())
+                .map(|range| self.pending.range_mut(range.get_range()).map(|(_, state)| *state = PacketState::Rejected).count())
+                .sum(),
+            false => 0,
+        }
+    }
+
+    /// The headers of the packets to resend at `now`, in sequence order, which are tracked as sent at `now` again.
+    pub fn get_due(&mut self, now: Instant) -> Vec<CaptureHeaderDTO> {
+        let resend_after = self.resend_after;
+        let is_due = |state: &PacketState| match state {
+            PacketState::Sent(sent_at) => now.saturating_duration_since(*sent_at) >= resend_after,
+            PacketState::Rejected => true,
+        };
+
+        self.pending.iter_mut()
+            .filter(|(_, state)| is_due(state))
+            .map(|(sequence_number, state)| {
+                *state = PacketState::Sent(now);
+                self.header.clone().with_sequence_number(*sequence_number)
+            })
+            .collect()
+    }
+
+    /// Where the packet numbered `sequence_number` stands, `None` if it is not tracked.
+    pub fn get_state(&self, sequence_number: i64) -> Option<PacketState> {
+        self.pending.get(&sequence_number).copied()
+    }
+
+    /// The number of packets sent and not acknowledged yet.
+    pub fn get_pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn is_own_session(&self, agent_id: &str, capture_session_id: &str) -> bool {
+        self.header.get_agent_id() == agent_id && self.header.get_capture_session_id() == capture_session_id
+    }
+}
+
+/// The ranges which can be looked up, as a map panics on a range ending before its start.
+fn get_valid_ranges(ranges: &[SequenceRangeDTO]) -> impl Iterator<Item = &SequenceRangeDTO> {
+    ranges.iter().filter(|range| range.validate().is_ok())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use crate::api::capture_header::CaptureHeaderDTO;
+    use crate::api::data_packet_ack::DataPacketAckDTO;
+    use crate::api::data_packet_nack::DataPacketNackDTO;
+    use crate::api::resend_tracker::PacketState;
+    use crate::api::resend_tracker::ResendTracker;
+    use crate::api::sequence_range::SequenceRangeDTO;
+
+    const RESEND_AFTER: Duration = Duration::from_secs(5);
+
+    fn get_sequence_numbers(headers: &[CaptureHeaderDTO]) -> Vec<i64> {
+        headers.iter().map(CaptureHeaderDTO::get_sequence_number).collect()
+    }
+
+    fn send_packets(count: usize, now: Instant) -> ResendTracker {
+        let mut tracker = ResendTracker::new(CaptureHeaderDTO::new("agent-1", "session-1", 0).with_interface_name("eth0"), RESEND_AFTER);
+        (0..count).for_each(|_| {
+            tracker.send(now);
+        });
+        tracker
+    }
+
+    #[test]
+    fn packets_are_numbered_in_sequence() {
+        let now = Instant::now();
+        let mut tracker = ResendTracker::new(CaptureHeaderDTO::new("agent-1", "session-1", 10).with_interface_name("eth0"), RESEND_AFTER);
+
+        let header = tracker.send(now).unwrap();
+        assert_eq!(header, CaptureHeaderDTO::new("agent-1", "session-1", 10).with_interface_name("eth0"));
+        assert_eq!(tracker.send(now), header.get_next());
+        assert_eq!(tracker.get_pending_count(), 2);
+        assert_eq!(tracker.get_state(10), Some(PacketState::Sent(now)));
+    }
+
+    #[test]
+    fn sequence_numbers_run_out_at_the_last_one() {
+        let now = Instant::now();
+        let mut tracker = ResendTracker::new(CaptureHeaderDTO::new("agent-1", "session-1", i64::MAX), RESEND_AFTER);
+
+        assert_eq!(tracker.send(now).map(|header| header.get_sequence_number()), Some(i64::MAX));
+        assert_eq!(tracker.send(now), None);
+        assert_eq!(tracker.get_pending_count(), 1);
+    }
+
+    #[test]
+    fn acknowledged_packets_are_not_resent() {
+        let now = Instant::now();
+        let mut tracker = send_packets(5, now);
+
+        let ack = DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(0, 2), SequenceRangeDTO::new(3, 7)]);
+        assert_eq!(tracker.acknowledge(&ack), 4);
+        assert_eq!(tracker.acknowledge(&ack), 0);
+        assert_eq!(tracker.get_state(0), None);
+
+        let due = tracker.get_due(now + RESEND_AFTER);
+        assert_eq!(get_sequence_numbers(&due), [2]);
+        assert_eq!(due[0].get_interface_name(), Some("eth0"));
+    }
+
+    #[test]
+    fn unacknowledged_packets_are_resent_after_a_while() {
+        let now = Instant::now();
+        let mut tracker = send_packets(2, now);
+        tracker.send(now + Duration::from_secs(2));
+
+        assert!(tracker.get_due(now + Duration::from_secs(4)).is_empty());
+        assert_eq!(get_sequence_numbers(&tracker.get_due(now + RESEND_AFTER)), [0, 1]);
+        assert_eq!(tracker.get_state(0), Some(PacketState::Sent(now + RESEND_AFTER)));
+        assert_eq!(get_sequence_numbers(&tracker.get_due(now + Duration::from_secs(7))), [2]);
+    }
+
+    #[test]
+    fn rejected_packets_are_resent_right_away() {
+        let now = Instant::now();
+        let mut tracker = send_packets(5, now);
+        tracker.acknowledge(&DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(1, 2)]));
+
+        let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(0, 3), SequenceRangeDTO::new(9, 10)]);
+        assert_eq!(tracker.reject(&nack), 2);
+        assert_eq!(tracker.get_state(2), Some(PacketState::Rejected));
+        assert_eq!(get_sequence_numbers(&tracker.get_due(now)), [0, 2]);
+        assert!(tracker.get_due(now).is_empty());
+    }
+
+    #[test]
+    fn other_sessions_are_ignored() {
+        let now = Instant::now();
+        let mut tracker = send_packets(3, now);
+
+        assert_eq!(tracker.acknowledge(&DataPacketAckDTO::new("agent-1", "session-2", &[SequenceRangeDTO::new(0, 3)])), 0);
+        assert_eq!(tracker.reject(&DataPacketNackDTO::new("agent-2", "session-1", &[SequenceRangeDTO::new(0, 3)])), 0);
+        assert_eq!(tracker.get_pending_count(), 3);
+    }
+
+    #[test]
+    fn invalid_ranges_are_ignored() {
+        let now = Instant::now();
+        let mut tracker = send_packets(3, now);
+
+        let ack = DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(2, 0), SequenceRangeDTO::new(0, 1)]);
+        assert_eq!(tracker.acknowledge(&ack), 1);
+        let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(3, 1), SequenceRangeDTO::new(-1, 2)]);
+        assert_eq!(tracker.reject(&nack), 0);
+        assert_eq!(tracker.get_pending_count(), 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::ops::Range;
+
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::acknowledgement_error::AcknowledgementError;
+
+
+const DATA_TYPE: &str = "sequence_range";
+const VERSION: u32 = 1;
+
+/// The sequence numbers of a capture session from `start` up to, but excluding, `end`.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceRangeDTO {
+    start: i64,
+    end: i64,
+}
+impl API for SequenceRangeDTO { }
+
+impl SequenceRangeDTO {
+    pub fn new(start: i64, end: i64) -> Self {
+        SequenceRangeDTO { start, end }
+    }
+
+    /// The ranges covering `sequence_numbers`, in order, with consecutive numbers merged into a single range.
+    pub fn from_sequence_numbers(sequence_numbers: impl IntoIterator<Item = i64>) -> Vec<Self> {
+        let mut sequence_numbers: Vec<i64> = sequence_numbers.into_iter().collect();
+        sequence_numbers.sort_unstable();
+        sequence_numbers.dedup();
+
+        let mut ranges: Vec<SequenceRangeDTO> = Vec::new();
+        for sequence_number in sequence_numbers {
+            match ranges.last_mut() {
+                Some(range) if range.end == sequence_number => range.end += 1,
+                _ => ranges.push(SequenceRangeDTO::new(sequence_number, sequence_number + 1)),
+            }
+        }
+        ranges
+    }
+
+    pub fn get_start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn get_end(&self) -> i64 {
+        self.end
+    }
+
+    pub fn get_range(&self) -> Range<i64> {
+        self.start..self.end
+    }
+
+    pub fn contains(&self, sequence_number: i64) -> bool {
+        self.get_range().contains(&sequence_number)
+    }
+
+    /// Checks the range starts at `0` or above and is not empty. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AcknowledgementError> {
+        match self.start < 0 || self.end <= self.start {
+            true => Err(AcknowledgementError::InvalidRange { start: self.start, end: self.end }),
+            false => Ok(()),
+        }
+    }
+}
+
+impl From<Range<i64>> for SequenceRangeDTO {
+    fn from(range: Range<i64>) -> Self {
+        SequenceRangeDTO::new(range.start, range.end)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::acknowledgement_error::AcknowledgementError;
+    use crate::api::sequence_range::SequenceRangeDTO;
+
+    #[test]
+    fn endec_sequence_range() {
+        let range = SequenceRangeDTO::new(42, 45);
+        assert_eq!(range, SequenceRangeDTO::decode(&range.encode()));
+        assert_eq!(range, SequenceRangeDTO::from(42..45));
+        assert!(range.contains(44));
+        assert!(!range.contains(45));
+    }
+
+    #[test]
+    fn consecutive_sequence_numbers_are_merged() {
+        assert_eq!(
+            SequenceRangeDTO::from_sequence_numbers([7, 3, 1, 2, 3, 8, 5]),
+            vec![SequenceRangeDTO::new(1, 4), SequenceRangeDTO::new(5, 6), SequenceRangeDTO::new(7, 9)],
+        );
+        assert!(SequenceRangeDTO::from_sequence_numbers([]).is_empty());
+    }
+
+    #[test]
+    fn invalid_sequence_range_is_not_decoded() {
+        assert_eq!(SequenceRangeDTO::new(-1, 2).validate(), Err(AcknowledgementError::InvalidRange { start: -1, end: 2 }));
+
+        let error = SequenceRangeDTO::try_decode(&SequenceRangeDTO::new(5, 5).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AcknowledgementError::InvalidRange { start: 5, end: 5 }.to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use net_codec_api::core::golden::check_golden;
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_agent_api::api::agent_config::AgentConfigDTO;
+use net_agent_api::api::agent_config_ack::AgentConfigAckDTO;
+use net_agent_api::api::agent_health::AgentHealthDTO;
+use net_agent_api::api::agent_heartbeat::AgentHeartbeatDTO;
+use net_agent_api::api::capture_header::CaptureHeaderDTO;
+use net_agent_api::api::capture_stats::CaptureStatsDTO;
+use net_agent_api::api::config_error::ConfigErrorDTO;
+use net_agent_api::api::data_packet::DataPacketDTO;
+use net_agent_api::api::data_packet_ack::DataPacketAckDTO;
+use net_agent_api::api::data_packet_nack::DataPacketNackDTO;
+use net_agent_api::api::sequence_range::SequenceRangeDTO;
+
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+
+#[test]
+fn golden_data_packet() {
+    check_golden(GOLDEN_DIR, "data_packet", &DataPacketDTO::new("SOME_RAW_PCAP".as_bytes()));
+}
+
+#[test]
+fn golden_capture_header() {
+    let header = CaptureHeaderDTO::new("agent-1", "session-1", 42)
+        .with_hostname("probe-1.local")
+        .with_interface_name("eth0")
+        .with_link_type(1);
+    check_golden(GOLDEN_DIR, "capture_header", &header);
+}
+
+#[test]
+fn golden_sequence_range() {
+    check_golden(GOLDEN_DIR, "sequence_range", &SequenceRangeDTO::new(42, 45));
+}
+
+#[test]
+fn golden_data_packet_ack() {
+    let ack = DataPacketAckDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(0, 42), SequenceRangeDTO::new(45, 46)]);
+    check_golden(GOLDEN_DIR, "data_packet_ack", &ack);
+}
+
+#[test]
+fn golden_data_packet_nack() {
+    let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(42, 45)]).with_reason("lost");
+    check_golden(GOLDEN_DIR, "data_packet_nack", &nack);
+}
+
+fn get_heartbeat() -> AgentHeartbeatDTO {
+    AgentHeartbeatDTO::new("agent-1", "0.1.4", Timestamp::from_millis(1_700_000_000_000).unwrap(), Duration::from_secs(3600))
+}
+
+#[test]
+fn golden_agent_heartbeat() {
+    check_golden(GOLDEN_DIR, "agent_heartbeat", &get_heartbeat());
+}
+
+#[test]
+fn golden_capture_stats() {
+    check_golden(GOLDEN_DIR, "capture_stats", &CaptureStatsDTO::new("eth0", 123_456, 12, 3));
+}
+
+#[test]
+fn golden_agent_health() {
+    let health = AgentHealthDTO::new(get_heartbeat(), 1500, 64 * 1024 * 1024, &[CaptureStatsDTO::new("eth0", 123_456, 12, 3)]);
+    check_golden(GOLDEN_DIR, "agent_health", &health);
+}
+
+#[test]
+fn golden_agent_config() {
+    let config = AgentConfigDTO::new("agent-1", 7, &["eth0", "eth1"])
+        .with_bpf_filter("tcp port 80")
+        .with_snap_length(1500)
+        .with_promiscuous(true)
+        .with_sampling_rate(10)
+        .with_upload_batch_size(500);
+    check_golden(GOLDEN_DIR, "agent_config", &config);
+}
+
+#[test]
+fn golden_config_error() {
+    check_golden(GOLDEN_DIR, "config_error", &ConfigErrorDTO::new("interfaces", "the interface `eth9` does not exist"));
+}
+
+#[test]
+fn golden_agent_config_ack() {
+    let ack = AgentConfigAckDTO::new("agent-1", 7, &[ConfigErrorDTO::new("interfaces", "the interface `eth9` does not exist")]);
+    check_golden(GOLDEN_DIR, "agent_config_ack", &ack);
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use net_core_api::core::decoder_api::Decoder;
+use net_core_api::core::encoder_api::Encoder;
+
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_agent_api::api::agent_config_ack::AgentConfigAckDTO;
+use net_agent_api::api::agent_health::AgentHealthDTO;
+use net_agent_api::api::agent_heartbeat::AgentHeartbeatDTO;
+use net_agent_api::api::capture_header::CaptureHeaderDTO;
+use net_agent_api::api::capture_stats::CaptureStatsDTO;
+use net_agent_api::api::config_error::ConfigErrorDTO;
+use net_agent_api::api::data_packet::DataPacketDTO;
+use net_agent_api::api::data_packet_nack::DataPacketNackDTO;
+use net_agent_api::api::sequence_range::SequenceRangeDTO;
+
+
+#[test]
+fn json_round_trip_data_packet() {
+    let data_packet = DataPacketDTO::decode(&DataPacketDTO::new("SOME_RAW_PCAP".as_bytes()).encode());
+
+    let json = serde_json::to_value(&data_packet).unwrap();
+    assert_eq!(json["data"].as_array().map(Vec::len), Some("SOME_RAW_PCAP".len()));
+    assert_eq!(serde_json::from_value::<DataPacketDTO>(json).unwrap(), data_packet);
+}
+
+#[test]
+fn json_round_trip_data_packet_with_header() {
+    let header = CaptureHeaderDTO::new("agent-1", "session-1", 42).with_hostname("probe-1.local");
+    let data_packet = DataPacketDTO::decode(&DataPacketDTO::new_with_header(&[1, 2, 3], header).encode());
+
+    let json = serde_json::to_value(&data_packet).unwrap();
+    assert_eq!(json["header"]["agent_id"], "agent-1");
+    assert_eq!(json["header"]["sequence_number"], 42);
+    assert_eq!(json["header"]["interface_name"], serde_json::Value::Null);
+    assert_eq!(serde_json::from_value::<DataPacketDTO>(json).unwrap(), data_packet);
+}
+
+#[test]
+fn json_round_trip_data_packet_nack() {
+    let nack = DataPacketNackDTO::new("agent-1", "session-1", &[SequenceRangeDTO::new(42, 45)]);
+
+    let json = serde_json::to_value(&nack).unwrap();
+    assert_eq!(json["ranges"][0]["start"], 42);
+    assert_eq!(json["ranges"][0]["end"], 45);
+    assert_eq!(json["reason"], serde_json::Value::Null);
+    assert_eq!(serde_json::from_value::<DataPacketNackDTO>(json).unwrap(), nack);
+}
+
+#[test]
+fn json_round_trip_agent_health() {
+    let heartbeat = AgentHeartbeatDTO::new("agent-1", "0.1.4", Timestamp::from_millis(1_700_000_000_000).unwrap(), Duration::from_secs(3600));
+    let health = AgentHealthDTO::new(heartbeat, 1500, 64 * 1024 * 1024, &[CaptureStatsDTO::new("eth0", 123_456, 12, 3)]);
+
+    let json = serde_json::to_value(&health).unwrap();
+    assert_eq!(json["heartbeat"]["sent_at"], "2023-11-14T22:13:20Z");
+    assert_eq!(json["heartbeat"]["uptime"], 3_600_000);
+    assert_eq!(json["capture_stats"][0]["packets_dropped_by_kernel"], 12);
+    assert_eq!(serde_json::from_value::<AgentHealthDTO>(json).unwrap(), health);
+}
+
+#[test]
+fn json_round_trip_agent_config_ack() {
+    let ack = AgentConfigAckDTO::new("agent-1", 7, &[ConfigErrorDTO::new("sampling_rate", "the sampling rate has to be positive, it is 0")]);
+
+    let json = serde_json::to_value(&ack).unwrap();
+    assert_eq!(json["config_version"], 7);
+    assert_eq!(json["errors"][0]["field"], "sampling_rate");
+    assert_eq!(serde_json::from_value::<AgentConfigAckDTO>(json).unwrap(), ack);
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+//! Compares the codecs blobs may be compressed with on pcap captures, both as whole captures,
+//! which is how agents upload `DataPacketDTO`s, and frame by frame, which is how `NetworkPacketDTO`s hold them.
+//!
+//! Captures are read from the `.pcap` files of the directory `NET_API_PCAP_SAMPLES` points at, e.g.
+//! `NET_API_PCAP_SAMPLES=~/captures cargo bench -p net-codec-api --bench compression`.
+//! Without it, the captures of `benches/samples` are used: `loopback_http.pcap` was captured on the loopback interface
+//! of a Linux host while a local HTTP server served the READMEs and ISL schemas of this repository.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use net_codec_api::core::compression::Compression;
+
+
+const SAMPLES_VARIABLE: &str = "NET_API_PCAP_SAMPLES";
+const SAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/samples");
+const PCAP_HEADER_LENGTH: usize = 24;
+const RECORD_HEADER_LENGTH: usize = 16;
+const ROUNDS: u32 = 5;
+
+struct Sample {
+    name: String,
+    capture: Vec<u8>,
+}
+
+fn main() {
+    let samples = match env::var_os(SAMPLES_VARIABLE) {
+        Some(directory) => read_samples(PathBuf::from(directory)),
+        None => read_samples(PathBuf::from(SAMPLES_DIR)),
+    };
+
+    println!("{:<30} {:<6} {:>8} {:>12} {:>10} {:>12} {:>12}", "sample", "codec", "frames", "bytes", "ratio", "compress", "decompress");
+    for sample in &samples {
+        let frames = split_frames(&sample.capture);
+        for codec in Compression::ALL {
+            report(&sample.name, codec, "whole", &[sample.capture.as_slice()]);
+            report(&sample.name, codec, "frames", &frames);
+        }
+    }
+}
+
+fn read_samples(directory: PathBuf) -> Vec<Sample> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(&directory)
+        .unwrap_or_else(|error| panic!("cannot list {}: {error}", directory.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "pcap"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "{} holds no .pcap file", directory.display());
+
+    paths.into_iter()
+        .map(|path| Sample {
+            name: path.file_name().unwrap().to_string_lossy().into(),
+            capture: fs::read(&path).unwrap_or_else(|error| panic!("cannot read {}: {error}", path.display())),
+        })
+        .collect()
+}
+
+/// Splits a pcap capture into the frames it holds, ignoring a truncated last record.
+fn split_frames(capture: &[u8]) -> Vec<&[u8]> {
+    let read_u32: fn([u8; 4]) -> u32 = match capture.get(..4) {
+        Some([0xd4, 0xc3, 0xb2, 0xa1]) | Some([0x4d, 0x3c, 0xb2, 0xa1]) => u32::from_le_bytes,
+        Some([0xa1, 0xb2, 0xc3, 0xd4]) | Some([0xa1, 0xb2, 0x3c, 0x4d]) => u32::from_be_bytes,
+        _ => panic!("not a pcap capture, pcapng is not supported"),
+    };
+
+    let mut frames = Vec::new();
+    let mut offset = PCAP_HEADER_LENGTH;
+    while let Some(header) = capture.get(offset..offset + RECORD_HEADER_LENGTH) {
+        let length = read_u32(header[8..12].try_into().unwrap()) as usize;
+        let start = offset + RECORD_HEADER_LENGTH;
+        let Some(frame) = capture.get(start..start + length) else {
+            break;
+        };
+        frames.push(frame);
+        offset = start + length;
+    }
+    frames
+}
+
+fn report(sample: &str, codec: Compression, unit: &str, blobs: &[&[u8]]) {
+    let length: usize = blobs.iter().map(|blob| blob.len()).sum();
+
+    let mut compressed = Vec::new();
+    let compress_time = measure(|| compressed = blobs.iter().map(|blob| codec.compress(blob)).collect());
+    let compressed_length: usize = compressed.iter().map(Vec::len).sum();
+    let decompress_time = measure(|| compressed.iter().for_each(|blob| {
+        codec.decompress(blob).unwrap();
+    }));
+
+    println!(
+        "{:<30} {:<6} {:>8} {:>12} {:>10.3} {:>9.1}MB/s {:>9.1}MB/s",
+        format!("{sample} ({unit})"),
+        codec,
+        blobs.len(),
+        length,
+        length as f64 / compressed_length.max(1) as f64,
+        throughput(length, compress_time),
+        throughput(length, decompress_time),
+    );
+}
+
+/// The best time out of a few rounds, to leave warm-up and noise out.
+fn measure(mut run: impl FnMut()) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn throughput(length: usize, time: Duration) -> f64 {
+    length as f64 / 1_000_000.0 / time.as_secs_f64().max(f64::EPSILON)
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+/// Former data types of a DTO.
+///
+/// Older producers may still tag payloads, e.g. in envelopes, with the data type a DTO had before
+/// its data type was renamed. Consumers keyed on data types accept those aliases as well as the current one.
+pub trait Aliased {
+    fn get_data_type_aliases() -> &'static [&'static str] where Self : Sized;
+}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+use crate::core::compression_error::CompressionError;
+
+
+/// The most bytes a payload may decompress to, well above the largest capture an agent uploads at once.
+pub const MAX_DECOMPRESSED_LENGTH: usize = 64 * 1024 * 1024;
+
+const ZSTD_LEVEL: i32 = 3;
+const LZ4_SIZE_PREFIX_LENGTH: usize = 4;
+
+/// A codec a blob may be compressed with. DTOs name it in a `codec` field written next to the blob,
+/// which is left unset for blobs written uncompressed, so that payloads of every producer stay readable.
+/// The `serde` feature writes it by name as well.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Compression {
+    /// Zstandard, which compresses better, at its default level.
+    Zstd,
+    /// LZ4 block format, prefixed with the decompressed length, which is faster on the agents' side.
+    Lz4,
+}
+
+impl Compression {
+    pub const ALL: [Compression; 2] = [Compression::Zstd, Compression::Lz4];
+
+    /// The name the codec field holds, e.g. `zstd`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Compression::Zstd => "zstd",
+            Compression::Lz4 => "lz4",
+        }
+    }
+
+    /// Picks the first of the `preferred` codecs the peer names in `accepted`,
+    /// or `None` if they have none in common and blobs have to be sent uncompressed.
+    pub fn negotiate(preferred: &[Compression], accepted: &[&str]) -> Option<Compression> {
+        preferred.iter().copied().find(|codec| accepted.contains(&codec.get_name()))
+    }
+
+    pub fn compress(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Compression::Zstd => zstd::bulk::compress(data, ZSTD_LEVEL).expect("zstd compresses any input in memory"),
+            Compression::Lz4 => lz4_flex::compress_prepend_size(data),
+        }
+    }
+
+    /// Decompresses `data`, refusing payloads which decompress to more than [`MAX_DECOMPRESSED_LENGTH`] bytes.
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        let corrupted = |description: String| CompressionError::Corrupted { codec: self.get_name().into(), description };
+        let too_large = || CompressionError::TooLarge { codec: self.get_name().into(), max_length: MAX_DECOMPRESSED_LENGTH };
+
+        match self {
+            Compression::Zstd => {
+                let decoder = zstd::stream::read::Decoder::new(data).map_err(|error| corrupted(error.to_string()))?;
+                let mut decompressed = Vec::new();
+                decoder.take(MAX_DECOMPRESSED_LENGTH as u64 + 1).read_to_end(&mut decompressed)
+                    .map_err(|error| corrupted(error.to_string()))?;
+                match decompressed.len() > MAX_DECOMPRESSED_LENGTH {
+                    true => Err(too_large()),
+                    false => Ok(decompressed),
+                }
+            },
+            Compression::Lz4 => {
+                // The length is checked before decompressing, as the buffer is allocated upfront
+                let length = data.get(..LZ4_SIZE_PREFIX_LENGTH)
+                    .map(|prefix| u32::from_le_bytes(prefix.try_into().unwrap()) as usize)
+                    .ok_or_else(|| corrupted("the decompressed length is missing".into()))?;
+                if length > MAX_DECOMPRESSED_LENGTH {
+                    return Err(too_large());
+                }
+                lz4_flex::decompress_size_prepended(data).map_err(|error| corrupted(error.to_string()))
+            },
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl FromStr for Compression {
+    type Err = CompressionError;
+
+    fn from_str(codec: &str) -> Result<Self, Self::Err> {
+        Compression::ALL.into_iter()
+            .find(|compression| compression.get_name() == codec)
+            .ok_or_else(|| CompressionError::UnknownCodec(codec.into()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Compression {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.get_name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Compression {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Compresses `data` with `codec`, leaving it as is if the codec is unset.
+pub fn compress_with(codec: Option<Compression>, data: &[u8]) -> Vec<u8> {
+    match codec {
+        Some(codec) => codec.compress(data),
+        None => data.into(),
+    }
+}
+
+/// Decompresses `data` with `codec`, leaving it as is if the codec is unset.
+pub fn decompress_with(codec: Option<Compression>, data: &[u8]) -> Result<Vec<u8>, CompressionError> {
+    match codec {
+        Some(codec) => codec.decompress(data),
+        None => Ok(data.into()),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::compression::compress_with;
+    use crate::core::compression::decompress_with;
+    use crate::core::compression::Compression;
+    use crate::core::compression::MAX_DECOMPRESSED_LENGTH;
+    use crate::core::compression_error::CompressionError;
+
+    fn get_data() -> Vec<u8> {
+        (0..4096).map(|index| (index % 64) as u8).collect()
+    }
+
+    #[test]
+    fn compressed_data_decompresses_to_itself() {
+        let data = get_data();
+        for codec in Compression::ALL {
+            let compressed = codec.compress(&data);
+            assert!(compressed.len() < data.len());
+            assert_eq!(codec.decompress(&compressed), Ok(data.clone()));
+            assert_eq!(codec.decompress(&codec.compress(&[])), Ok(Vec::new()));
+        }
+    }
+
+    #[test]
+    fn codecs_are_named_in_codec_fields() {
+        assert_eq!("zstd".parse(), Ok(Compression::Zstd));
+        assert_eq!(Compression::Lz4.to_string(), "lz4");
+        assert_eq!("gzip".parse::<Compression>(), Err(CompressionError::UnknownCodec("gzip".into())));
+
+        let data = get_data();
+        assert_eq!(compress_with(None, &data), data);
+        assert_eq!(decompress_with(None, &data), Ok(data.clone()));
+        assert_eq!(decompress_with(Some(Compression::Lz4), &compress_with(Some(Compression::Lz4), &data)), Ok(data.clone()));
+    }
+
+    #[test]
+    fn negotiation_picks_the_first_preferred_codec_the_peer_accepts() {
+        assert_eq!(Compression::negotiate(&Compression::ALL, &["lz4", "zstd"]), Some(Compression::Zstd));
+        assert_eq!(Compression::negotiate(&[Compression::Lz4, Compression::Zstd], &["zstd", "lz4"]), Some(Compression::Lz4));
+        assert_eq!(Compression::negotiate(&Compression::ALL, &["gzip"]), None);
+    }
+
+    #[test]
+    fn corrupted_and_oversized_data_is_rejected() {
+        for codec in Compression::ALL {
+            let compressed = codec.compress(&get_data());
+            let error = codec.decompress(&compressed[..compressed.len() / 2]).unwrap_err();
+            assert!(matches!(error, CompressionError::Corrupted { .. }));
+        }
+
+        let mut bomb = ((MAX_DECOMPRESSED_LENGTH + 1) as u32).to_le_bytes().to_vec();
+        bomb.extend(lz4_flex::compress(&[0]));
+        assert_eq!(
+            Compression::Lz4.decompress(&bomb),
+            Err(CompressionError::TooLarge { codec: "lz4".into(), max_length: MAX_DECOMPRESSED_LENGTH }),
+        );
+
+        let bomb = Compression::Zstd.compress(&vec![0; MAX_DECOMPRESSED_LENGTH + 1]);
+        assert!(matches!(Compression::Zstd.decompress(&bomb), Err(CompressionError::TooLarge { .. })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why a payload could not be compressed or decompressed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CompressionError {
+    /// The codec field names no codec this crate supports.
+    UnknownCodec(String),
+    /// The payload is not one the codec wrote, e.g. it is truncated.
+    Corrupted { codec: String, description: String },
+    /// The payload decompresses to more than the maximum length, which guards against decompression bombs.
+    TooLarge { codec: String, max_length: usize },
+}
+
+impl fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressionError::UnknownCodec(codec) => write!(f, "{codec:?} is not a supported codec, expected `zstd` or `lz4`"),
+            CompressionError::Corrupted { codec, description } => write!(f, "the payload is not valid {codec}: {description}"),
+            CompressionError::TooLarge { codec, max_length } => write!(f, "the {codec} payload decompresses to more than {max_length} bytes"),
+        }
+    }
+}
+
+impl Error for CompressionError { }
\ No newline at end of file
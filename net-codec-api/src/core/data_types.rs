@@ -0,0 +1,71 @@
+//! Checks of the data types the DTOs are tagged with.
+//!
+//! A data type is written in lower snake case and named after the DTO it tags, without its `DTO` suffix,
+//! e.g. `http_requests_bucket` for `HttpRequestsBucketDTO`. Data types and their aliases must be unique
+//! among all the DTOs a consumer may receive, otherwise it could not tell which DTO to decode a payload as.
+
+use std::any::type_name;
+use std::collections::HashMap;
+
+use net_core_api::core::typed_api::Typed;
+
+use crate::core::aliased_api::Aliased;
+
+
+pub fn is_canonical_data_type(data_type: &str) -> bool {
+    data_type.split('_')
+        .all(|word| word.starts_with(|c: char| c.is_ascii_lowercase())
+            && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()))
+}
+
+/// Collects the data types of DTOs and reports those which are not canonical or not unique.
+#[derive(Default)]
+pub struct DataTypeCheck {
+    owners: HashMap<&'static str, &'static str>,
+    problems: Vec<String>,
+}
+
+impl DataTypeCheck {
+    pub fn new() -> Self {
+        DataTypeCheck::default()
+    }
+
+    pub fn add<T: Typed + Aliased>(&mut self) -> &mut Self {
+        let dto = type_name::<T>();
+        let data_type = T::get_data_type();
+        if !is_canonical_data_type(data_type) {
+            self.problems.push(format!("{dto} has the non canonical data type `{data_type}`"));
+        }
+
+        for data_type in [data_type].iter().chain(T::get_data_type_aliases()) {
+            if let Some(owner) = self.owners.insert(data_type, dto) {
+                self.problems.push(format!("{dto} and {owner} share the data type `{data_type}`"));
+            }
+        }
+        self
+    }
+
+    /// Panics listing every problem found.
+    pub fn check(&self) {
+        assert!(self.problems.is_empty(), "invalid data types:\n{}", self.problems.join("\n"));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::data_types::is_canonical_data_type;
+
+    #[test]
+    fn canonical_data_types_are_lower_snake_case() {
+        assert!(is_canonical_data_type("http_requests_bucket"));
+        assert!(is_canonical_data_type("ipv4_endpoint"));
+
+        assert!(!is_canonical_data_type(""));
+        assert!(!is_canonical_data_type("bandwidth-per-endpoint"));
+        assert!(!is_canonical_data_type("HttpClient"));
+        assert!(!is_canonical_data_type("http__client"));
+        assert!(!is_canonical_data_type("_http_client"));
+        assert!(!is_canonical_data_type("4xx_responses"));
+    }
+}
\ No newline at end of file
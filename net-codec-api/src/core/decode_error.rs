@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+
+use ion_rs::IonType;
+use ion_rs::StreamItem;
+
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodeErrorKind {
+    /// The value has a different Ion type than the one the DTO expects.
+    UnexpectedType(StreamItem),
+    /// The payload ended before the value could be read.
+    Missing,
+    /// A required field is absent from its struct.
+    MissingField,
+    /// The Ion reader rejected the payload itself, e.g. it is truncated or not Ion at all.
+    Malformed(String),
+    /// The value has the expected Ion type but is not one the DTO accepts, e.g. an unknown name.
+    Invalid(String),
+}
+
+/// Describes why a DTO could not be decoded: the path of the field that failed
+/// (e.g. `filters.endpoints[2]`), the Ion type the decoder expected there and,
+/// for binary payloads, the byte offset of the offending value.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DecodeError {
+    field: String,
+    expected: Option<IonType>,
+    offset: Option<usize>,
+    kind: DecodeErrorKind,
+}
+
+impl DecodeError {
+    pub fn new(
+        field: &str,
+        expected: Option<IonType>,
+        offset: Option<usize>,
+        kind: DecodeErrorKind,
+    ) -> Self {
+        DecodeError {
+            field: field.into(),
+            expected,
+            offset,
+            kind,
+        }
+    }
+
+    /// Re-roots an error produced while decoding a nested DTO under the field of the parent
+    /// it was read from. `base_offset` is where the nested payload starts in the parent one.
+    pub fn nested(self, parent_field: &str, base_offset: Option<usize>) -> Self {
+        DecodeError {
+            field: join_field_path(parent_field, &self.field),
+            offset: self.offset.zip(base_offset).map(|(offset, base_offset)| offset + base_offset),
+            ..self
+        }
+    }
+
+    pub fn get_field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn get_expected_type(&self) -> Option<IonType> {
+        self.expected
+    }
+
+    pub fn get_offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    pub fn get_kind(&self) -> &DecodeErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field.as_str() {
+            "" => write!(f, "failed to decode the top-level value")?,
+            field => write!(f, "failed to decode field `{field}`")?,
+        };
+
+        match (&self.kind, self.expected) {
+            (DecodeErrorKind::UnexpectedType(found), Some(expected)) => write!(f, ": expected {expected}, found {found}")?,
+            (DecodeErrorKind::UnexpectedType(found), None) => write!(f, ": unexpected {found}")?,
+            (DecodeErrorKind::Missing, Some(expected)) => write!(f, ": expected {expected}, but the payload ended")?,
+            (DecodeErrorKind::Missing, None) => write!(f, ": the payload ended")?,
+            (DecodeErrorKind::MissingField, Some(expected)) => write!(f, ": expected {expected}, but the field is absent")?,
+            (DecodeErrorKind::MissingField, None) => write!(f, ": the field is absent")?,
+            (DecodeErrorKind::Malformed(description), _) => write!(f, ": {description}")?,
+            (DecodeErrorKind::Invalid(description), _) => write!(f, ": {description}")?,
+        };
+
+        match self.offset {
+            Some(offset) => write!(f, " (byte offset {offset})"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for DecodeError { }
+
+pub(crate) fn join_field_path(parent: &str, child: &str) -> String {
+    match (parent, child) {
+        ("", child) => child.into(),
+        (parent, "") => parent.into(),
+        (parent, child) if child.starts_with('[') => format!("{parent}{child}"),
+        (parent, child) => format!("{parent}.{child}"),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonType;
+    use ion_rs::StreamItem;
+
+    use crate::core::decode_error::DecodeError;
+    use crate::core::decode_error::DecodeErrorKind;
+
+    #[test]
+    fn nested_error_is_rerooted_under_parent_field() {
+        let error = DecodeError::new(
+            "endpoints[1]",
+            Some(IonType::String),
+            Some(7),
+            DecodeErrorKind::UnexpectedType(StreamItem::Value(IonType::Int)),
+        ).nested("filters", Some(30));
+
+        assert_eq!(error.get_field(), "filters.endpoints[1]");
+        assert_eq!(error.get_expected_type(), Some(IonType::String));
+        assert_eq!(error.get_offset(), Some(37));
+    }
+
+    #[test]
+    fn display_mentions_field_type_and_offset() {
+        let error = DecodeError::new(
+            "start_date_time",
+            Some(IonType::Int),
+            Some(12),
+            DecodeErrorKind::UnexpectedType(StreamItem::Value(IonType::String)),
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "failed to decode field `start_date_time`: expected int, found string (byte offset 12)"
+        );
+    }
+}
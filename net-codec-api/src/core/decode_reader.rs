@@ -0,0 +1,818 @@
+use std::fmt::Display;
+use std::io;
+use std::io::Read;
+use std::str::FromStr;
+
+use ion_rs::BlockingRawBinaryReader;
+use ion_rs::IonError;
+use ion_rs::IonReader;
+use ion_rs::IonType;
+use ion_rs::Reader;
+use ion_rs::ReaderBuilder;
+use ion_rs::StreamItem;
+use ion_rs::Symbol;
+use ion_rs::UserReader;
+
+use crate::core::decode_error::join_field_path;
+use crate::core::decode_error::DecodeError;
+use crate::core::decode_error::DecodeErrorKind;
+use crate::core::ion_struct_api::IonStruct;
+use crate::core::struct_writer::SEGMENT_ANNOTATION;
+use crate::core::time_unit::TimeUnit;
+use crate::core::timestamp::Timestamp;
+use crate::core::try_decoder_api::TryDecoder;
+use crate::core::version::parse_version_annotation;
+use crate::core::version::LEGACY_VERSION;
+
+
+const ION_VERSION_MARKER: &[u8] = &[0xe0, 0x01, 0x00, 0xea];
+
+enum Source<'a> {
+    // Binary payloads are read through the concrete reader to have access to value offsets
+    Binary(UserReader<BlockingRawBinaryReader<io::Cursor<&'a [u8]>>>),
+    BinaryStream(UserReader<BlockingRawBinaryReader<io::BufReader<Box<dyn Read + 'a>>>>),
+    Text(Reader<'a>),
+}
+
+macro_rules! with_reader {
+    ($source:expr, $reader:ident => $body:expr) => {
+        match $source {
+            Source::Binary($reader) => $body,
+            Source::BinaryStream($reader) => $body,
+            Source::Text($reader) => $body,
+        }
+    };
+}
+
+/// A struct or list the reader has stepped into.
+struct Container {
+    field: String,
+    version: u32,
+    layout: Layout,
+}
+
+/// How the values of a container are laid out, see [`StructWriter`](crate::core::struct_writer::StructWriter).
+enum Layout {
+    Inline,
+    /// A top-level struct written as a sequence of segments, the reader being within one of them
+    /// or, when `in_segment` is `false`, on the top-level value following them.
+    Segments { in_segment: bool },
+    /// A list of a segmented struct, whose elements are the top-level values up to the next segment.
+    /// The reader is on that segment once the list is `ended`.
+    Elements { ended: bool },
+}
+
+/// A thin layer over the Ion reader which turns every failure into a [`DecodeError`]
+/// carrying the path of the field being read, the expected Ion type and the byte offset.
+pub struct DecodeReader<'a> {
+    source: Source<'a>,
+    path: Vec<Container>,
+    field: String,
+}
+
+impl<'a> DecodeReader<'a> {
+    /// Creates a reader positioned inside the top-level struct of `data`.
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut reader = Self::open(data)?;
+        reader.step_in_struct()?;
+        Ok(reader)
+    }
+
+    /// Creates a reader positioned on the top-level value of `data`.
+    pub fn open(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let source = if data.starts_with(ION_VERSION_MARKER) {
+            BlockingRawBinaryReader::new(io::Cursor::new(data)).map(|reader| Source::Binary(UserReader::new(reader)))
+        } else {
+            ReaderBuilder::new().build(data).map(Source::Text)
+        };
+
+        Self::from_source(source)
+    }
+
+    /// Creates a reader positioned inside the top-level struct read from `input`.
+    /// The input is read as the reader moves on rather than loaded at once.
+    pub fn from_reader<R: Read + 'a>(input: R) -> Result<Self, DecodeError> {
+        let mut reader = Self::open_reader(input)?;
+        reader.step_in_struct()?;
+        Ok(reader)
+    }
+
+    /// Creates a reader positioned on the top-level value read from `input`.
+    pub fn open_reader<R: Read + 'a>(mut input: R) -> Result<Self, DecodeError> {
+        // The marker is read ahead to detect the format and then handed back to the Ion reader
+        let mut marker = Vec::with_capacity(ION_VERSION_MARKER.len());
+        (&mut input).take(ION_VERSION_MARKER.len() as u64).read_to_end(&mut marker)
+            .map_err(|error| malformed("", None, error.into()))?;
+        let is_binary = marker == ION_VERSION_MARKER;
+        let input: Box<dyn Read + 'a> = Box::new(io::Cursor::new(marker).chain(input));
+        let input = io::BufReader::new(input);
+
+        let source = if is_binary {
+            BlockingRawBinaryReader::new(input).map(|reader| Source::BinaryStream(UserReader::new(reader)))
+        } else {
+            ReaderBuilder::new().build(input).map(Source::Text)
+        };
+
+        Self::from_source(source)
+    }
+
+    fn from_source(source: Result<Source<'a>, IonError>) -> Result<Self, DecodeError> {
+        let mut reader = DecodeReader {
+            source: source.map_err(|error| malformed("", None, error))?,
+            path: Vec::new(),
+            field: String::new(),
+        };
+        reader.advance()?;
+
+        Ok(reader)
+    }
+
+    /// Returns the schema version the innermost struct the reader is in is annotated with,
+    /// or [`LEGACY_VERSION`] for payloads written before versioning was introduced.
+    pub fn get_version(&self) -> u32 {
+        self.path.last().map_or(LEGACY_VERSION, |container| container.version)
+    }
+
+    /// Returns how many structs and lists deep the reader is.
+    pub fn get_depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Returns the Ion type of the current value, or `None` if it is a null or there is no value.
+    pub fn get_current_type(&self) -> Option<IonType> {
+        match self.current() {
+            StreamItem::Value(ion_type) => Some(ion_type),
+            _ => None,
+        }
+    }
+
+    /// Moves to the next value of the current container, which is expected to be `field`.
+    pub fn next_field(&mut self, field: &str) -> Result<(), DecodeError> {
+        self.field = field.into();
+        self.advance()
+    }
+
+    /// Moves to the next field of the current struct and returns its name,
+    /// or `None` once the struct is exhausted. Unread fields are skipped.
+    pub fn next_field_name(&mut self) -> Result<Option<String>, DecodeError> {
+        self.field = String::new();
+        if let StreamItem::Nothing = self.advance_item()? {
+            return Ok(None);
+        }
+        let field_name = with_reader!(&self.source, reader => reader.field_name())
+            .map_err(|error| self.malformed(error))?;
+        self.field = field_name.text().unwrap_or_default().to_owned();
+        Ok(Some(self.field.clone()))
+    }
+
+    /// Moves to `field` of the current struct, handing every field before it to `read_field` along with its name.
+    pub fn seek_field<F>(&mut self, field: &str, expected: IonType, mut read_field: F) -> Result<(), DecodeError>
+    where F: FnMut(&mut Self, &str) -> Result<(), DecodeError> {
+        loop {
+            match self.next_field_name()? {
+                Some(field_name) if field_name == field => return Ok(()),
+                Some(field_name) => read_field(self, &field_name)?,
+                None => return self.require(field, expected, None),
+            }
+        }
+    }
+
+    /// Unwraps a value collected by name, reporting `field` as missing from the current struct otherwise.
+    pub fn require<T>(&self, field: &str, expected: IonType, value: Option<T>) -> Result<T, DecodeError> {
+        value.ok_or_else(|| DecodeError::new(
+            &join_field_path(&self.get_container_path(), field),
+            Some(expected),
+            None,
+            DecodeErrorKind::MissingField,
+        ))
+    }
+
+    /// Reports `field` of the current struct as holding a value the DTO does not accept.
+    pub fn invalid(&self, field: &str, expected: IonType, description: &str) -> DecodeError {
+        DecodeError::new(
+            &join_field_path(&self.get_container_path(), field),
+            Some(expected),
+            None,
+            DecodeErrorKind::Invalid(description.into()),
+        )
+    }
+
+    pub fn step_in_struct(&mut self) -> Result<(), DecodeError> {
+        self.expect_value(IonType::Struct)?;
+        let version = self.read_version()?;
+        let layout = match self.path.is_empty() && self.has_segment_annotation()? {
+            true => Layout::Segments { in_segment: true },
+            false => Layout::Inline,
+        };
+        self.step_in(version, layout)
+    }
+
+    pub fn step_in_list(&mut self) -> Result<(), DecodeError> {
+        self.expect_value(IonType::List)?;
+        let in_segment = matches!(self.path.last(), Some(Container { layout: Layout::Segments { in_segment: true }, .. }));
+        if !(in_segment && self.has_segment_annotation()?) {
+            return self.step_in(self.get_version(), Layout::Inline);
+        }
+
+        // The list ends its segment, its elements follow it
+        self.step_out_of_source()?;
+        self.path.push(Container {
+            field: std::mem::take(&mut self.field),
+            version: self.get_version(),
+            layout: Layout::Elements { ended: false },
+        });
+        Ok(())
+    }
+
+    pub fn step_out(&mut self) -> Result<(), DecodeError> {
+        match self.path.last().map(|container| &container.layout) {
+            Some(Layout::Elements { .. }) => {
+                // Skips the elements which were not read, up to the segment the struct carries on with
+                while self.next_element_item()? != StreamItem::Nothing { }
+                if let Some(Container { layout, .. }) = self.path.iter_mut().rev().nth(1) {
+                    *layout = Layout::Segments { in_segment: false };
+                }
+            },
+            Some(Layout::Segments { in_segment: false }) => (),
+            _ => self.step_out_of_source()?,
+        }
+        self.field = self.path.pop().map(|container| container.field).unwrap_or_default();
+        Ok(())
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, DecodeError> {
+        self.expect_value(IonType::Int)?;
+        with_reader!(&mut self.source, reader => reader.read_i64())
+            .map_err(|error| self.malformed(error))
+    }
+
+    pub fn read_optional_i64(&mut self) -> Result<Option<i64>, DecodeError> {
+        match self.expect_nullable(IonType::Int)? {
+            true => self.read_i64().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, DecodeError> {
+        self.expect_value(IonType::Bool)?;
+        with_reader!(&mut self.source, reader => reader.read_bool())
+            .map_err(|error| self.malformed(error))
+    }
+
+    pub fn read_optional_bool(&mut self) -> Result<Option<bool>, DecodeError> {
+        match self.expect_nullable(IonType::Bool)? {
+            true => self.read_bool().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    pub fn read_string(&mut self) -> Result<String, DecodeError> {
+        self.expect_value(IonType::String)?;
+        with_reader!(&mut self.source, reader => reader.read_string())
+            .map(|string| string.text().to_owned())
+            .map_err(|error| self.malformed(error))
+    }
+
+    pub fn read_optional_string(&mut self) -> Result<Option<String>, DecodeError> {
+        match self.expect_nullable(IonType::String)? {
+            true => self.read_string().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    pub fn read_blob(&mut self) -> Result<Vec<u8>, DecodeError> {
+        self.expect_value(IonType::Blob)?;
+        with_reader!(&mut self.source, reader => reader.read_blob())
+            .map(|blob| blob.as_slice().to_vec())
+            .map_err(|error| self.malformed(error))
+    }
+
+    /// Reads a timestamp, written as an Ion timestamp, or as an int counting `legacy_unit`s from the Unix epoch,
+    /// which is how timestamps were written before. Ints are rejected when there is no `legacy_unit`.
+    pub fn read_timestamp(&mut self, legacy_unit: Option<TimeUnit>) -> Result<Timestamp, DecodeError> {
+        if let (StreamItem::Value(IonType::Int), Some(legacy_unit)) = (self.current(), legacy_unit) {
+            let amount = self.read_i64()?;
+            return Timestamp::from_amount(amount, legacy_unit)
+                .ok_or_else(|| self.invalid_value(IonType::Int, &format!("{amount} {legacy_unit:?} is out of range")));
+        }
+
+        self.expect_value(IonType::Timestamp)?;
+        let timestamp = with_reader!(&mut self.source, reader => reader.read_timestamp())
+            .map_err(|error| self.malformed(error))?;
+        Timestamp::try_from(timestamp).map_err(|error| self.invalid_value(IonType::Timestamp, &error.to_string()))
+    }
+
+    pub fn read_optional_timestamp(&mut self, legacy_unit: Option<TimeUnit>) -> Result<Option<Timestamp>, DecodeError> {
+        match self.current() {
+            StreamItem::Value(IonType::Int) if legacy_unit.is_some() => self.read_timestamp(legacy_unit).map(Some),
+            _ => self.read_optional(IonType::Timestamp, |reader| reader.read_timestamp(legacy_unit)),
+        }
+    }
+
+    /// Reads the current value with `read_value` unless it is a null, in which case `None` is returned.
+    pub fn read_optional<T, F>(&mut self, expected: IonType, read_value: F) -> Result<Option<T>, DecodeError>
+    where F: FnOnce(&mut Self) -> Result<T, DecodeError> {
+        match self.expect_nullable(expected)? {
+            true => read_value(self).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// Reads a DTO written as an inline struct, or as a blob holding its encoded payload,
+    /// which is how nested DTOs were written before.
+    pub fn read_struct<T: IonStruct>(&mut self) -> Result<T, DecodeError> {
+        match self.current() {
+            StreamItem::Value(IonType::Blob) => self.read_nested(),
+            _ => T::read_struct(self),
+        }
+    }
+
+    /// Reads a DTO written as an inline struct, or as a string it is parsed from, which is how it was written before.
+    pub fn read_parsed_struct<T>(&mut self) -> Result<T, DecodeError>
+    where T: IonStruct + FromStr, T::Err: Display {
+        match self.current() {
+            StreamItem::Value(IonType::String) => {
+                let text = self.read_string()?;
+                text.parse().map_err(|error| self.invalid_value(IonType::Struct, &format!("{text:?} is invalid: {error}")))
+            },
+            _ => self.read_struct(),
+        }
+    }
+
+    pub fn read_optional_parsed_struct<T>(&mut self) -> Result<Option<T>, DecodeError>
+    where T: IonStruct + FromStr, T::Err: Display {
+        match self.current() {
+            StreamItem::Value(IonType::String) => self.read_parsed_struct().map(Some),
+            _ => self.read_optional(IonType::Struct, |reader| reader.read_struct()),
+        }
+    }
+
+    /// Reads a value written as a string, e.g. an enum, with the `FromStr` impl of its type.
+    pub fn read_parsed<T>(&mut self) -> Result<T, DecodeError>
+    where T: FromStr, T::Err: Display {
+        let text = self.read_string()?;
+        text.parse().map_err(|error: T::Err| self.invalid_value(IonType::String, &error.to_string()))
+    }
+
+    /// Reads a DTO which was written as a blob holding its own encoded payload.
+    pub fn read_nested<T: TryDecoder>(&mut self) -> Result<T, DecodeError> {
+        let payload_offset = self.get_payload_offset();
+        let payload = self.read_blob()?;
+        T::try_decode(&payload).map_err(|error| error.nested(&self.get_field_path(), payload_offset))
+    }
+
+    /// Reads every element of the current list with `read_element`.
+    pub fn read_list<T, F>(&mut self, mut read_element: F) -> Result<Vec<T>, DecodeError>
+    where F: FnMut(&mut Self) -> Result<T, DecodeError> {
+        self.step_in_list()?;
+        let mut elements = Vec::new();
+        while self.next_element(elements.len())? {
+            elements.push(read_element(self)?);
+        }
+        self.step_out()?;
+        Ok(elements)
+    }
+
+    /// Moves to the element at `index` of the current list, the one after the current element,
+    /// and returns whether there is one. It lets a list be read one element at a time instead of all at once.
+    pub fn next_element(&mut self, index: usize) -> Result<bool, DecodeError> {
+        self.field = format!("[{index}]");
+        Ok(!matches!(self.advance_item()?, StreamItem::Nothing))
+    }
+
+    fn advance(&mut self) -> Result<(), DecodeError> {
+        self.advance_item().map(|_| ())
+    }
+
+    fn advance_item(&mut self) -> Result<StreamItem, DecodeError> {
+        match self.path.last().map(|container| &container.layout) {
+            Some(Layout::Segments { .. }) => self.next_segment_item(),
+            Some(Layout::Elements { .. }) => self.next_element_item(),
+            _ => self.next_source_item(),
+        }
+    }
+
+    fn next_source_item(&mut self) -> Result<StreamItem, DecodeError> {
+        with_reader!(&mut self.source, reader => reader.next())
+            .map_err(|error| self.malformed(error))
+    }
+
+    /// Moves to the next field of a segmented struct, going from one segment to the next.
+    fn next_segment_item(&mut self) -> Result<StreamItem, DecodeError> {
+        loop {
+            if let Some(Container { layout: Layout::Segments { in_segment: true }, .. }) = self.path.last() {
+                let item = self.next_source_item()?;
+                if item != StreamItem::Nothing {
+                    return Ok(item);
+                }
+                self.step_out_of_source()?;
+                self.next_source_item()?;
+                self.set_in_segment(false);
+            }
+
+            match self.current() {
+                StreamItem::Nothing => return Ok(StreamItem::Nothing),
+                StreamItem::Value(IonType::Struct) if self.has_segment_annotation()? => {
+                    with_reader!(&mut self.source, reader => reader.step_in())
+                        .map_err(|error| self.malformed(error))?;
+                    self.set_in_segment(true);
+                },
+                found => return Err(self.unexpected(IonType::Struct, found)),
+            }
+        }
+    }
+
+    /// Moves to the next element of a list of a segmented struct, the list ending at the next segment.
+    fn next_element_item(&mut self) -> Result<StreamItem, DecodeError> {
+        if let Some(Container { layout: Layout::Elements { ended: true }, .. }) = self.path.last() {
+            return Ok(StreamItem::Nothing);
+        }
+
+        let item = self.next_source_item()?;
+        if item == StreamItem::Nothing || self.has_segment_annotation()? {
+            if let Some(container) = self.path.last_mut() {
+                container.layout = Layout::Elements { ended: true };
+            }
+            return Ok(StreamItem::Nothing);
+        }
+        Ok(item)
+    }
+
+    fn set_in_segment(&mut self, in_segment: bool) {
+        if let Some(container) = self.path.last_mut() {
+            container.layout = Layout::Segments { in_segment };
+        }
+    }
+
+    fn read_annotations(&self) -> Result<Vec<Symbol>, DecodeError> {
+        with_reader!(&self.source, reader => reader.annotations().collect::<Result<Vec<_>, _>>())
+            .map_err(|error| self.malformed(error))
+    }
+
+    fn read_version(&self) -> Result<u32, DecodeError> {
+        Ok(self.read_annotations()?.iter()
+            .find_map(|annotation| annotation.text().and_then(parse_version_annotation))
+            .unwrap_or(LEGACY_VERSION))
+    }
+
+    fn has_segment_annotation(&self) -> Result<bool, DecodeError> {
+        Ok(self.read_annotations()?.iter().any(|annotation| annotation.text() == Some(SEGMENT_ANNOTATION)))
+    }
+
+    fn step_in(&mut self, version: u32, layout: Layout) -> Result<(), DecodeError> {
+        with_reader!(&mut self.source, reader => reader.step_in())
+            .map_err(|error| self.malformed(error))?;
+        self.path.push(Container {
+            field: std::mem::take(&mut self.field),
+            version,
+            layout,
+        });
+        Ok(())
+    }
+
+    fn step_out_of_source(&mut self) -> Result<(), DecodeError> {
+        with_reader!(&mut self.source, reader => reader.step_out())
+            .map_err(|error| malformed(&self.get_field_path(), None, error))
+    }
+
+    fn current(&self) -> StreamItem {
+        with_reader!(&self.source, reader => reader.current())
+    }
+
+    fn expect_value(&self, expected: IonType) -> Result<(), DecodeError> {
+        match self.current() {
+            StreamItem::Value(ion_type) if ion_type == expected => Ok(()),
+            found => Err(self.unexpected(expected, found)),
+        }
+    }
+
+    /// Returns whether the current value is a non-null value of the `expected` type.
+    fn expect_nullable(&self, expected: IonType) -> Result<bool, DecodeError> {
+        match self.current() {
+            StreamItem::Value(ion_type) if ion_type == expected => Ok(true),
+            StreamItem::Null(_) => Ok(false),
+            found => Err(self.unexpected(expected, found)),
+        }
+    }
+
+    fn get_container_path(&self) -> String {
+        self.path.iter().fold(String::new(), |path, container| join_field_path(&path, &container.field))
+    }
+
+    fn get_field_path(&self) -> String {
+        join_field_path(&self.get_container_path(), &self.field)
+    }
+
+    fn get_offset(&self) -> Option<usize> {
+        match &self.source {
+            Source::Binary(reader) => Some(reader.header_offset()),
+            // Ion only tracks offsets of payloads held in memory
+            Source::BinaryStream(_) | Source::Text(_) => None,
+        }
+    }
+
+    fn get_payload_offset(&self) -> Option<usize> {
+        match &self.source {
+            Source::Binary(reader) => Some(reader.value_offset()),
+            Source::BinaryStream(_) | Source::Text(_) => None,
+        }
+    }
+
+    fn unexpected(&self, expected: IonType, found: StreamItem) -> DecodeError {
+        let kind = match found {
+            StreamItem::Nothing => DecodeErrorKind::Missing,
+            found => DecodeErrorKind::UnexpectedType(found),
+        };
+        let offset = match found {
+            StreamItem::Nothing => None,
+            _ => self.get_offset(),
+        };
+        DecodeError::new(&self.get_field_path(), Some(expected), offset, kind)
+    }
+
+    fn invalid_value(&self, expected: IonType, description: &str) -> DecodeError {
+        DecodeError::new(&self.get_field_path(), Some(expected), self.get_offset(), DecodeErrorKind::Invalid(description.into()))
+    }
+
+    fn malformed(&self, error: IonError) -> DecodeError {
+        malformed(&self.get_field_path(), self.get_offset(), error)
+    }
+}
+
+fn malformed(field: &str, offset: Option<usize>, error: IonError) -> DecodeError {
+    DecodeError::new(field, None, offset, DecodeErrorKind::Malformed(error.to_string()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonType;
+    use ion_rs::IonWriter;
+    use ion_rs::StreamItem;
+
+    use crate::core::decode_error::DecodeError;
+    use crate::core::decode_error::DecodeErrorKind;
+    use crate::core::decode_reader::DecodeReader;
+    use crate::core::time_unit::TimeUnit;
+    use crate::core::timestamp::Timestamp;
+    use crate::core::try_decoder_api::TryDecoder;
+    use crate::core::version::LEGACY_VERSION;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Inner {
+        values: Vec<String>,
+    }
+
+    impl TryDecoder for Inner {
+        fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+            let mut reader = DecodeReader::new(data)?;
+
+            reader.next_field("values")?;
+            let values = reader.read_list(DecodeReader::read_string)?;
+
+            reader.step_out()?;
+
+            Ok(Inner { values })
+        }
+    }
+
+    fn encode_inner(values: &[&str], extra: Option<i64>) -> Vec<u8> {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("values");
+        writer.step_in(IonType::List).unwrap();
+        values.iter().for_each(|value| writer.write_string(value).unwrap());
+        if let Some(extra) = extra {
+            writer.write_i64(extra).unwrap();
+        }
+        writer.step_out().unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+        writer.output().as_slice().into()
+    }
+
+    fn encode_outer(id: i64, inner: &[u8]) -> Vec<u8> {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("id");
+        writer.write_i64(id).unwrap();
+        writer.set_field_name("inner");
+        writer.write_blob(inner).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+        writer.output().as_slice().into()
+    }
+
+    fn decode_outer(data: &[u8]) -> Result<(i64, Inner), DecodeError> {
+        let mut reader = DecodeReader::new(data)?;
+
+        reader.next_field("id")?;
+        let id = reader.read_i64()?;
+
+        reader.next_field("inner")?;
+        let inner = reader.read_nested::<Inner>()?;
+
+        reader.step_out()?;
+
+        Ok((id, inner))
+    }
+
+    #[test]
+    fn decodes_nested_payloads() {
+        let data = encode_outer(42, &encode_inner(&["a", "b"], None));
+        let inner = Inner { values: vec!["a".into(), "b".into()] };
+        assert_eq!(decode_outer(&data), Ok((42, inner)));
+    }
+
+    #[test]
+    fn reports_unexpected_type_with_path_and_absolute_offset() {
+        let inner = encode_inner(&["a"], Some(1));
+        let data = encode_outer(42, &inner);
+
+        let error = decode_outer(&data).unwrap_err();
+        assert_eq!(error.get_field(), "inner.values[1]");
+        assert_eq!(error.get_expected_type(), Some(IonType::String));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::UnexpectedType(StreamItem::Value(IonType::Int)));
+
+        let inner_offset = data.windows(inner.len()).position(|window| window == inner).unwrap();
+        let element_offset = error.get_offset().unwrap() - inner_offset;
+        assert_eq!(inner[element_offset], 0x21);
+    }
+
+    #[test]
+    fn reports_missing_fields_of_truncated_structs() {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("id");
+        writer.write_i64(42).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+        let data: Vec<u8> = writer.output().as_slice().into();
+
+        let error = decode_outer(&data).unwrap_err();
+        assert_eq!(error.get_field(), "inner");
+        assert_eq!(error.get_expected_type(), Some(IonType::Blob));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Missing);
+    }
+
+    #[test]
+    fn reports_malformed_payloads() {
+        let data = encode_outer(42, &encode_inner(&["a", "b"], None));
+
+        let error = decode_outer(&data[..data.len() - 3]).unwrap_err();
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Malformed(_)));
+
+        assert!(decode_outer(&[]).is_err());
+    }
+
+    fn decode_outer_by_name(data: &[u8]) -> Result<(i64, Inner), DecodeError> {
+        let mut reader = DecodeReader::new(data)?;
+
+        let mut id = None;
+        let mut inner = None;
+        while let Some(field_name) = reader.next_field_name()? {
+            match field_name.as_str() {
+                "id" => id = Some(reader.read_i64()?),
+                "inner" => inner = Some(reader.read_nested::<Inner>()?),
+                _ => (),
+            }
+        }
+
+        let id = reader.require("id", IonType::Int, id)?;
+        let inner = reader.require("inner", IonType::Blob, inner)?;
+
+        reader.step_out()?;
+
+        Ok((id, inner))
+    }
+
+    #[test]
+    fn decodes_fields_by_name_in_any_order() {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("inner");
+        writer.write_blob(encode_inner(&["a", "b"], None)).unwrap();
+        writer.set_field_name("unknown");
+        writer.step_in(IonType::List).unwrap();
+        writer.write_i64(1).unwrap();
+        writer.step_out().unwrap();
+        writer.set_field_name("id");
+        writer.write_i64(42).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+
+        let inner = Inner { values: vec!["a".into(), "b".into()] };
+        assert_eq!(decode_outer_by_name(writer.output().as_slice()), Ok((42, inner)));
+    }
+
+    #[test]
+    fn reports_missing_required_fields() {
+        let data = "{extra: \"value\", id: 42}";
+        let error = decode_outer_by_name(data.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "inner");
+        assert_eq!(error.get_expected_type(), Some(IonType::Blob));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::MissingField);
+    }
+
+    #[test]
+    fn decodes_text_payloads() {
+        let data = "{id: 42, inner: {{ }}}";
+        let error = decode_outer(data.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "inner");
+        assert_eq!(error.get_offset(), None);
+    }
+
+    #[test]
+    fn reads_the_schema_version_annotation() {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.set_annotations(["v3"]);
+        writer.step_in(IonType::Struct).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+        assert_eq!(DecodeReader::new(writer.output().as_slice()).unwrap().get_version(), 3);
+
+        assert_eq!(DecodeReader::new("origin::v2::{}".as_bytes()).unwrap().get_version(), 2);
+        assert_eq!(DecodeReader::new("{}".as_bytes()).unwrap().get_version(), LEGACY_VERSION);
+    }
+
+    #[test]
+    fn reads_timestamps_and_legacy_ints() {
+        let timestamp = Timestamp::from_millis(1_700_000_000_123).unwrap();
+        let mut reader = DecodeReader::new("{at: 2023-11-14T22:13:20.123Z, legacy: 1700000000123, unset: null.timestamp}".as_bytes()).unwrap();
+
+        reader.next_field("at").unwrap();
+        assert_eq!(reader.read_timestamp(None), Ok(timestamp));
+        reader.next_field("legacy").unwrap();
+        assert_eq!(reader.read_optional_timestamp(Some(TimeUnit::Milliseconds)), Ok(Some(timestamp)));
+        reader.next_field("unset").unwrap();
+        assert_eq!(reader.read_optional_timestamp(Some(TimeUnit::Milliseconds)), Ok(None));
+    }
+
+    #[test]
+    fn reports_ints_without_legacy_unit_and_out_of_range_ones() {
+        let data = format!("{{at: 1700000000123, legacy: {}}}", i64::MAX);
+        let mut reader = DecodeReader::new(data.as_bytes()).unwrap();
+
+        reader.next_field("at").unwrap();
+        let error = reader.read_timestamp(None).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::UnexpectedType(StreamItem::Value(IonType::Int)));
+
+        reader.next_field("legacy").unwrap();
+        let error = reader.read_timestamp(Some(TimeUnit::Seconds)).unwrap_err();
+        assert_eq!(error.get_field(), "legacy");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    /// Hands out a single byte per read, as a slow socket would.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl std::io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            match buf.first_mut() {
+                Some(byte) => *byte = *first,
+                None => return Ok(0),
+            }
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn decodes_payloads_pulled_from_readers() {
+        let data = encode_outer(42, &encode_inner(&["a", "b"], None));
+        let mut reader = DecodeReader::from_reader(Trickle(&data)).unwrap();
+        reader.next_field("id").unwrap();
+        assert_eq!(reader.read_i64(), Ok(42));
+        reader.next_field("inner").unwrap();
+        assert_eq!(reader.read_nested::<Inner>(), Ok(Inner { values: vec!["a".into(), "b".into()] }));
+        reader.step_out().unwrap();
+
+        let mut reader = DecodeReader::from_reader(Trickle("v2::{id: 42}".as_bytes())).unwrap();
+        assert_eq!(reader.get_version(), 2);
+        reader.next_field("id").unwrap();
+        assert_eq!(reader.read_i64(), Ok(42));
+
+        let error = DecodeReader::from_reader(Trickle(&[])).err().unwrap();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Missing);
+    }
+
+    #[test]
+    fn seeks_fields_handing_over_the_ones_before() {
+        let mut reader = DecodeReader::new("{id: 42, other: true, values: [], tail: 1}".as_bytes()).unwrap();
+
+        let mut skipped = Vec::new();
+        reader.seek_field("values", IonType::List, |reader, field| {
+            skipped.push(field.to_owned());
+            reader.read_optional_i64().map(|_| ()).or(Ok(()))
+        }).unwrap();
+        assert_eq!(skipped, ["id", "other"]);
+        assert_eq!(reader.get_current_type(), Some(IonType::List));
+
+        let error = reader.seek_field("missing", IonType::Int, |_, _| Ok(())).unwrap_err();
+        assert_eq!(error.get_field(), "missing");
+        assert_eq!(error.get_kind(), &DecodeErrorKind::MissingField);
+    }
+}
\ No newline at end of file
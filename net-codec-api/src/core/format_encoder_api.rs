@@ -0,0 +1,146 @@
+use std::io::Write;
+
+use ion_rs::BinaryWriterBuilder;
+use ion_rs::IonResult;
+use ion_rs::IonWriter;
+use ion_rs::TextWriterBuilder;
+
+use crate::core::ion_format::IonFormat;
+use crate::core::ion_struct_api::IonStruct;
+
+/// Encodes a DTO in the Ion format chosen by the caller,
+/// e.g. `dto.encode_as(IonFormat::Pretty)` to log it in a human-readable form.
+pub trait FormatEncoder {
+    /// Panics if the DTO cannot be written, see [`FormatEncoder::try_encode_as`].
+    fn encode_as(&self, format: IonFormat) -> Vec<u8>;
+
+    /// Encodes the DTO, failing instead of panicking if one of its values cannot be written.
+    fn try_encode_as(&self, format: IonFormat) -> IonResult<Vec<u8>>;
+
+    /// Encodes the DTO straight into `output`, e.g. a socket or a file, instead of returning its payload.
+    fn encode_to<O: Write>(&self, format: IonFormat, output: O) -> IonResult<()>;
+}
+
+impl<T: IonStruct> FormatEncoder for T {
+    fn encode_as(&self, format: IonFormat) -> Vec<u8> {
+        self.try_encode_as(format).unwrap_or_else(|error| panic!("the DTO cannot be encoded: {error}"))
+    }
+
+    fn try_encode_as(&self, format: IonFormat) -> IonResult<Vec<u8>> {
+        let mut output = Vec::new();
+        self.encode_to(format, &mut output)?;
+        Ok(output)
+    }
+
+    fn encode_to<O: Write>(&self, format: IonFormat, output: O) -> IonResult<()> {
+        match format {
+            IonFormat::Binary => write_struct(self, BinaryWriterBuilder::new().build(output)?),
+            IonFormat::Text => write_struct(self, TextWriterBuilder::compact().build(output)?),
+            IonFormat::Pretty => write_struct(self, TextWriterBuilder::pretty().build(output)?),
+        }
+    }
+}
+
+fn write_struct<T: IonStruct, W: IonWriter>(dto: &T, mut writer: W) -> IonResult<()> {
+    dto.write_struct(&mut writer)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonError;
+    use ion_rs::IonResult;
+    use ion_rs::IonType;
+    use ion_rs::IonWriter;
+
+    use crate::core::decode_error::DecodeError;
+    use crate::core::decode_reader::DecodeReader;
+    use crate::core::format_encoder_api::FormatEncoder;
+    use crate::core::ion_format::IonFormat;
+    use crate::core::ion_struct_api::IonStruct;
+    use crate::core::try_decoder_api::TryDecoder;
+    use crate::core::versioned_api::Versioned;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Point {
+        x: i64,
+    }
+
+    /// Fails to be written, as a DTO holding a value Ion cannot represent would.
+    struct Unwritable;
+
+    impl Versioned for Point {
+        fn get_version() -> u32 {
+            1
+        }
+    }
+
+    impl TryDecoder for Point {
+        fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+            Self::read_struct(&mut DecodeReader::open(data)?)
+        }
+    }
+
+    impl IonStruct for Point {
+        fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+            writer.set_annotations(["v1"]);
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("x");
+            writer.write_i64(self.x)?;
+            writer.step_out()
+        }
+
+        fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+            reader.step_in_struct()?;
+            reader.next_field("x")?;
+            let x = reader.read_i64()?;
+            reader.step_out()?;
+            Ok(Point { x })
+        }
+    }
+
+    impl Versioned for Unwritable {
+        fn get_version() -> u32 {
+            1
+        }
+    }
+
+    impl TryDecoder for Unwritable {
+        fn try_decode(_data: &[u8]) -> Result<Self, DecodeError> {
+            Ok(Unwritable)
+        }
+    }
+
+    impl IonStruct for Unwritable {
+        fn write_struct<W: IonWriter>(&self, _writer: &mut W) -> IonResult<()> {
+            Err(IonError::EncodingError { description: "unwritable".into() })
+        }
+
+        fn read_struct(_reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+            Ok(Unwritable)
+        }
+    }
+
+    #[test]
+    fn encodes_in_every_format() {
+        let point = Point { x: 42 };
+
+        assert!(point.encode_as(IonFormat::Binary).starts_with(&[0xe0, 0x01, 0x00, 0xea]));
+        assert_eq!(String::from_utf8(point.encode_as(IonFormat::Text)).unwrap(), "v1::{x: 42}");
+        assert!(String::from_utf8(point.encode_as(IonFormat::Pretty)).unwrap().contains('\n'));
+
+        for format in [IonFormat::Binary, IonFormat::Text, IonFormat::Pretty] {
+            assert_eq!(Point::try_decode(&point.encode_as(format)), Ok(Point { x: 42 }));
+
+            let mut output = Vec::new();
+            point.encode_to(format, &mut output).unwrap();
+            assert_eq!(output, point.encode_as(format));
+        }
+    }
+
+    #[test]
+    fn encoding_errors_are_returned() {
+        assert_eq!(Point { x: 42 }.try_encode_as(IonFormat::Text).unwrap(), Point { x: 42 }.encode_as(IonFormat::Text));
+        assert!(Unwritable.try_encode_as(IonFormat::Binary).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+//! Golden-file checks guarding the wire format of DTOs across releases.
+//!
+//! Every DTO keeps a directory of payloads, one per schema version it has been released with:
+//! `<golden_dir>/<name>/v<version>.10n`. The file of the current version must match
+//! what the encoder writes byte for byte, and every file, including those of older versions,
+//! must still decode to the same sample DTO.
+//!
+//! Hand-written Ion text fixtures, `v<version>.ion`, may sit next to them and have to decode to the sample too.
+//! The sample itself must survive a round trip through every [`IonFormat`].
+//!
+//! Files of new versions are written by running the tests with `NET_API_BLESS=1`.
+//! Existing files are never rewritten, they are the record of what has been released.
+
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use net_core_api::core::encoder_api::Encoder;
+
+use crate::core::format_encoder_api::FormatEncoder;
+use crate::core::ion_format::IonFormat;
+use crate::core::ion_struct_api::IonStruct;
+use crate::core::version::parse_version_annotation;
+
+
+const BLESS_VARIABLE: &str = "NET_API_BLESS";
+const GOLDEN_EXTENSION: &str = "10n";
+const FIXTURE_EXTENSION: &str = "ion";
+
+pub fn check_golden<T>(golden_dir: &str, name: &str, dto: &T)
+where T: Encoder + IonStruct + PartialEq + Debug {
+    let dir = Path::new(golden_dir).join(name);
+    let current_path = dir.join(format!("v{}.{GOLDEN_EXTENSION}", T::get_version()));
+    let encoded = dto.encode();
+
+    if !current_path.exists() {
+        assert!(
+            env::var_os(BLESS_VARIABLE).is_some(),
+            "golden file {} is missing, run the tests with {BLESS_VARIABLE}=1 to create it",
+            current_path.display(),
+        );
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&current_path, &encoded).unwrap();
+    }
+
+    assert_eq!(
+        fs::read(&current_path).unwrap(),
+        encoded,
+        "{name} no longer encodes to {}, the wire format of version {} has changed",
+        current_path.display(),
+        T::get_version(),
+    );
+
+    for entry in fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        let version = path.file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(parse_version_annotation);
+        let is_payload = path.extension()
+            .is_some_and(|extension| extension == GOLDEN_EXTENSION || extension == FIXTURE_EXTENSION);
+        if version.is_none() || !is_payload {
+            continue;
+        }
+
+        let golden = fs::read(&path).unwrap();
+        assert_eq!(
+            T::try_decode(&golden).as_ref(),
+            Ok(dto),
+            "{} does not decode to the {name} sample",
+            path.display(),
+        );
+    }
+
+    for format in [IonFormat::Binary, IonFormat::Text, IonFormat::Pretty] {
+        assert_eq!(
+            T::try_decode(&dto.encode_as(format)).as_ref(),
+            Ok(dto),
+            "{name} does not survive a round trip through {format:?}",
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+/// The Ion encoding a DTO is written with.
+///
+/// Decoders don't need to be told the format, they detect it from the payload itself.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum IonFormat {
+    /// Ion binary, which is what `Encoder::encode` writes.
+    #[default]
+    Binary,
+    /// Ion text on a single line.
+    Text,
+    /// Ion text indented over several lines, meant for logs and hand-written fixtures.
+    Pretty,
+}
\ No newline at end of file
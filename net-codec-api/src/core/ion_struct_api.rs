@@ -0,0 +1,17 @@
+use ion_rs::IonResult;
+use ion_rs::IonWriter;
+
+use crate::core::decode_error::DecodeError;
+use crate::core::decode_reader::DecodeReader;
+use crate::core::try_decoder_api::TryDecoder;
+use crate::core::versioned_api::Versioned;
+
+/// A DTO written as an Ion struct annotated with its version,
+/// which lets other DTOs embed it inline instead of wrapping its encoded payload into a blob.
+pub trait IonStruct : TryDecoder + Versioned {
+    /// Writes the DTO as a struct into the container `writer` is in.
+    fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()>;
+
+    /// Reads the DTO from the struct `reader` is positioned on.
+    fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> where Self : Sized;
+}
\ No newline at end of file
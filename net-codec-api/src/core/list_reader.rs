@@ -0,0 +1,148 @@
+use std::marker::PhantomData;
+
+use crate::core::decode_error::DecodeError;
+use crate::core::decode_reader::DecodeReader;
+use crate::core::ion_struct_api::IonStruct;
+
+
+/// Reads the DTOs of a list one at a time, so that a large collection can be processed
+/// without holding all of its elements in memory.
+///
+/// The iterator stops after the first element it fails to decode.
+pub struct ListReader<'a, T> {
+    reader: DecodeReader<'a>,
+    depth: usize,
+    next_index: Option<usize>,
+    element: PhantomData<T>,
+}
+
+impl<'a, T: IonStruct> ListReader<'a, T> {
+    /// Steps into the list `reader` is positioned on.
+    pub fn new(mut reader: DecodeReader<'a>) -> Result<Self, DecodeError> {
+        reader.step_in_list()?;
+        let depth = reader.get_depth();
+        Ok(ListReader { reader, depth, next_index: Some(0), element: PhantomData })
+    }
+
+    /// Steps out of the list, skipping the elements which were not read,
+    /// and hands the reader back to read the fields following the list.
+    pub fn finish(mut self) -> Result<DecodeReader<'a>, DecodeError> {
+        // An element which failed to decode may have left the reader inside of it
+        while self.reader.get_depth() >= self.depth {
+            self.reader.step_out()?;
+        }
+        Ok(self.reader)
+    }
+}
+
+impl<T: IonStruct> Iterator for ListReader<'_, T> {
+    type Item = Result<T, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_index?;
+        let element = match self.reader.next_element(index) {
+            Ok(true) => self.reader.read_struct::<T>(),
+            Ok(false) => {
+                self.next_index = None;
+                return None;
+            },
+            Err(error) => Err(error),
+        };
+
+        self.next_index = element.is_ok().then_some(index + 1);
+        Some(element)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonResult;
+    use ion_rs::IonType;
+    use ion_rs::IonWriter;
+
+    use crate::core::decode_error::DecodeError;
+    use crate::core::decode_reader::DecodeReader;
+    use crate::core::ion_struct_api::IonStruct;
+    use crate::core::list_reader::ListReader;
+    use crate::core::try_decoder_api::TryDecoder;
+    use crate::core::versioned_api::Versioned;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Point {
+        x: i64,
+    }
+
+    impl Versioned for Point {
+        fn get_version() -> u32 {
+            1
+        }
+    }
+
+    impl TryDecoder for Point {
+        fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+            Self::read_struct(&mut DecodeReader::open(data)?)
+        }
+    }
+
+    impl IonStruct for Point {
+        fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("x");
+            writer.write_i64(self.x)?;
+            writer.step_out()
+        }
+
+        fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+            reader.step_in_struct()?;
+            reader.next_field("x")?;
+            let x = reader.read_i64()?;
+            reader.step_out()?;
+            Ok(Point { x })
+        }
+    }
+
+    fn open_points(data: &str) -> ListReader<'_, Point> {
+        let mut reader = DecodeReader::new(data.as_bytes()).unwrap();
+        reader.next_field("points").unwrap();
+        ListReader::new(reader).unwrap()
+    }
+
+    #[test]
+    fn elements_are_read_one_at_a_time() {
+        let mut points = open_points("{points: [{x: 1}, {x: 2}], count: 2}");
+
+        assert_eq!(points.next(), Some(Ok(Point { x: 1 })));
+        assert_eq!(points.next(), Some(Ok(Point { x: 2 })));
+        assert_eq!(points.next(), None);
+        assert_eq!(points.next(), None);
+
+        let mut reader = points.finish().unwrap();
+        reader.next_field("count").unwrap();
+        assert_eq!(reader.read_i64(), Ok(2));
+    }
+
+    #[test]
+    fn unread_elements_are_skipped() {
+        let mut points = open_points("{points: [{x: 1}, {x: 2}], count: 2}");
+        assert_eq!(points.next(), Some(Ok(Point { x: 1 })));
+
+        let mut reader = points.finish().unwrap();
+        reader.next_field("count").unwrap();
+        assert_eq!(reader.read_i64(), Ok(2));
+    }
+
+    #[test]
+    fn reader_stops_at_the_first_invalid_element() {
+        let mut points = open_points("{points: [{x: 1}, {x: \"2\"}, {x: 3}], count: 3}");
+
+        assert_eq!(points.next(), Some(Ok(Point { x: 1 })));
+        let error = points.next().unwrap().unwrap_err();
+        assert_eq!(error.get_field(), "points[1].x");
+        assert_eq!(points.next(), None);
+
+        let mut reader = points.finish().unwrap();
+        reader.next_field("count").unwrap();
+        assert_eq!(reader.read_i64(), Ok(3));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+pub mod aliased_api;
+pub mod compression;
+pub mod compression_error;
+#[cfg(feature = "testing")]
+pub mod data_types;
+pub mod decode_error;
+pub mod decode_reader;
+pub mod format_encoder_api;
+#[cfg(feature = "testing")]
+pub mod golden;
+pub mod ion_format;
+pub mod ion_struct_api;
+pub mod list_reader;
+pub mod struct_writer;
+pub mod time_unit;
+pub mod time_unit_error;
+pub mod timestamp;
+pub mod try_decoder_api;
+pub mod version;
+pub mod versioned_api;
\ No newline at end of file
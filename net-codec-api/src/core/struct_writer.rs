@@ -0,0 +1,332 @@
+use std::io::Write;
+
+use ion_rs::BinaryWriter;
+use ion_rs::BinaryWriterBuilder;
+use ion_rs::IonResult;
+use ion_rs::IonType;
+use ion_rs::IonWriter;
+use ion_rs::TextWriter;
+use ion_rs::TextWriterBuilder;
+
+use crate::core::ion_format::IonFormat;
+use crate::core::ion_struct_api::IonStruct;
+use crate::core::version::version_annotation;
+
+
+/// Annotates the segments of a struct written in binary, and the lists ending them.
+pub(crate) const SEGMENT_ANNOTATION: &str = "segment";
+
+/// The binary encoder keeps every byte it encodes, so it is replaced once it has encoded that many.
+const MAX_ENCODED_LEN: usize = 64 * 1024;
+
+enum Sink<W: Write> {
+    Binary { encoder: Box<BinaryWriter<Vec<u8>>>, encoded_len: usize, output: W },
+    Text(TextWriter<W>),
+}
+
+macro_rules! with_writer {
+    ($sink:expr, $writer:ident => $body:expr) => {
+        match $sink {
+            Sink::Binary { encoder: $writer, .. } => {
+                let $writer = $writer.as_mut();
+                $body
+            },
+            Sink::Text($writer) => $body,
+        }
+    };
+}
+
+/// Writes the top-level struct of a DTO into `output` field by field, so that the elements
+/// of a large collection are encoded as they are produced instead of being collected first.
+/// Fields are written the way the derived encoder writes them, so the payload decodes as the DTO.
+///
+/// Binary Ion prefixes containers with their length, so in binary the struct is split into top-level values
+/// which reach `output` one at a time: segments annotated `segment` holding the fields, each list ending a segment
+/// with an empty `segment::[]` followed by its elements, e.g. `v2::segment::{points: segment::[]} v1::{x: 1} segment::{}`.
+/// [`DecodeReader`](crate::core::decode_reader::DecodeReader) reads it as the struct it is split from.
+pub struct StructWriter<W: Write> {
+    sink: Sink<W>,
+}
+
+impl<W: Write> StructWriter<W> {
+    /// Starts the top-level struct of a DTO of schema `version`.
+    pub fn new(output: W, format: IonFormat, version: u32) -> IonResult<Self> {
+        let (sink, annotations) = match format {
+            IonFormat::Binary => (
+                Sink::Binary { encoder: Box::new(BinaryWriterBuilder::new().build(Vec::new())?), encoded_len: 0, output },
+                vec![version_annotation(version), SEGMENT_ANNOTATION.into()],
+            ),
+            IonFormat::Text => (Sink::Text(TextWriterBuilder::compact().build(output)?), vec![version_annotation(version)]),
+            IonFormat::Pretty => (Sink::Text(TextWriterBuilder::pretty().build(output)?), vec![version_annotation(version)]),
+        };
+
+        let mut writer = StructWriter { sink };
+        with_writer!(&mut writer.sink, writer => {
+            writer.set_annotations(annotations);
+            writer.step_in(IonType::Struct)
+        })?;
+
+        Ok(writer)
+    }
+
+    /// Writes `field` holding `value`, or a null struct if it is unset.
+    pub fn write_optional_struct<T: IonStruct>(&mut self, field: &str, value: Option<&T>) -> IonResult<()> {
+        with_writer!(&mut self.sink, writer => {
+            writer.set_field_name(field);
+            match value {
+                Some(value) => value.write_struct(writer),
+                None => writer.write_null(IonType::Struct),
+            }
+        })
+    }
+
+    /// Starts the list `field`, which elements are then written into one by one.
+    pub fn begin_list(&mut self, field: &str) -> IonResult<()> {
+        match &mut self.sink {
+            Sink::Binary { encoder, .. } => {
+                encoder.set_field_name(field);
+                encoder.set_annotations([SEGMENT_ANNOTATION]);
+                encoder.step_in(IonType::List)?;
+                encoder.step_out()?;
+                encoder.step_out()?;
+                self.flush_binary()
+            },
+            Sink::Text(writer) => {
+                writer.set_field_name(field);
+                writer.step_in(IonType::List)
+            },
+        }
+    }
+
+    /// Writes `element` into the list being written.
+    pub fn write_element<T: IonStruct>(&mut self, element: &T) -> IonResult<()> {
+        with_writer!(&mut self.sink, writer => element.write_struct(writer))?;
+        self.flush_binary()
+    }
+
+    pub fn end_list(&mut self) -> IonResult<()> {
+        match &mut self.sink {
+            Sink::Binary { encoder, .. } => {
+                encoder.set_annotations([SEGMENT_ANNOTATION]);
+                encoder.step_in(IonType::Struct)
+            },
+            Sink::Text(writer) => writer.step_out(),
+        }
+    }
+
+    /// Ends the struct and flushes it into the output.
+    pub fn finish(mut self) -> IonResult<()> {
+        with_writer!(&mut self.sink, writer => writer.step_out())?;
+        self.flush_binary()?;
+        match &mut self.sink {
+            Sink::Binary { output, .. } => Ok(output.flush()?),
+            Sink::Text(writer) => writer.flush(),
+        }
+    }
+
+    /// Hands the top-level values encoded in binary over to the output, replacing the encoder when it has encoded enough.
+    fn flush_binary(&mut self) -> IonResult<()> {
+        if let Sink::Binary { encoder, encoded_len, output } = &mut self.sink {
+            encoder.flush()?;
+            let encoded = encoder.output_mut();
+            output.write_all(encoded)?;
+            *encoded_len += encoded.len();
+            encoded.clear();
+
+            if *encoded_len >= MAX_ENCODED_LEN {
+                **encoder = BinaryWriterBuilder::new().build(Vec::new())?;
+                *encoded_len = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::element::Element;
+    use ion_rs::IonResult;
+    use ion_rs::IonType;
+    use ion_rs::IonWriter;
+
+    use crate::core::decode_error::DecodeError;
+    use crate::core::decode_reader::DecodeReader;
+    use crate::core::format_encoder_api::FormatEncoder;
+    use crate::core::ion_format::IonFormat;
+    use crate::core::ion_struct_api::IonStruct;
+    use crate::core::list_reader::ListReader;
+    use crate::core::struct_writer::Sink;
+    use crate::core::struct_writer::StructWriter;
+    use crate::core::struct_writer::MAX_ENCODED_LEN;
+    use crate::core::try_decoder_api::TryDecoder;
+    use crate::core::version::version_annotation;
+    use crate::core::versioned_api::Versioned;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Point {
+        x: i64,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Path {
+        points: Vec<Point>,
+        end: Option<Point>,
+    }
+
+    impl Versioned for Point {
+        fn get_version() -> u32 {
+            1
+        }
+    }
+
+    impl TryDecoder for Point {
+        fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+            Self::read_struct(&mut DecodeReader::open(data)?)
+        }
+    }
+
+    impl IonStruct for Point {
+        fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+            writer.set_annotations([version_annotation(Self::get_version())]);
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("x");
+            writer.write_i64(self.x)?;
+            writer.step_out()
+        }
+
+        fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+            reader.step_in_struct()?;
+            reader.next_field("x")?;
+            let x = reader.read_i64()?;
+            reader.step_out()?;
+            Ok(Point { x })
+        }
+    }
+
+    impl Versioned for Path {
+        fn get_version() -> u32 {
+            2
+        }
+    }
+
+    impl TryDecoder for Path {
+        fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+            Self::read_struct(&mut DecodeReader::open(data)?)
+        }
+    }
+
+    impl IonStruct for Path {
+        fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+            writer.set_annotations([version_annotation(Self::get_version())]);
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("points");
+            writer.step_in(IonType::List)?;
+            self.points.iter().try_for_each(|point| point.write_struct(writer))?;
+            writer.step_out()?;
+            writer.set_field_name("end");
+            match &self.end {
+                Some(end) => end.write_struct(writer)?,
+                None => writer.write_null(IonType::Struct)?,
+            }
+            writer.step_out()
+        }
+
+        fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+            reader.step_in_struct()?;
+            reader.next_field("points")?;
+            let points = reader.read_list(|reader| reader.read_struct())?;
+            reader.next_field("end")?;
+            let end = reader.read_optional(IonType::Struct, |reader| reader.read_struct())?;
+            reader.step_out()?;
+            Ok(Path { points, end })
+        }
+    }
+
+    fn write_path(format: IonFormat, points: &[Point], end: Option<&Point>) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut writer = StructWriter::new(&mut output, format, Path::get_version()).unwrap();
+        writer.begin_list("points").unwrap();
+        points.iter().for_each(|point| writer.write_element(point).unwrap());
+        writer.end_list().unwrap();
+        writer.write_optional_struct("end", end).unwrap();
+        writer.finish().unwrap();
+        output
+    }
+
+    fn get_path() -> Path {
+        Path { points: vec![Point { x: 1 }, Point { x: 2 }], end: Some(Point { x: 3 }) }
+    }
+
+    #[test]
+    fn writes_the_payload_of_the_whole_dto() {
+        let path = get_path();
+
+        for format in [IonFormat::Text, IonFormat::Pretty] {
+            let data = write_path(format, &path.points, path.end.as_ref());
+            assert_eq!(data, path.encode_as(format));
+            assert_eq!(Path::try_decode(&data), Ok(get_path()));
+        }
+    }
+
+    #[test]
+    fn writes_binary_in_segments_read_as_the_dto() {
+        let path = get_path();
+        let data = write_path(IonFormat::Binary, &path.points, path.end.as_ref());
+
+        let expected = Element::read_all("v2::segment::{points: segment::[]} v1::{x: 1} v1::{x: 2} segment::{end: v1::{x: 3}}").unwrap();
+        assert_eq!(Element::read_all(&data).unwrap(), expected);
+        assert_eq!(Path::try_decode(&data), Ok(get_path()));
+        assert_eq!(Path::read_struct(&mut DecodeReader::open_reader(data.as_slice()).unwrap()), Ok(get_path()));
+
+        let data = write_path(IonFormat::Binary, &[], None);
+        assert_eq!(Path::try_decode(&data), Ok(Path { points: Vec::new(), end: None }));
+    }
+
+    #[test]
+    fn skips_the_binary_elements_which_are_not_read() {
+        let path = get_path();
+        let data = write_path(IonFormat::Binary, &path.points, path.end.as_ref());
+
+        let mut reader = DecodeReader::new(&data).unwrap();
+        reader.next_field("points").unwrap();
+        let mut points = ListReader::<Point>::new(reader).unwrap();
+        assert_eq!(points.next(), Some(Ok(Point { x: 1 })));
+
+        let mut reader = points.finish().unwrap();
+        reader.next_field("end").unwrap();
+        assert_eq!(reader.read_struct(), Ok(Point { x: 3 }));
+        assert_eq!(reader.next_field_name(), Ok(None));
+    }
+
+    #[test]
+    fn binary_elements_reach_the_output_as_they_are_written() {
+        const POINT_COUNT: i64 = 50_000;
+
+        let mut output = Vec::new();
+        let mut writer = StructWriter::new(&mut output, IonFormat::Binary, Path::get_version()).unwrap();
+        writer.begin_list("points").unwrap();
+        let mut written_len = 0;
+        for x in 0..POINT_COUNT {
+            writer.write_element(&Point { x }).unwrap();
+
+            let Sink::Binary { encoded_len, output, .. } = &writer.sink else { unreachable!() };
+            assert!(output.len() > written_len);
+            assert!(*encoded_len < MAX_ENCODED_LEN);
+            written_len = output.len();
+        }
+        writer.end_list().unwrap();
+        writer.write_optional_struct::<Point>("end", None).unwrap();
+        writer.finish().unwrap();
+
+        let path = Path::try_decode(&output).unwrap();
+        assert_eq!(path.points.len(), POINT_COUNT as usize);
+        assert_eq!(path.points.last(), Some(&Point { x: POINT_COUNT - 1 }));
+    }
+
+    #[test]
+    fn writes_empty_lists_and_unset_fields() {
+        let data = write_path(IonFormat::Text, &[], None);
+        assert_eq!(String::from_utf8(data).unwrap(), "v2::{points: [], end: null.struct}");
+    }
+}
\ No newline at end of file
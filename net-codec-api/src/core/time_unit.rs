@@ -0,0 +1,121 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::core::time_unit_error::TimeUnitError;
+
+/// Unit a point in time or a duration is expressed in, points in time being counted from the Unix epoch.
+/// It is written by name, e.g. `milliseconds`, the `serde` feature included.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TimeUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimeUnit {
+    pub const ALL: [TimeUnit; 4] = [TimeUnit::Seconds, TimeUnit::Milliseconds, TimeUnit::Microseconds, TimeUnit::Nanoseconds];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TimeUnit::Seconds => "seconds",
+            TimeUnit::Milliseconds => "milliseconds",
+            TimeUnit::Microseconds => "microseconds",
+            TimeUnit::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// How many nanoseconds one unit lasts.
+    pub fn get_nanoseconds(&self) -> i64 {
+        match self {
+            TimeUnit::Seconds => 1_000_000_000,
+            TimeUnit::Milliseconds => 1_000_000,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Nanoseconds => 1,
+        }
+    }
+
+    pub fn to_duration(&self, amount: u64) -> Duration {
+        match self {
+            TimeUnit::Seconds => Duration::from_secs(amount),
+            TimeUnit::Milliseconds => Duration::from_millis(amount),
+            TimeUnit::Microseconds => Duration::from_micros(amount),
+            TimeUnit::Nanoseconds => Duration::from_nanos(amount),
+        }
+    }
+
+    /// The whole amount of units in `duration`, rounded down.
+    pub fn to_amount(&self, duration: Duration) -> u128 {
+        match self {
+            TimeUnit::Seconds => duration.as_secs().into(),
+            TimeUnit::Milliseconds => duration.as_millis(),
+            TimeUnit::Microseconds => duration.as_micros(),
+            TimeUnit::Nanoseconds => duration.as_nanos(),
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl FromStr for TimeUnit {
+    type Err = TimeUnitError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        TimeUnit::ALL.into_iter()
+            .find(|unit| unit.get_name() == name)
+            .ok_or_else(|| TimeUnitError::UnknownUnit(name.into()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TimeUnit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.get_name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TimeUnit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::core::time_unit::TimeUnit;
+    use crate::core::time_unit_error::TimeUnitError;
+
+    #[test]
+    fn amounts_are_converted_according_to_the_unit() {
+        assert_eq!(TimeUnit::Seconds.to_duration(90), Duration::from_secs(90));
+        assert_eq!(TimeUnit::Milliseconds.to_duration(90), Duration::from_millis(90));
+        assert_eq!(TimeUnit::Microseconds.to_duration(90), Duration::from_micros(90));
+        assert_eq!(TimeUnit::Nanoseconds.to_duration(90), Duration::from_nanos(90));
+    }
+
+    #[test]
+    fn durations_are_converted_according_to_the_unit() {
+        assert_eq!(TimeUnit::Seconds.to_amount(Duration::from_millis(90_500)), 90);
+        assert_eq!(TimeUnit::Milliseconds.to_amount(Duration::from_secs(90)), 90_000);
+        assert_eq!(TimeUnit::Microseconds.to_amount(Duration::from_millis(90)), 90_000);
+        assert_eq!(TimeUnit::Nanoseconds.to_amount(Duration::from_micros(90)), 90_000);
+    }
+
+    #[test]
+    fn units_are_parsed_from_their_name() {
+        for unit in TimeUnit::ALL {
+            assert_eq!(unit.to_string().parse(), Ok(unit));
+        }
+        assert_eq!("ms".parse::<TimeUnit>(), Err(TimeUnitError::UnknownUnit("ms".into())));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why a time unit could not be parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TimeUnitError {
+    /// The name is none of `seconds`, `milliseconds`, `microseconds` or `nanoseconds`.
+    UnknownUnit(String),
+}
+
+impl fmt::Display for TimeUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeUnitError::UnknownUnit(unit) => write!(
+                f,
+                "{unit:?} is not a time unit, expected `seconds`, `milliseconds`, `microseconds` or `nanoseconds`",
+            ),
+        }
+    }
+}
+
+impl Error for TimeUnitError { }
\ No newline at end of file
@@ -0,0 +1,271 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::FixedOffset;
+use chrono::NaiveDateTime;
+use chrono::SecondsFormat;
+use chrono::Timelike;
+use chrono::Utc;
+
+use crate::core::time_unit::TimeUnit;
+
+
+/// A point in time with nanosecond precision, counted from the Unix epoch, which covers the years 1677 to 2262.
+///
+/// DTOs write it as a native Ion timestamp in UTC, with as many fractional second digits as it needs,
+/// and the `serde` feature as an RFC 3339 string, e.g. `2023-11-14T22:13:20.123Z`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Timestamp {
+    nanoseconds: i64,
+}
+
+impl Timestamp {
+    pub const UNIX_EPOCH: Timestamp = Timestamp::from_nanos(0);
+    pub const MIN: Timestamp = Timestamp::from_nanos(i64::MIN);
+    pub const MAX: Timestamp = Timestamp::from_nanos(i64::MAX);
+
+    pub const fn from_nanos(nanoseconds: i64) -> Self {
+        Timestamp { nanoseconds }
+    }
+
+    /// The timestamp `amount` units after the Unix epoch, or `None` if it lies outside the years timestamps cover.
+    pub fn from_amount(amount: i64, unit: TimeUnit) -> Option<Self> {
+        amount.checked_mul(unit.get_nanoseconds()).map(Timestamp::from_nanos)
+    }
+
+    pub fn from_micros(microseconds: i64) -> Option<Self> {
+        Timestamp::from_amount(microseconds, TimeUnit::Microseconds)
+    }
+
+    pub fn from_millis(milliseconds: i64) -> Option<Self> {
+        Timestamp::from_amount(milliseconds, TimeUnit::Milliseconds)
+    }
+
+    pub fn from_secs(seconds: i64) -> Option<Self> {
+        Timestamp::from_amount(seconds, TimeUnit::Seconds)
+    }
+
+    pub fn now() -> Self {
+        SystemTime::now().try_into().expect("the system clock lies within the years timestamps cover")
+    }
+
+    pub fn as_nanos(&self) -> i64 {
+        self.nanoseconds
+    }
+
+    /// The whole amount of units since the Unix epoch, rounded down.
+    pub fn to_amount(&self, unit: TimeUnit) -> i64 {
+        self.nanoseconds.div_euclid(unit.get_nanoseconds())
+    }
+
+    pub fn as_micros(&self) -> i64 {
+        self.to_amount(TimeUnit::Microseconds)
+    }
+
+    pub fn as_millis(&self) -> i64 {
+        self.to_amount(TimeUnit::Milliseconds)
+    }
+
+    pub fn as_secs(&self) -> i64 {
+        self.to_amount(TimeUnit::Seconds)
+    }
+
+    /// The time elapsed between this timestamp and `other`, whichever comes first.
+    pub fn abs_diff(&self, other: Timestamp) -> Duration {
+        Duration::from_nanos(self.nanoseconds.abs_diff(other.nanoseconds))
+    }
+
+    fn to_date_time(self) -> DateTime<Utc> {
+        DateTime::from_timestamp_nanos(self.nanoseconds)
+    }
+
+    fn from_date_time(date_time: DateTime<Utc>) -> Result<Self, TimestampError> {
+        date_time.timestamp_nanos_opt()
+            .map(Timestamp::from_nanos)
+            .ok_or(TimestampError::OutOfRange)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        let since_epoch = Duration::from_nanos(timestamp.nanoseconds.unsigned_abs());
+        match timestamp.nanoseconds < 0 {
+            true => UNIX_EPOCH - since_epoch,
+            false => UNIX_EPOCH + since_epoch,
+        }
+    }
+}
+
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let nanoseconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => i128::try_from(since_epoch.as_nanos()),
+            Err(error) => i128::try_from(error.duration().as_nanos()).map(|nanoseconds| -nanoseconds),
+        };
+        nanoseconds.ok()
+            .and_then(|nanoseconds| i64::try_from(nanoseconds).ok())
+            .map(Timestamp::from_nanos)
+            .ok_or(TimestampError::OutOfRange)
+    }
+}
+
+impl From<Timestamp> for ion_rs::Timestamp {
+    fn from(timestamp: Timestamp) -> Self {
+        let date_time = timestamp.to_date_time();
+        let builder = ion_rs::Timestamp::with_ymd(date_time.year() as u32, date_time.month(), date_time.day())
+            .with_hms(date_time.hour(), date_time.minute(), date_time.second());
+
+        // Fractional seconds are written with as few digits as they need
+        let nanoseconds = date_time.nanosecond();
+        let builder = match nanoseconds {
+            0 => builder,
+            _ if nanoseconds % 1_000_000 == 0 => builder.with_milliseconds(nanoseconds / 1_000_000),
+            _ if nanoseconds % 1_000 == 0 => builder.with_microseconds(nanoseconds / 1_000),
+            _ => builder.with_nanoseconds(nanoseconds),
+        };
+        builder.build_at_offset(0).expect("the years timestamps cover are supported by Ion")
+    }
+}
+
+impl TryFrom<ion_rs::Timestamp> for Timestamp {
+    type Error = TimestampError;
+
+    /// Timestamps of an unknown offset, e.g. `2023-11-14T22:13:20-00:00`, are taken as UTC.
+    fn try_from(timestamp: ion_rs::Timestamp) -> Result<Self, Self::Error> {
+        let date_time = match timestamp.offset() {
+            Some(_) => TryInto::<DateTime<FixedOffset>>::try_into(timestamp).map(|date_time| date_time.to_utc()),
+            None => TryInto::<NaiveDateTime>::try_into(timestamp).map(|date_time| date_time.and_utc()),
+        };
+        date_time
+            .map_err(|error| TimestampError::Invalid(error.to_string()))
+            .and_then(Timestamp::from_date_time)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_date_time().to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampError;
+
+    /// Parses an RFC 3339 date and time, e.g. `2023-11-14T22:13:20.123Z` or `2023-11-15T00:13:20+02:00`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        DateTime::parse_from_rfc3339(text)
+            .map_err(|_| TimestampError::Invalid(text.into()))
+            .and_then(|date_time| Timestamp::from_date_time(date_time.to_utc()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timestamp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde::Deserialize>::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Describes why a point in time cannot be turned into a [`Timestamp`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TimestampError {
+    /// The point in time lies outside the years timestamps cover.
+    OutOfRange,
+    /// The text is not an RFC 3339 date and time.
+    Invalid(String),
+}
+
+impl fmt::Display for TimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimestampError::OutOfRange => write!(f, "the point in time lies outside the years 1677 to 2262"),
+            TimestampError::Invalid(text) => write!(f, "`{text}` is not an RFC 3339 date and time"),
+        }
+    }
+}
+
+impl Error for TimestampError { }
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use ion_rs::element::Element;
+
+    use crate::core::time_unit::TimeUnit;
+    use crate::core::timestamp::Timestamp;
+    use crate::core::timestamp::TimestampError;
+
+    #[test]
+    fn timestamps_are_converted_between_units() {
+        let timestamp = Timestamp::from_millis(1_700_000_000_123).unwrap();
+        assert_eq!(timestamp.as_nanos(), 1_700_000_000_123_000_000);
+        assert_eq!(timestamp.as_micros(), 1_700_000_000_123_000);
+        assert_eq!(timestamp.as_secs(), 1_700_000_000);
+        assert_eq!(Timestamp::from_nanos(-1).to_amount(TimeUnit::Seconds), -1);
+        assert_eq!(Timestamp::from_millis(i64::MAX), None);
+        assert_eq!(Timestamp::from_secs(i64::MIN / 1_000_000_000), Some(Timestamp::from_nanos(-9_223_372_036_000_000_000)));
+    }
+
+    #[test]
+    fn timestamps_are_converted_to_and_from_system_time() {
+        for timestamp in [Timestamp::from_nanos(1_700_000_000_123_456_789), Timestamp::from_nanos(-1_500), Timestamp::MIN, Timestamp::MAX] {
+            assert_eq!(Timestamp::try_from(SystemTime::from(timestamp)), Ok(timestamp));
+        }
+        assert_eq!(SystemTime::from(Timestamp::from_secs(1).unwrap()), UNIX_EPOCH + Duration::from_secs(1));
+        assert_eq!(Timestamp::try_from(UNIX_EPOCH + Duration::from_secs(u32::MAX.into()) * 4), Err(TimestampError::OutOfRange));
+    }
+
+    #[test]
+    fn ion_timestamps_keep_the_precision_they_need() {
+        for (nanoseconds, text) in [
+            (1_700_000_000_000_000_000, "2023-11-14T22:13:20+00:00"),
+            (1_700_000_000_123_000_000, "2023-11-14T22:13:20.123+00:00"),
+            (1_700_000_000_123_456_000, "2023-11-14T22:13:20.123456+00:00"),
+            (1_700_000_000_123_456_789, "2023-11-14T22:13:20.123456789+00:00"),
+        ] {
+            let timestamp = Timestamp::from_nanos(nanoseconds);
+            let ion_timestamp = ion_rs::Timestamp::from(timestamp);
+            assert_eq!(Element::from(ion_timestamp.clone()).to_string(), text);
+            assert_eq!(Timestamp::try_from(ion_timestamp), Ok(timestamp));
+        }
+    }
+
+    #[test]
+    fn ion_timestamps_are_read_in_utc() {
+        for text in ["2023-11-15T00:13:20.5+02:00", "2023-11-14T22:13:20.5-00:00"] {
+            let ion_timestamp = Element::read_one(text).unwrap().as_timestamp().unwrap().clone();
+            assert_eq!(Timestamp::try_from(ion_timestamp), Ok(Timestamp::from_millis(1_700_000_000_500).unwrap()), "{text}");
+        }
+
+        let ion_timestamp = Element::read_one("2300-01-01T").unwrap().as_timestamp().unwrap().clone();
+        assert_eq!(Timestamp::try_from(ion_timestamp), Err(TimestampError::OutOfRange));
+    }
+
+    #[test]
+    fn timestamps_are_formatted_as_rfc_3339() {
+        let timestamp = Timestamp::from_millis(1_700_000_000_123).unwrap();
+        assert_eq!(timestamp.to_string(), "2023-11-14T22:13:20.123Z");
+        assert_eq!("2023-11-15T00:13:20.123+02:00".parse(), Ok(timestamp));
+        assert_eq!("yesterday".parse::<Timestamp>(), Err(TimestampError::Invalid("yesterday".into())));
+    }
+}
\ No newline at end of file
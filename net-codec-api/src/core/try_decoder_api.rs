@@ -0,0 +1,5 @@
+use crate::core::decode_error::DecodeError;
+
+pub trait TryDecoder {
+    fn try_decode(data: &[u8]) -> Result<Self, DecodeError> where Self : Sized;
+}
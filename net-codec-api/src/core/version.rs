@@ -0,0 +1,50 @@
+//! Helpers for the schema version annotation every DTO writes on its top-level struct.
+//!
+//! A DTO payload looks like `v1::{ ... }`. Payloads written before versioning was introduced
+//! carry no annotation and are treated as [`LEGACY_VERSION`].
+//!
+//! To keep payloads of different releases interchangeable, a DTO layout may only evolve as follows:
+//! - a new field may be added, but only as an optional one, and bumping the version of the DTO;
+//! - an existing field is never removed, renamed or given another Ion type, unless decoders keep reading
+//!   the former type during a migration window, like nested DTOs which used to be written as blobs;
+//! - decoders skip the fields they don't know, so payloads of a newer version are still readable
+//!   by older consumers;
+//! - any other change is a breaking one and requires a new `DATA_TYPE` instead of a new version.
+
+/// The version of payloads written before DTOs were annotated with their schema version.
+pub const LEGACY_VERSION: u32 = 0;
+
+const VERSION_ANNOTATION_PREFIX: &str = "v";
+
+/// Returns the annotation marking a payload of the given schema `version`, e.g. `v1`.
+pub fn version_annotation(version: u32) -> String {
+    format!("{VERSION_ANNOTATION_PREFIX}{version}")
+}
+
+/// Parses a version annotation, returning `None` for annotations of any other kind.
+pub fn parse_version_annotation(annotation: &str) -> Option<u32> {
+    annotation
+        .strip_prefix(VERSION_ANNOTATION_PREFIX)
+        .filter(|version| version.bytes().all(|byte| byte.is_ascii_digit()))
+        .and_then(|version| version.parse().ok())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::version::parse_version_annotation;
+    use crate::core::version::version_annotation;
+
+    #[test]
+    fn version_annotation_round_trips() {
+        assert_eq!(version_annotation(1), "v1");
+        assert_eq!(parse_version_annotation(&version_annotation(12)), Some(12));
+    }
+
+    #[test]
+    fn foreign_annotations_are_not_versions() {
+        assert_eq!(parse_version_annotation("value"), None);
+        assert_eq!(parse_version_annotation("v+1"), None);
+        assert_eq!(parse_version_annotation("v"), None);
+    }
+}
\ No newline at end of file
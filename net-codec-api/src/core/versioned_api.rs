@@ -0,0 +1,9 @@
+/// Schema version of a DTO's wire layout.
+///
+/// Encoders annotate the top-level struct with the version of the layout they write
+/// (see [`version_annotation`](crate::core::version::version_annotation)), so consumers can
+/// tell payloads of different releases apart. The compatibility rules a new version has to follow
+/// are described in the [`version`](crate::core::version) module.
+pub trait Versioned {
+    fn get_version() -> u32 where Self : Sized;
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use syn::Attribute;
+use syn::Expr;
+use syn::Ident;
+
+
+const ION_ATTRIBUTE: &str = "ion";
+
+/// Options of the `#[ion(...)]` attribute of the DTO itself.
+pub struct DtoAttributes {
+    pub data_type: Expr,
+    pub aliases: Option<Expr>,
+    pub version: Option<Expr>,
+    pub validate: Option<Expr>,
+}
+
+/// Options of the `#[ion(...)]` attribute of a DTO field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub present_with: Option<Ident>,
+    pub legacy_unit: Option<Expr>,
+    pub legacy_string: bool,
+    pub as_string: bool,
+    pub compressed_by: Option<Ident>,
+    pub default: Option<Expr>,
+}
+
+impl DtoAttributes {
+    pub fn parse(ident: &Ident, attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut data_type = None;
+        let mut aliases = None;
+        let mut version = None;
+        let mut validate = None;
+
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident(ION_ATTRIBUTE)) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("data_type") {
+                    data_type = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("aliases") {
+                    aliases = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("version") {
+                    version = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("validate") {
+                    validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported ion attribute, expected `data_type`, `aliases`, `version` or `validate`"))
+                }
+            })?;
+        }
+
+        let data_type = data_type.ok_or_else(|| syn::Error::new_spanned(
+            ident,
+            "IonDto requires a data type, e.g. `#[ion(data_type = \"graph_edge\")]`",
+        ))?;
+
+        Ok(DtoAttributes { data_type, aliases, version, validate })
+    }
+}
+
+impl FieldAttributes {
+    pub fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut field_attributes = FieldAttributes::default();
+
+        for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident(ION_ATTRIBUTE)) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("present_with") {
+                    field_attributes.present_with = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("legacy_unit") {
+                    field_attributes.legacy_unit = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("legacy_string") {
+                    field_attributes.legacy_string = true;
+                    Ok(())
+                } else if meta.path.is_ident("as_string") {
+                    field_attributes.as_string = true;
+                    Ok(())
+                } else if meta.path.is_ident("compressed_by") {
+                    field_attributes.compressed_by = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    field_attributes.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported ion attribute, expected `present_with`, `legacy_unit`, `legacy_string`, `as_string`, `compressed_by` or `default`"))
+                }
+            })?;
+        }
+
+        Ok(field_attributes)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,314 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::Fields;
+use syn::Ident;
+use syn::LitStr;
+
+use crate::attributes::DtoAttributes;
+use crate::attributes::FieldAttributes;
+use crate::value_kind::ValueKind;
+
+
+struct DtoField {
+    ident: Ident,
+    name: LitStr,
+    value_kind: ValueKind,
+    present_with: Option<Ident>,
+    compressed_by: Option<Ident>,
+    default: Option<Expr>,
+}
+
+impl DtoField {
+    /// Statements writing `value`, a reference to the value of the field, compressing it first if it is compressed.
+    fn write(&self, value: TokenStream) -> TokenStream {
+        let Some(codec) = &self.compressed_by else {
+            return self.value_kind.write(value);
+        };
+        quote! {
+            writer.write_blob(::net_codec_api::core::compression::compress_with(self.#codec, #value))?;
+        }
+    }
+}
+
+pub fn expand_ion_dto(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let attributes = DtoAttributes::parse(&input.ident, &input.attrs)?;
+    let fields = parse_fields(input)?;
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let data_type = &attributes.data_type;
+    let aliases = attributes.aliases.as_ref().map_or_else(|| quote!(&[]), |aliases| quote!(#aliases));
+    let version = attributes.version.as_ref().map_or_else(|| quote!(1), |version| quote!(#version));
+    let write_struct = expand_write_struct(&fields);
+    let read_struct = expand_read_struct(&fields, attributes.validate.as_ref());
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::net_codec_api::core::ion_struct_api::IonStruct for #ident #type_generics #where_clause {
+            fn write_struct<W: ::ion_rs::IonWriter>(&self, writer: &mut W) -> ::ion_rs::IonResult<()> {
+                #write_struct
+            }
+
+            fn read_struct(
+                reader: &mut ::net_codec_api::core::decode_reader::DecodeReader,
+            ) -> ::std::result::Result<Self, ::net_codec_api::core::decode_error::DecodeError> {
+                #read_struct
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_core_api::core::encoder_api::Encoder for #ident #type_generics #where_clause {
+            fn encode(&self) -> ::std::vec::Vec<u8> {
+                ::net_codec_api::core::format_encoder_api::FormatEncoder::encode_as(
+                    self,
+                    ::net_codec_api::core::ion_format::IonFormat::Binary,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_codec_api::core::try_decoder_api::TryDecoder for #ident #type_generics #where_clause {
+            fn try_decode(data: &[u8]) -> ::std::result::Result<Self, ::net_codec_api::core::decode_error::DecodeError> {
+                let mut reader = ::net_codec_api::core::decode_reader::DecodeReader::open(data)?;
+                <Self as ::net_codec_api::core::ion_struct_api::IonStruct>::read_struct(&mut reader)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_core_api::core::decoder_api::Decoder for #ident #type_generics #where_clause {
+            fn decode(data: &[u8]) -> Self {
+                <Self as ::net_codec_api::core::try_decoder_api::TryDecoder>::try_decode(data).unwrap()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_core_api::core::typed_api::Typed for #ident #type_generics #where_clause {
+            fn get_data_type() -> &'static str {
+                #data_type
+            }
+            fn get_type(&self) -> &str {
+                <Self as ::net_core_api::core::typed_api::Typed>::get_data_type()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_codec_api::core::aliased_api::Aliased for #ident #type_generics #where_clause {
+            fn get_data_type_aliases() -> &'static [&'static str] {
+                #aliases
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::net_codec_api::core::versioned_api::Versioned for #ident #type_generics #where_clause {
+            fn get_version() -> u32 {
+                #version
+            }
+        }
+    })
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<Vec<DtoField>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "IonDto can only be derived for structs"));
+    };
+    let Fields::Named(named_fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(&input.ident, "IonDto can only be derived for structs with named fields"));
+    };
+
+    let fields = named_fields.named.iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let name = LitStr::new(ident.to_string().trim_start_matches("r#"), ident.span());
+            let field_attributes = FieldAttributes::parse(&field.attrs)?;
+            let mut value_kind = ValueKind::from_type(&field.ty)?;
+            if let Some(legacy_unit) = field_attributes.legacy_unit {
+                value_kind.set_legacy_unit(&field.ty, legacy_unit)?;
+            }
+            if field_attributes.legacy_string {
+                value_kind.set_legacy_string(&field.ty)?;
+            }
+            if field_attributes.as_string {
+                value_kind.set_as_string(&field.ty)?;
+            }
+            Ok(DtoField {
+                name,
+                value_kind,
+                present_with: field_attributes.present_with,
+                compressed_by: field_attributes.compressed_by,
+                default: field_attributes.default,
+                ident,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    for field in &fields {
+        let Some(present_with) = &field.present_with else {
+            continue;
+        };
+        match fields.iter().find(|other| &other.ident == present_with) {
+            Some(other) if other.value_kind.is_optional() && other.present_with.is_none() => (),
+            Some(_) => return Err(syn::Error::new_spanned(
+                present_with,
+                "`present_with` has to name an `Option` field which is not `present_with` another one",
+            )),
+            None => return Err(syn::Error::new_spanned(present_with, "no such field")),
+        }
+    }
+
+    for field in &fields {
+        if field.default.is_some() && field.value_kind.is_optional() {
+            return Err(syn::Error::new_spanned(&field.ident, "`default` does not apply to `Option` fields, which decode to `None`"));
+        }
+    }
+
+    for field in &fields {
+        let Some(codec) = &field.compressed_by else {
+            continue;
+        };
+        if !matches!(field.value_kind, ValueKind::Blob) {
+            return Err(syn::Error::new_spanned(&field.ident, "`compressed_by` only applies to `Vec<u8>` fields"));
+        }
+        match fields.iter().find(|other| &other.ident == codec) {
+            Some(other) if matches!(&other.value_kind, ValueKind::Optional(value_kind) if matches!(**value_kind, ValueKind::Parsed(_))) => (),
+            Some(_) => return Err(syn::Error::new_spanned(
+                codec,
+                "`compressed_by` has to name an `Option<Compression>` field marked with `#[ion(as_string)]`",
+            )),
+            None => return Err(syn::Error::new_spanned(codec, "no such field")),
+        }
+    }
+
+    Ok(fields)
+}
+
+fn expand_write_struct(fields: &[DtoField]) -> TokenStream {
+    let write_fields = fields.iter()
+        .filter(|field| field.present_with.is_none())
+        .map(|field| {
+            let ident = &field.ident;
+            let name = &field.name;
+            let dependents: Vec<&DtoField> = fields.iter()
+                .filter(|dependent| dependent.present_with.as_ref() == Some(ident))
+                .collect();
+
+            if dependents.is_empty() {
+                let write_value = field.write(quote!(&self.#ident));
+                return quote! {
+                    writer.set_field_name(#name);
+                    #write_value
+                };
+            }
+
+            // The dependent fields are written only when this one is set
+            let ValueKind::Optional(value_kind) = &field.value_kind else {
+                unreachable!("checked while parsing the fields");
+            };
+            let write_value = value_kind.write(quote!(value));
+            let ion_type = value_kind.ion_type();
+            let write_dependents = dependents.iter().map(|dependent| {
+                let dependent_ident = &dependent.ident;
+                let dependent_name = &dependent.name;
+                let write_dependent = dependent.write(quote!(&self.#dependent_ident));
+                quote! {
+                    writer.set_field_name(#dependent_name);
+                    #write_dependent
+                }
+            });
+            quote! {
+                writer.set_field_name(#name);
+                match &self.#ident {
+                    Some(value) => {
+                        #write_value
+                        #(#write_dependents)*
+                    },
+                    None => writer.write_null(#ion_type)?,
+                }
+            }
+        });
+
+    quote! {
+        writer.set_annotations([::net_codec_api::core::version::version_annotation(
+            <Self as ::net_codec_api::core::versioned_api::Versioned>::get_version(),
+        )]);
+        writer.step_in(::ion_rs::IonType::Struct)?;
+
+        #(#write_fields)*
+
+        writer.step_out()
+    }
+}
+
+fn expand_read_struct(fields: &[DtoField], validate: Option<&Expr>) -> TokenStream {
+    let idents: Vec<&Ident> = fields.iter().map(|field| &field.ident).collect();
+
+    let read_arms = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+        let read_value = field.value_kind.read();
+        match field.value_kind.is_optional() {
+            true => quote!(#name => #ident = #read_value?,),
+            false => quote!(#name => #ident = ::std::option::Option::Some(#read_value?),),
+        }
+    });
+
+    let unwrap_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+        let ion_type = field.value_kind.ion_type();
+        match (field.value_kind.is_optional(), &field.default, &field.present_with) {
+            (true, _, _) => quote!(),
+            (false, Some(default), _) => quote!(let #ident = #ident.unwrap_or_else(|| #default);),
+            (false, None, Some(_)) => quote!(let #ident = #ident.unwrap_or_default();),
+            (false, None, None) => quote!(let #ident = reader.require(#name, #ion_type, #ident)?;),
+        }
+    });
+
+    // Compressed fields are decompressed once their codec, which may come after them, is read
+    let decompress_fields = fields.iter().filter_map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+        let codec = field.compressed_by.as_ref()?;
+        Some(quote! {
+            let #ident = ::net_codec_api::core::compression::decompress_with(#codec, &#ident)
+                .map_err(|error| reader.invalid(#name, ::ion_rs::IonType::Blob, &::std::string::ToString::to_string(&error)))?;
+        })
+    });
+
+    // The struct is validated before stepping out of it, so errors point at it
+    let validate = validate.map(|validate| quote! {
+        (#validate)(&value).map_err(|error| reader.invalid(
+            "",
+            ::ion_rs::IonType::Struct,
+            &::std::string::ToString::to_string(&error),
+        ))?;
+    });
+
+    quote! {
+        reader.step_in_struct()?;
+
+        #(let mut #idents = ::std::option::Option::None;)*
+        while let ::std::option::Option::Some(field_name) = reader.next_field_name()? {
+            #[allow(clippy::single_match)]
+            match field_name.as_str() {
+                #(#read_arms)*
+                _ => (),
+            }
+        }
+
+        #(#unwrap_fields)*
+        #(#decompress_fields)*
+
+        let value = Self {
+            #(#idents,)*
+        };
+        #validate
+
+        reader.step_out()?;
+
+        ::std::result::Result::Ok(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+mod attributes;
+mod expand;
+mod value_kind;
+
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+use syn::DeriveInput;
+
+
+/// Generates the `IonStruct`, `Encoder`, `TryDecoder`, `Decoder`, `Typed`, `Aliased` and `Versioned` impls of an Ion DTO.
+///
+/// ```ignore
+/// #[derive(IonDto)]
+/// #[ion(data_type = "graph_edge", version = 1)]
+/// pub struct GraphEdgeDTO {
+///     src_id: String,
+///     dst_id: String,
+///     value: i64,
+/// }
+/// ```
+///
+/// The DTO is written as a struct annotated with its version, fields being written in their declaration order
+/// under their own names. Supported field types are `i64`, `bool`, `String`, `Vec<u8>` (written as a blob),
+/// `Timestamp` (written as an Ion timestamp),
+/// other DTOs (written inline as structs, though blobs holding their encoded payload are still read),
+/// `Vec` of any of them (written as a list) and `Option` of any of them (written as a typed null when `None`).
+///
+/// Fields which are not an `Option` are required when decoding, unknown fields are skipped.
+///
+/// A field marked with `#[ion(present_with = other_field)]` is written right after `other_field`
+/// and only if the latter, which has to be an `Option`, is set. It is decoded to its default value when absent.
+/// A field which is not an `Option` marked with `#[ion(default = TimeUnit::Milliseconds)]` is decoded to that value
+/// when absent instead, e.g. from payloads written before it was added.
+///
+/// A timestamp field marked with `#[ion(legacy_unit = TimeUnit::Milliseconds)]` is also decoded from an int
+/// counting that unit from the Unix epoch, which is how it was written before.
+/// Likewise, a field holding another DTO marked with `#[ion(legacy_string)]` is also parsed from a string
+/// with the `FromStr` impl of that DTO.
+///
+/// A field of any other type, e.g. an enum, marked with `#[ion(as_string)]` is written as a string with its `Display` impl
+/// and parsed back with its `FromStr` one. Decoding fails with an `Invalid` error when the string cannot be parsed.
+///
+/// A `Vec<u8>` field marked with `#[ion(compressed_by = codec)]` is compressed with the codec held by `codec`,
+/// an `Option<Compression>` field marked with `#[ion(as_string)]`, and decompressed when decoding.
+/// It is written as is while `codec` is unset.
+///
+/// `validate` names a function taking the decoded DTO by reference and returning a `Result<(), E>`, `E` being `Display`.
+/// Decoding fails with an [`Invalid`](net_codec_api::core::decode_error::DecodeErrorKind::Invalid) error when it returns an error.
+///
+/// `aliases` lists the former data types of the DTO, e.g. `aliases = &["bucket"]`, and defaults to none.
+/// `version` defaults to `1`. `data_type`, `aliases`, `version` and `validate` accept any expression.
+#[proc_macro_derive(IonDto, attributes(ion))]
+pub fn derive_ion_dto(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::expand_ion_dto(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
\ No newline at end of file
@@ -0,0 +1,201 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Expr;
+use syn::GenericArgument;
+use syn::PathArguments;
+use syn::Type;
+
+
+const UNSUPPORTED_PRIMITIVES: &[&str] = &[
+    "i8", "i16", "i32", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "f32", "f64", "char", "str",
+];
+
+/// How a Rust type maps onto an Ion value.
+pub enum ValueKind {
+    Int,
+    Bool,
+    String,
+    Blob,
+    /// A `Timestamp`, along with the unit of the ints it was written as before, if it ever was.
+    Timestamp(Option<Expr>),
+    List(Box<ValueKind>),
+    Optional(Box<ValueKind>),
+    /// Another DTO, written inline as a struct, along with whether it used to be written as a string it is parsed from.
+    Nested(Box<Type>, bool),
+    /// A value written as a string with its `Display` impl and parsed back with its `FromStr` one.
+    Parsed(Box<Type>),
+}
+
+impl ValueKind {
+    pub fn from_type(ty: &Type) -> syn::Result<Self> {
+        let Type::Path(type_path) = ty else {
+            return Err(syn::Error::new_spanned(ty, "IonDto does not support this field type"));
+        };
+        let segment = type_path.path.segments.last()
+            .ok_or_else(|| syn::Error::new_spanned(ty, "IonDto does not support this field type"))?;
+
+        match segment.ident.to_string().as_str() {
+            "i64" => Ok(ValueKind::Int),
+            "bool" => Ok(ValueKind::Bool),
+            "String" => Ok(ValueKind::String),
+            "Timestamp" => Ok(ValueKind::Timestamp(None)),
+            "Vec" => match get_type_argument(ty, &segment.arguments)? {
+                Type::Path(element) if element.path.is_ident("u8") => Ok(ValueKind::Blob),
+                element => Ok(ValueKind::List(Box::new(ValueKind::from_type(element)?))),
+            },
+            "Option" => match ValueKind::from_type(get_type_argument(ty, &segment.arguments)?)? {
+                ValueKind::Optional(_) => Err(syn::Error::new_spanned(ty, "IonDto does not support nested options")),
+                value_kind => Ok(ValueKind::Optional(Box::new(value_kind))),
+            },
+            primitive if UNSUPPORTED_PRIMITIVES.contains(&primitive) => Err(syn::Error::new_spanned(
+                ty,
+                "IonDto only supports `i64` among the primitive number types",
+            )),
+            _ => Ok(ValueKind::Nested(Box::new(ty.clone()), false)),
+        }
+    }
+
+    /// Lets a timestamp, or a list or an option of them, be read from the ints of `legacy_unit` it used to be written as.
+    pub fn set_legacy_unit(&mut self, ty: &Type, legacy_unit: Expr) -> syn::Result<()> {
+        match self {
+            ValueKind::Timestamp(unit) => {
+                *unit = Some(legacy_unit);
+                Ok(())
+            },
+            ValueKind::List(value_kind) | ValueKind::Optional(value_kind) => value_kind.set_legacy_unit(ty, legacy_unit),
+            _ => Err(syn::Error::new_spanned(ty, "`legacy_unit` only applies to `Timestamp` fields")),
+        }
+    }
+
+    /// Lets a nested DTO, or a list or an option of them, be parsed from the strings it used to be written as.
+    pub fn set_legacy_string(&mut self, ty: &Type) -> syn::Result<()> {
+        match self {
+            ValueKind::Nested(_, legacy_string) => {
+                *legacy_string = true;
+                Ok(())
+            },
+            ValueKind::List(value_kind) | ValueKind::Optional(value_kind) => value_kind.set_legacy_string(ty),
+            _ => Err(syn::Error::new_spanned(ty, "`legacy_string` only applies to fields holding another DTO")),
+        }
+    }
+
+    /// Lets a value, or a list or an option of them, be written as a string and parsed back, e.g. an enum.
+    pub fn set_as_string(&mut self, ty: &Type) -> syn::Result<()> {
+        match self {
+            ValueKind::Nested(parsed_ty, false) => {
+                *self = ValueKind::Parsed(parsed_ty.clone());
+                Ok(())
+            },
+            ValueKind::List(value_kind) | ValueKind::Optional(value_kind) => value_kind.set_as_string(ty),
+            _ => Err(syn::Error::new_spanned(ty, "`as_string` only applies to fields of a type which is not supported otherwise")),
+        }
+    }
+
+    pub fn is_optional(&self) -> bool {
+        matches!(self, ValueKind::Optional(_))
+    }
+
+    /// The Ion type the value is written as.
+    pub fn ion_type(&self) -> TokenStream {
+        match self {
+            ValueKind::Int => quote!(::ion_rs::IonType::Int),
+            ValueKind::Bool => quote!(::ion_rs::IonType::Bool),
+            ValueKind::String => quote!(::ion_rs::IonType::String),
+            ValueKind::Blob => quote!(::ion_rs::IonType::Blob),
+            ValueKind::Timestamp(_) => quote!(::ion_rs::IonType::Timestamp),
+            ValueKind::Nested(..) => quote!(::ion_rs::IonType::Struct),
+            ValueKind::Parsed(_) => quote!(::ion_rs::IonType::String),
+            ValueKind::List(_) => quote!(::ion_rs::IonType::List),
+            ValueKind::Optional(value_kind) => value_kind.ion_type(),
+        }
+    }
+
+    /// Statements writing `value`, a reference to the Rust value, with `writer`, propagating Ion errors.
+    pub fn write(&self, value: TokenStream) -> TokenStream {
+        match self {
+            ValueKind::Int => quote!(writer.write_i64(*#value)?;),
+            ValueKind::Bool => quote!(writer.write_bool(*#value)?;),
+            ValueKind::String => quote!(writer.write_string(#value)?;),
+            ValueKind::Blob => quote!(writer.write_blob(#value)?;),
+            ValueKind::Timestamp(_) => quote!(writer.write_timestamp(&::ion_rs::Timestamp::from(*#value))?;),
+            ValueKind::Nested(..) => quote! {
+                ::net_codec_api::core::ion_struct_api::IonStruct::write_struct(#value, writer)?;
+            },
+            ValueKind::Parsed(_) => quote!(writer.write_string(&::std::string::ToString::to_string(#value))?;),
+            ValueKind::List(element_kind) => {
+                let write_element = element_kind.write(quote!(element));
+                quote! {
+                    writer.step_in(::ion_rs::IonType::List)?;
+                    for element in #value {
+                        #write_element
+                    }
+                    writer.step_out()?;
+                }
+            },
+            ValueKind::Optional(value_kind) => {
+                let write_value = value_kind.write(quote!(value));
+                let ion_type = value_kind.ion_type();
+                quote! {
+                    match #value {
+                        Some(value) => { #write_value },
+                        None => writer.write_null(#ion_type)?,
+                    }
+                }
+            },
+        }
+    }
+
+    /// An expression reading the current value with `reader`, evaluating to a `Result`.
+    pub fn read(&self) -> TokenStream {
+        match self {
+            ValueKind::Int => quote!(reader.read_i64()),
+            ValueKind::Bool => quote!(reader.read_bool()),
+            ValueKind::String => quote!(reader.read_string()),
+            ValueKind::Blob => quote!(reader.read_blob()),
+            ValueKind::Timestamp(legacy_unit) => {
+                let legacy_unit = expand_legacy_unit(legacy_unit.as_ref());
+                quote!(reader.read_timestamp(#legacy_unit))
+            },
+            ValueKind::Nested(ty, false) => quote!(reader.read_struct::<#ty>()),
+            ValueKind::Nested(ty, true) => quote!(reader.read_parsed_struct::<#ty>()),
+            ValueKind::Parsed(ty) => quote!(reader.read_parsed::<#ty>()),
+            ValueKind::List(element_kind) => {
+                let read_element = element_kind.read();
+                quote!(reader.read_list(|reader| #read_element))
+            },
+            ValueKind::Optional(value_kind) => match &**value_kind {
+                // Legacy ints have to be told apart from nulls before expecting a timestamp
+                ValueKind::Timestamp(legacy_unit) => {
+                    let legacy_unit = expand_legacy_unit(legacy_unit.as_ref());
+                    quote!(reader.read_optional_timestamp(#legacy_unit))
+                },
+                // So do legacy strings
+                ValueKind::Nested(ty, true) => quote!(reader.read_optional_parsed_struct::<#ty>()),
+                value_kind => {
+                    let read_value = value_kind.read();
+                    let ion_type = value_kind.ion_type();
+                    quote!(reader.read_optional(#ion_type, |reader| #read_value))
+                },
+            },
+        }
+    }
+}
+
+fn expand_legacy_unit(legacy_unit: Option<&Expr>) -> TokenStream {
+    match legacy_unit {
+        Some(legacy_unit) => quote!(::std::option::Option::Some(#legacy_unit)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+fn get_type_argument<'a>(ty: &Type, arguments: &'a PathArguments) -> syn::Result<&'a Type> {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(argument)) if arguments.args.len() == 1 => Ok(argument),
+            _ => Err(syn::Error::new_spanned(ty, "expected a single type argument")),
+        },
+        _ => Err(syn::Error::new_spanned(ty, "expected a single type argument")),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,373 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ion_rs::IonType;
+use ion_rs::IonWriter;
+
+use net_core_api::core::decoder_api::Decoder;
+use net_core_api::core::encoder_api::Encoder;
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::aliased_api::Aliased;
+use net_codec_api::core::compression::Compression;
+use net_codec_api::core::compression_error::CompressionError;
+use net_codec_api::core::decode_error::DecodeErrorKind;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::time_unit::TimeUnit;
+use net_codec_api::core::timestamp::Timestamp;
+use net_codec_api::core::try_decoder_api::TryDecoder;
+use net_codec_api::core::versioned_api::Versioned;
+
+use net_codec_derive::IonDto;
+
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_child")]
+struct ChildDTO {
+    name: String,
+    enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_parent", aliases = &["test-parent", "parent"], version = 2)]
+struct ParentDTO {
+    id: i64,
+    payload: Vec<u8>,
+    child: ChildDTO,
+    children: Vec<ChildDTO>,
+    optional_child: Option<ChildDTO>,
+    #[ion(present_with = include_codes_mode)]
+    codes: Vec<i64>,
+    include_codes_mode: Option<bool>,
+    comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_wrapper")]
+struct WrapperDTO {
+    child: ChildDTO,
+    children: Vec<ChildDTO>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_range", validate = RangeDTO::validate)]
+struct RangeDTO {
+    lower_bound: i64,
+    upper_bound: i64,
+}
+
+impl RangeDTO {
+    fn validate(&self) -> Result<(), String> {
+        match self.lower_bound <= self.upper_bound {
+            true => Ok(()),
+            false => Err("lower bound exceeds upper bound".into()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_event")]
+struct EventDTO {
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    started_at: Timestamp,
+    #[ion(legacy_unit = TimeUnit::Seconds)]
+    ended_at: Option<Timestamp>,
+    checkpoints: Vec<Timestamp>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_point")]
+struct PointDTO {
+    x: i64,
+    y: i64,
+}
+
+impl FromStr for PointDTO {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (x, y) = text.split_once(',').ok_or("expected `x,y`")?;
+        match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => Ok(PointDTO { x, y }),
+            _ => Err("coordinates have to be ints".into()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_path")]
+struct PathDTO {
+    #[ion(legacy_string)]
+    start: PointDTO,
+    #[ion(legacy_string)]
+    end: Option<PointDTO>,
+    #[ion(legacy_string)]
+    waypoints: Vec<PointDTO>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Heading {
+    North,
+    South,
+}
+
+impl FromStr for Heading {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "north" => Ok(Heading::North),
+            "south" => Ok(Heading::South),
+            _ => Err(format!("`{text}` is not a heading")),
+        }
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Heading::North => write!(f, "north"),
+            Heading::South => write!(f, "south"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_route")]
+struct RouteDTO {
+    #[ion(as_string)]
+    heading: Heading,
+    #[ion(as_string)]
+    previous_heading: Option<Heading>,
+    #[ion(as_string)]
+    turns: Vec<Heading>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_turn", version = 2)]
+struct TurnDTO {
+    angle: i64,
+    #[ion(as_string, default = Heading::North)]
+    heading: Heading,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = "test_capture")]
+struct CaptureDTO {
+    #[ion(compressed_by = codec)]
+    data: Vec<u8>,
+    #[ion(as_string)]
+    codec: Option<Compression>,
+}
+
+fn get_child(name: &str) -> ChildDTO {
+    ChildDTO { name: name.into(), enabled: true }
+}
+
+fn get_parent(include_codes_mode: Option<bool>) -> ParentDTO {
+    ParentDTO {
+        id: 42,
+        payload: vec![1, 2, 3],
+        child: get_child("first"),
+        children: vec![get_child("second"), get_child("third")],
+        optional_child: None,
+        codes: match include_codes_mode {
+            Some(_) => vec![200, 404],
+            None => Vec::new(),
+        },
+        include_codes_mode,
+        comment: Some("comment".into()),
+    }
+}
+
+#[test]
+fn endec_derived_dto() {
+    let parent = get_parent(Some(true));
+    assert_eq!(parent, ParentDTO::decode(&parent.encode()));
+
+    let parent = get_parent(None);
+    assert_eq!(parent, ParentDTO::decode(&parent.encode()));
+}
+
+#[test]
+fn derived_dto_writes_fields_in_declaration_order() {
+    let parent = get_parent(Some(false));
+    let encoded = parent.encode();
+    let mut reader = DecodeReader::new(&encoded).unwrap();
+
+    assert_eq!(reader.get_version(), 2);
+
+    let mut field_names = Vec::new();
+    while let Some(field_name) = reader.next_field_name().unwrap() {
+        field_names.push(field_name);
+    }
+    assert_eq!(
+        field_names,
+        ["id", "payload", "child", "children", "optional_child", "include_codes_mode", "codes", "comment"],
+    );
+}
+
+#[test]
+fn derived_dto_skips_fields_present_with_an_unset_one() {
+    let parent = get_parent(None);
+    let encoded = parent.encode();
+    let mut reader = DecodeReader::new(&encoded).unwrap();
+
+    while let Some(field_name) = reader.next_field_name().unwrap() {
+        assert_ne!(field_name, "codes");
+    }
+}
+
+#[test]
+fn derived_dto_requires_non_optional_fields() {
+    let error = ChildDTO::try_decode("{name: \"child\"}".as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "enabled");
+    assert_eq!(error.get_expected_type(), Some(IonType::Bool));
+    assert_eq!(error.get_kind(), &DecodeErrorKind::MissingField);
+}
+
+#[test]
+fn derived_dto_is_validated_when_decoded() {
+    let range = RangeDTO { lower_bound: 1, upper_bound: 2 };
+    assert_eq!(range, RangeDTO::decode(&range.encode()));
+
+    let error = RangeDTO::try_decode(&RangeDTO { lower_bound: 2, upper_bound: 1 }.encode()).unwrap_err();
+    assert_eq!(error.get_expected_type(), Some(IonType::Struct));
+    assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid("lower bound exceeds upper bound".into()));
+}
+
+#[test]
+fn derived_dto_reads_timestamps_and_legacy_ints() {
+    let started_at = Timestamp::from_millis(1_700_000_000_123).unwrap();
+    let event = EventDTO {
+        started_at,
+        ended_at: Timestamp::from_secs(1_700_000_060),
+        checkpoints: vec![Timestamp::from_nanos(1_700_000_000_123_456_789)],
+    };
+    assert_eq!(event, EventDTO::decode(&event.encode()));
+
+    let legacy = "{started_at: 1700000000123, ended_at: 1700000060, checkpoints: [2023-11-14T22:13:20.123456789Z]}";
+    assert_eq!(EventDTO::try_decode(legacy.as_bytes()), Ok(event));
+
+    let error = EventDTO::try_decode("{started_at: 1700000000123, checkpoints: [1700000000123]}".as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "checkpoints[0]");
+    assert_eq!(error.get_expected_type(), Some(IonType::Timestamp));
+}
+
+#[test]
+fn derived_dto_parses_children_from_legacy_strings() {
+    let path = PathDTO {
+        start: PointDTO { x: 1, y: 2 },
+        end: Some(PointDTO { x: 3, y: 4 }),
+        waypoints: vec![PointDTO { x: 5, y: 6 }, PointDTO { x: 7, y: 8 }],
+    };
+    assert_eq!(path, PathDTO::decode(&path.encode()));
+
+    let legacy = r#"{start: "1,2", end: "3,4", waypoints: ["5,6", v1::{x: 7, y: 8}]}"#;
+    assert_eq!(PathDTO::try_decode(legacy.as_bytes()), Ok(path));
+
+    let unset = r#"{start: "1,2", end: null.struct, waypoints: []}"#;
+    assert_eq!(PathDTO::try_decode(unset.as_bytes()).unwrap().end, None);
+
+    let error = PathDTO::try_decode(r#"{start: "1;2", waypoints: []}"#.as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "start");
+    assert_eq!(error.get_expected_type(), Some(IonType::Struct));
+    assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+}
+
+#[test]
+fn derived_dto_writes_as_string_fields_as_strings() {
+    let route = RouteDTO { heading: Heading::North, previous_heading: None, turns: vec![Heading::South, Heading::North] };
+    assert_eq!(route, RouteDTO::decode(&route.encode()));
+
+    let text = r#"{heading: "south", previous_heading: "north", turns: []}"#;
+    let route = RouteDTO { heading: Heading::South, previous_heading: Some(Heading::North), turns: Vec::new() };
+    assert_eq!(RouteDTO::try_decode(text.as_bytes()), Ok(route));
+
+    let error = RouteDTO::try_decode(r#"{heading: "north", previous_heading: null.string, turns: ["up"]}"#.as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "turns[0]");
+    assert_eq!(error.get_expected_type(), Some(IonType::String));
+    assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid("`up` is not a heading".into()));
+}
+
+#[test]
+fn derived_dto_decodes_absent_fields_to_their_default() {
+    let turn = TurnDTO { angle: 90, heading: Heading::South };
+    assert_eq!(turn, TurnDTO::decode(&turn.encode()));
+
+    assert_eq!(TurnDTO::try_decode("v1::{angle: 90}".as_bytes()), Ok(TurnDTO { angle: 90, heading: Heading::North }));
+}
+
+#[test]
+fn derived_dto_compresses_blobs_with_their_codec() {
+    let data: Vec<u8> = (0..1024).map(|index| (index % 16) as u8).collect();
+
+    for codec in [None, Some(Compression::Zstd), Some(Compression::Lz4)] {
+        let capture = CaptureDTO { data: data.clone(), codec };
+        let encoded = capture.encode();
+        assert_eq!(codec.is_some(), encoded.len() < data.len());
+        assert_eq!(CaptureDTO::decode(&encoded), capture);
+    }
+
+    let uncompressed = r#"{data: {{AQID}}, codec: null.string}"#;
+    assert_eq!(CaptureDTO::try_decode(uncompressed.as_bytes()).unwrap().data, [1, 2, 3]);
+
+    let error = CaptureDTO::try_decode(r#"{data: {{AQID}}, codec: "gzip"}"#.as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "codec");
+    assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(CompressionError::UnknownCodec("gzip".into()).to_string()));
+
+    let error = CaptureDTO::try_decode(r#"{data: {{AQID}}, codec: "zstd"}"#.as_bytes()).unwrap_err();
+    assert_eq!(error.get_field(), "data");
+    assert_eq!(error.get_expected_type(), Some(IonType::Blob));
+    assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+}
+
+#[test]
+fn derived_dto_has_data_type_and_version() {
+    assert_eq!(get_parent(None).get_type(), "test_parent");
+    assert_eq!(ChildDTO::get_data_type(), "test_child");
+    assert_eq!(ParentDTO::get_version(), 2);
+    assert_eq!(ChildDTO::get_version(), 1);
+}
+
+#[test]
+fn derived_dto_has_data_type_aliases() {
+    assert_eq!(ParentDTO::get_data_type_aliases(), ["test-parent", "parent"]);
+    assert!(ChildDTO::get_data_type_aliases().is_empty());
+}
+
+#[test]
+fn derived_dto_writes_children_inline() {
+    let wrapper = WrapperDTO { child: get_child("first"), children: vec![get_child("second")] };
+    let encoded = wrapper.encode();
+    let mut reader = DecodeReader::new(&encoded).unwrap();
+
+    reader.next_field("child").unwrap();
+    assert_eq!(reader.get_current_type(), Some(IonType::Struct));
+    reader.step_in_struct().unwrap();
+    assert_eq!(reader.get_version(), ChildDTO::get_version());
+    reader.step_out().unwrap();
+
+    reader.next_field("children").unwrap();
+    reader.step_in_list().unwrap();
+    assert!(reader.next_element(0).unwrap());
+    assert_eq!(reader.get_current_type(), Some(IonType::Struct));
+}
+
+#[test]
+fn derived_dto_reads_blob_wrapped_children() {
+    let wrapper = WrapperDTO { child: get_child("first"), children: vec![get_child("second"), get_child("third")] };
+
+    let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+    writer.step_in(IonType::Struct).unwrap();
+    writer.set_field_name("child");
+    writer.write_blob(wrapper.child.encode()).unwrap();
+    writer.set_field_name("children");
+    writer.step_in(IonType::List).unwrap();
+    wrapper.children.iter().for_each(|child| writer.write_blob(child.encode()).unwrap());
+    writer.step_out().unwrap();
+    writer.step_out().unwrap();
+    writer.flush().unwrap();
+
+    assert_eq!(WrapperDTO::decode(writer.output().as_slice()), wrapper);
+}
\ No newline at end of file
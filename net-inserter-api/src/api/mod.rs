@@ -1,3 +1,5 @@
 //TODO: Rewrite all the inbound ion structs encode to `write_blob(*.encode())`
 
+pub mod network_address;
 pub mod network_packet;
+pub mod network_packet_batch;
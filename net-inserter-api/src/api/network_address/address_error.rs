@@ -0,0 +1,42 @@
+use std::error::Error;
+use std::fmt;
+
+use super::ip_version::IpVersion;
+
+
+/// Describes why a network address is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AddressError {
+    /// The text is neither an IP nor an IP followed by a port, e.g. `10.0.0.1:443` or `[::1]:443`.
+    InvalidAddress(String),
+    /// The text is not a MAC address made of six hexadecimal octets, e.g. `00:1a:2b:3c:4d:5e`.
+    InvalidMacAddress(String),
+    /// The IP version is neither `4` nor `6`.
+    UnknownIpVersion(i64),
+    /// The IP is not made of as many octets as its version requires.
+    InvalidIpLength { ip_version: IpVersion, length: usize },
+    /// The port does not fit into 16 bits.
+    PortOutOfRange(i64),
+    /// The MAC address is not made of six octets.
+    InvalidMacAddressLength(usize),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::InvalidAddress(address) => write!(f, "`{address}` is not an IP, optionally followed by a port"),
+            AddressError::InvalidMacAddress(mac_address) => write!(f, "`{mac_address}` is not a MAC address"),
+            AddressError::UnknownIpVersion(ip_version) => write!(f, "the IP version has to be 4 or 6, it is {ip_version}"),
+            AddressError::InvalidIpLength { ip_version, length } => write!(
+                f,
+                "an IPv{} is made of {} octets, not {length}",
+                ip_version.get_number(),
+                ip_version.get_octet_count(),
+            ),
+            AddressError::PortOutOfRange(port) => write!(f, "the port has to be between 0 and 65535, it is {port}"),
+            AddressError::InvalidMacAddressLength(length) => write!(f, "a MAC address is made of 6 octets, not {length}"),
+        }
+    }
+}
+
+impl Error for AddressError { }
\ No newline at end of file
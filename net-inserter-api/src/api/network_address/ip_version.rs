@@ -0,0 +1,65 @@
+use std::net::IpAddr;
+
+
+/// Version of the Internet Protocol an address belongs to, written as its number, `4` or `6`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    pub fn of(ip: &IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => IpVersion::V4,
+            IpAddr::V6(_) => IpVersion::V6,
+        }
+    }
+
+    pub fn get_number(&self) -> i64 {
+        match self {
+            IpVersion::V4 => 4,
+            IpVersion::V6 => 6,
+        }
+    }
+
+    pub fn from_number(number: i64) -> Option<Self> {
+        match number {
+            4 => Some(IpVersion::V4),
+            6 => Some(IpVersion::V6),
+            _ => None,
+        }
+    }
+
+    /// How many octets an IP of the version is made of.
+    pub fn get_octet_count(&self) -> usize {
+        match self {
+            IpVersion::V4 => 4,
+            IpVersion::V6 => 16,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+    use std::net::Ipv4Addr;
+    use std::net::Ipv6Addr;
+
+    use crate::api::network_address::ip_version::IpVersion;
+
+    #[test]
+    fn versions_are_parsed_from_their_number() {
+        for ip_version in [IpVersion::V4, IpVersion::V6] {
+            assert_eq!(IpVersion::from_number(ip_version.get_number()), Some(ip_version));
+        }
+        assert_eq!(IpVersion::from_number(5), None);
+    }
+
+    #[test]
+    fn version_is_the_one_of_the_ip() {
+        assert_eq!(IpVersion::of(&IpAddr::V4(Ipv4Addr::LOCALHOST)), IpVersion::V4);
+        assert_eq!(IpVersion::of(&IpAddr::V6(Ipv6Addr::LOCALHOST)), IpVersion::V6);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use super::address_error::AddressError;
+
+
+pub const MAC_ADDRESS_LENGTH: usize = 6;
+
+/// Parses a MAC address written as six hexadecimal octets separated by colons or hyphens, e.g. `00:1a:2b:3c:4d:5e`.
+pub fn parse_mac_address(text: &str) -> Result<[u8; MAC_ADDRESS_LENGTH], AddressError> {
+    let invalid = || AddressError::InvalidMacAddress(text.into());
+    let separator = if text.contains('-') { '-' } else { ':' };
+
+    let mut mac_address = [0; MAC_ADDRESS_LENGTH];
+    let mut octets = text.split(separator);
+    for octet in mac_address.iter_mut() {
+        let text_octet = octets.next()
+            .filter(|text_octet| text_octet.len() == 2 && text_octet.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .ok_or_else(invalid)?;
+        *octet = u8::from_str_radix(text_octet, 16).map_err(|_| invalid())?;
+    }
+
+    match octets.next() {
+        Some(_) => Err(invalid()),
+        None => Ok(mac_address),
+    }
+}
+
+/// Writes a MAC address as six lowercase hexadecimal octets separated by colons.
+pub fn format_mac_address(mac_address: &[u8; MAC_ADDRESS_LENGTH]) -> String {
+    mac_address.iter()
+        .map(|octet| format!("{octet:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::network_address::address_error::AddressError;
+    use crate::api::network_address::mac_address::format_mac_address;
+    use crate::api::network_address::mac_address::parse_mac_address;
+
+    const MAC_ADDRESS: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+    #[test]
+    fn mac_addresses_are_parsed_with_either_separator() {
+        assert_eq!(parse_mac_address("00:1a:2b:3c:4d:5e"), Ok(MAC_ADDRESS));
+        assert_eq!(parse_mac_address("00-1A-2B-3C-4D-5E"), Ok(MAC_ADDRESS));
+        assert_eq!(parse_mac_address(&format_mac_address(&MAC_ADDRESS)), Ok(MAC_ADDRESS));
+    }
+
+    #[test]
+    fn malformed_mac_addresses_are_rejected() {
+        for text in ["", "00:1a:2b:3c:4d", "00:1a:2b:3c:4d:5e:6f", "00:1a:2b:3c:4d:5g", "00:1a:2b:3c:4d:+e", "0:1a:2b:3c:4d:5e0", "00:1a-2b:3c:4d:5e"] {
+            assert_eq!(parse_mac_address(text), Err(AddressError::InvalidMacAddress(text.into())));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod address_error;
+pub mod ip_version;
+pub mod mac_address;
+#[allow(clippy::module_inception)]
+pub mod network_address;
\ No newline at end of file
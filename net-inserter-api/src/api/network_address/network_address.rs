@@ -0,0 +1,254 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::address_error::AddressError;
+use super::ip_version::IpVersion;
+use super::mac_address::MAC_ADDRESS_LENGTH;
+#[cfg(feature = "serde")]
+use super::mac_address::format_mac_address;
+#[cfg(feature = "serde")]
+use super::mac_address::parse_mac_address;
+
+
+const DATA_TYPE: &str = "network_address";
+const VERSION: u32 = 1;
+
+/// An endpoint of a captured frame: its IP, written as a blob of octets along with its version,
+/// which decoding checks agree, and, when the protocol carries them, its port and MAC address.
+/// The `serde` feature writes the IP and the MAC address as text instead, e.g. `{"ip": "10.0.0.1", "port": 443, "mac_address": null}`.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "NetworkAddressFields", try_from = "NetworkAddressFields"))]
+pub struct NetworkAddressDTO {
+    ip_version: i64,
+    ip: Vec<u8>,
+    port: Option<i64>,
+    mac_address: Option<Vec<u8>>,
+}
+impl API for NetworkAddressDTO { }
+
+impl NetworkAddressDTO {
+    pub fn new(ip: IpAddr, port: Option<u16>, mac_address: Option<[u8; MAC_ADDRESS_LENGTH]>) -> Self {
+        let ip_octets = match ip {
+            IpAddr::V4(ip) => ip.octets().to_vec(),
+            IpAddr::V6(ip) => ip.octets().to_vec(),
+        };
+        NetworkAddressDTO {
+            ip_version: IpVersion::of(&ip).get_number(),
+            ip: ip_octets,
+            port: port.map(i64::from),
+            mac_address: mac_address.map(Vec::from),
+        }
+    }
+
+    pub fn get_ip_version(&self) -> IpVersion {
+        IpVersion::of(&self.get_ip())
+    }
+
+    /// The IP, unspecified when its octets are invalid, which decoding rejects.
+    pub fn get_ip(&self) -> IpAddr {
+        match <[u8; 16]>::try_from(self.ip.as_slice()) {
+            Ok(octets) => IpAddr::V6(Ipv6Addr::from(octets)),
+            Err(_) => IpAddr::V4(<[u8; 4]>::try_from(self.ip.as_slice()).map_or(Ipv4Addr::UNSPECIFIED, Ipv4Addr::from)),
+        }
+    }
+
+    pub fn get_port(&self) -> Option<u16> {
+        self.port.and_then(|port| u16::try_from(port).ok())
+    }
+
+    pub fn get_mac_address(&self) -> Option<[u8; MAC_ADDRESS_LENGTH]> {
+        self.mac_address.as_deref().and_then(|mac_address| mac_address.try_into().ok())
+    }
+
+    /// Checks the IP version is known, the IP is made of as many octets as it requires,
+    /// the port fits into 16 bits and the MAC address, if any, is made of six octets. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), AddressError> {
+        let ip_version = IpVersion::from_number(self.ip_version)
+            .ok_or(AddressError::UnknownIpVersion(self.ip_version))?;
+        if self.ip.len() != ip_version.get_octet_count() {
+            return Err(AddressError::InvalidIpLength { ip_version, length: self.ip.len() });
+        }
+
+        match (self.port, self.mac_address.as_deref()) {
+            (Some(port), _) if u16::try_from(port).is_err() => Err(AddressError::PortOutOfRange(port)),
+            (_, Some(mac_address)) if mac_address.len() != MAC_ADDRESS_LENGTH => {
+                Err(AddressError::InvalidMacAddressLength(mac_address.len()))
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The fields the `serde` feature writes a [`NetworkAddressDTO`] as.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NetworkAddressFields {
+    ip: IpAddr,
+    port: Option<u16>,
+    mac_address: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<NetworkAddressDTO> for NetworkAddressFields {
+    fn from(network_address: NetworkAddressDTO) -> Self {
+        NetworkAddressFields {
+            ip: network_address.get_ip(),
+            port: network_address.get_port(),
+            mac_address: network_address.get_mac_address().as_ref().map(format_mac_address),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NetworkAddressFields> for NetworkAddressDTO {
+    type Error = AddressError;
+
+    fn try_from(fields: NetworkAddressFields) -> Result<Self, Self::Error> {
+        let mac_address = fields.mac_address.as_deref().map(parse_mac_address).transpose()?;
+        Ok(NetworkAddressDTO::new(fields.ip, fields.port, mac_address))
+    }
+}
+
+/// Parses an IP, optionally followed by a port, e.g. `10.0.0.1`, `10.0.0.1:443`, `::1` or `[::1]:443`,
+/// which is how addresses were written before they were structured.
+impl FromStr for NetworkAddressDTO {
+    type Err = AddressError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if let Ok(socket_address) = SocketAddr::from_str(text) {
+            return Ok(NetworkAddressDTO::new(socket_address.ip(), Some(socket_address.port()), None));
+        }
+
+        let ip = text.strip_prefix('[')
+            .and_then(|text| text.strip_suffix(']'))
+            .unwrap_or(text);
+        IpAddr::from_str(ip)
+            .map(|ip| NetworkAddressDTO::new(ip, None, None))
+            .map_err(|_| AddressError::InvalidAddress(text.into()))
+    }
+}
+
+/// Writes the IP, followed by the port if any, in the form [`FromStr`] parses.
+impl fmt::Display for NetworkAddressDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.get_ip(), self.get_port()) {
+            (ip, Some(port)) => write!(f, "{}", SocketAddr::new(ip, port)),
+            (ip, None) => write!(f, "{ip}"),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+    use std::net::Ipv4Addr;
+    use std::net::Ipv6Addr;
+
+    use ion_rs::IonType;
+    use ion_rs::IonReader;
+    use ion_rs::ReaderBuilder;
+    use ion_rs::StreamItem;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::network_address::address_error::AddressError;
+    use crate::api::network_address::ip_version::IpVersion;
+    use crate::api::network_address::network_address::NetworkAddressDTO;
+
+
+    const IP: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    const PORT: u16 = 443;
+    const MAC_ADDRESS: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+    #[test]
+    fn reader_correctly_read_encoded_network_address() {
+        let network_address = NetworkAddressDTO::new(IP, Some(PORT), Some(MAC_ADDRESS));
+        let mut binary_user_reader = ReaderBuilder::new().build(network_address.encode()).unwrap();
+
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
+        binary_user_reader.step_in().unwrap();
+
+        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!("ip_version", binary_user_reader.field_name().unwrap());
+        assert_eq!(4, binary_user_reader.read_i64().unwrap());
+
+        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!("ip", binary_user_reader.field_name().unwrap());
+        assert_eq!([10, 0, 0, 1], binary_user_reader.read_blob().unwrap().as_slice());
+
+        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!("port", binary_user_reader.field_name().unwrap());
+        assert_eq!(i64::from(PORT), binary_user_reader.read_i64().unwrap());
+
+        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!("mac_address", binary_user_reader.field_name().unwrap());
+        assert_eq!(MAC_ADDRESS, binary_user_reader.read_blob().unwrap().as_slice());
+
+        binary_user_reader.step_out().unwrap();
+    }
+
+    #[test]
+    fn endec_network_address() {
+        let network_address = NetworkAddressDTO::new(IP, Some(PORT), Some(MAC_ADDRESS));
+        assert_eq!(network_address, NetworkAddressDTO::decode(&network_address.encode()));
+
+        let network_address = NetworkAddressDTO::new(IpAddr::V6(Ipv6Addr::LOCALHOST), None, None);
+        assert_eq!(network_address, NetworkAddressDTO::decode(&network_address.encode()));
+        assert_eq!(network_address.get_ip_version(), IpVersion::V6);
+        assert_eq!(network_address.get_port(), None);
+        assert_eq!(network_address.get_mac_address(), None);
+    }
+
+    #[test]
+    fn addresses_are_parsed_with_or_without_port() {
+        assert_eq!("10.0.0.1:443".parse(), Ok(NetworkAddressDTO::new(IP, Some(PORT), None)));
+        assert_eq!("10.0.0.1".parse(), Ok(NetworkAddressDTO::new(IP, None, None)));
+        assert_eq!("0.0.0.0:0000".parse(), Ok(NetworkAddressDTO::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), Some(0), None)));
+        assert_eq!("[::1]:443".parse(), Ok(NetworkAddressDTO::new(IpAddr::V6(Ipv6Addr::LOCALHOST), Some(PORT), None)));
+        assert_eq!("[::1]".parse(), Ok(NetworkAddressDTO::new(IpAddr::V6(Ipv6Addr::LOCALHOST), None, None)));
+        assert_eq!("::1".parse(), Ok(NetworkAddressDTO::new(IpAddr::V6(Ipv6Addr::LOCALHOST), None, None)));
+
+        for text in ["", "10.0.0.1:65536", "10.0.0.256", "localhost:443", "::1:443:x"] {
+            assert_eq!(text.parse::<NetworkAddressDTO>(), Err(AddressError::InvalidAddress(text.into())));
+        }
+    }
+
+    #[test]
+    fn addresses_are_written_the_way_they_are_parsed() {
+        for text in ["10.0.0.1:443", "10.0.0.1", "[::1]:443", "::1"] {
+            assert_eq!(text.parse::<NetworkAddressDTO>().unwrap().to_string(), text);
+        }
+    }
+
+    #[test]
+    fn invalid_address_is_not_decoded() {
+        let invalid_ip = NetworkAddressDTO { ip_version: 6, ip: vec![10, 0, 0, 1], port: None, mac_address: None };
+        assert_eq!(invalid_ip.validate(), Err(AddressError::InvalidIpLength { ip_version: IpVersion::V6, length: 4 }));
+
+        let invalid_port = NetworkAddressDTO { port: Some(65_536), ..NetworkAddressDTO::new(IP, None, None) };
+        assert_eq!(invalid_port.validate(), Err(AddressError::PortOutOfRange(65_536)));
+
+        let invalid_mac_address = NetworkAddressDTO { mac_address: Some(vec![0; 8]), ..NetworkAddressDTO::new(IP, None, None) };
+        assert_eq!(invalid_mac_address.validate(), Err(AddressError::InvalidMacAddressLength(8)));
+
+        let unknown_ip_version = NetworkAddressDTO { ip_version: 5, ..NetworkAddressDTO::new(IP, None, None) };
+        let error = NetworkAddressDTO::try_decode(&unknown_ip_version.encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(AddressError::UnknownIpVersion(5).to_string()));
+    }
+}
\ No newline at end of file
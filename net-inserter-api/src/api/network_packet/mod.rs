@@ -1,2 +1,5 @@
 #[allow(clippy::module_inception)]
-pub mod network_packet;
\ No newline at end of file
+pub mod network_packet;
+pub mod packet_metadata;
+pub mod packet_metadata_error;
+pub mod tcp_flags;
\ No newline at end of file
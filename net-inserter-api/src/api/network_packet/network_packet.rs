@@ -1,123 +1,99 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_api::core::compression::Compression;
+use net_codec_api::core::time_unit::TimeUnit;
+use net_codec_api::core::timestamp::Timestamp;
 
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+use crate::api::network_address::network_address::NetworkAddressDTO;
+
+
+use super::packet_metadata::PacketMetadataDTO;
 
 
 const DATA_TYPE: &str = "network_packet";
+const VERSION: u32 = 5;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkPacketDTO {
-    frame_time: i64,
+    /// When the frame was captured, written as milliseconds since the Unix epoch before version 2.
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    frame_time: Timestamp,
+
+    /// Where the frame comes from and goes to, written as strings like `0.0.0.0:5656` before version 3.
+    #[ion(legacy_string)]
+    src_addr: NetworkAddressDTO,
+    #[ion(legacy_string)]
+    dst_addr: NetworkAddressDTO,
+
+    /// The captured frame, compressed on the wire with `codec`, if any.
+    #[ion(compressed_by = codec)]
+    network_packet_data: Vec<u8>,
 
-    src_addr: String,
-    dst_addr: String,
+    metadata: Option<PacketMetadataDTO>,
 
-    network_packet_data: Vec<u8>,
+    #[ion(as_string)]
+    codec: Option<Compression>,
 }
 impl API for NetworkPacketDTO { }
 
 impl NetworkPacketDTO {
-    pub fn new(frame_time: i64, src_addr: &str, dst_addr: &str, network_packet_data: &[u8]) -> Self {
+    pub fn new(frame_time: Timestamp, src_addr: NetworkAddressDTO, dst_addr: NetworkAddressDTO, network_packet_data: &[u8]) -> Self {
         NetworkPacketDTO { 
             frame_time, 
-            src_addr: src_addr.into(), 
-            dst_addr: dst_addr.into(), 
-            network_packet_data: network_packet_data.into()
+            src_addr, 
+            dst_addr, 
+            network_packet_data: network_packet_data.into(),
+            metadata: None,
+            codec: None,
+        }
+    }
+
+    pub fn new_with_metadata(
+        frame_time: Timestamp,
+        src_addr: NetworkAddressDTO,
+        dst_addr: NetworkAddressDTO,
+        network_packet_data: &[u8],
+        metadata: PacketMetadataDTO,
+    ) -> Self {
+        NetworkPacketDTO {
+            metadata: Some(metadata),
+            ..NetworkPacketDTO::new(frame_time, src_addr, dst_addr, network_packet_data)
         }
     }
 
-    pub fn get_frame_time(&self) -> i64 {
+    /// Compresses the frame with `compression` when the packet is encoded, it is decompressed when decoded.
+    pub fn with_compression(self, compression: Compression) -> Self {
+        NetworkPacketDTO { codec: Some(compression), ..self }
+    }
+
+    pub fn get_frame_time(&self) -> Timestamp {
         self.frame_time
     }
 
-    pub fn get_src_addr(&self) -> &str {
+    pub fn get_src_addr(&self) -> &NetworkAddressDTO {
         &self.src_addr
     }
 
-    pub fn get_dst_addr(&self) -> &str {
+    pub fn get_dst_addr(&self) -> &NetworkAddressDTO {
         &self.dst_addr
     }
 
+    /// The frame as it was captured, decompressed if it was sent compressed.
     pub fn get_network_packet_data(&self) -> &[u8] {
         &self.network_packet_data
     }
-}
-
-impl Encoder for NetworkPacketDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("frame_time");
-        writer.write_i64(self.frame_time).unwrap();
-
-        writer.set_field_name("src_addr");
-        writer.write_string(&self.src_addr).unwrap();
-
-        writer.set_field_name("dst_addr");
-        writer.write_string(&self.dst_addr).unwrap();
-
-        writer.set_field_name("network_packet_data");
-        writer.write_blob(&self.network_packet_data).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkPacketDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let frame_time = binary_user_reader.read_i64().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let src_addr = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let dst_addr = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_blob().unwrap();
-        let network_packet_data = binding.as_slice();
-
-        NetworkPacketDTO::new(
-            frame_time, 
-            src_addr, 
-            dst_addr, 
-            network_packet_data
-        )
-    }
-}
 
-impl Typed for NetworkPacketDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+    /// The codec the frame is sent compressed with, unset if it is sent as is.
+    pub fn get_compression(&self) -> Option<Compression> {
+        self.codec
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    pub fn get_metadata(&self) -> Option<&PacketMetadataDTO> {
+        self.metadata.as_ref()
     }
 }
 
@@ -133,19 +109,25 @@ mod tests {
     use net_core_api::core::decoder_api::Decoder;
     use net_core_api::core::typed_api::Typed;
 
+    use net_codec_api::core::compression::Compression;
+    use net_codec_api::core::timestamp::Timestamp;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::network_address::network_address::NetworkAddressDTO;
     use crate::api::network_packet::network_packet::NetworkPacketDTO;
+    use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
 
 
     #[test]
     fn reader_correctly_read_encoded_network_paket() {        
-        const FRAME_TIME: i64 = i64::MIN;
+        const FRAME_TIME: Timestamp = Timestamp::MIN;
         const SRC_ADDR: &str = "0.0.0.0:0000";
         const DST_ADDR: &str = "0.0.0.0:5656";
         const NETWORK_PACKET_DATA: &[u8] = "NETWORK_PACKET_DATA".as_bytes();
         let network_paket = NetworkPacketDTO::new(
             FRAME_TIME, 
-            SRC_ADDR, 
-            DST_ADDR, 
+            SRC_ADDR.parse().unwrap(), 
+            DST_ADDR.parse().unwrap(), 
             NETWORK_PACKET_DATA
         );
         let mut binary_user_reader = ReaderBuilder::new().build(network_paket.encode()).unwrap();
@@ -153,49 +135,103 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("frame_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(FRAME_TIME, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(FRAME_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
         
-        assert_eq!(StreamItem::Value(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         assert_eq!("src_addr", binary_user_reader.field_name().unwrap());
-        assert_eq!(SRC_ADDR,  binary_user_reader.read_string().unwrap().text());
 
-        assert_eq!(StreamItem::Value(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         assert_eq!("dst_addr", binary_user_reader.field_name().unwrap());
-        assert_eq!(DST_ADDR,  binary_user_reader.read_string().unwrap().text());
 
         assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
         assert_eq!("network_packet_data", binary_user_reader.field_name().unwrap());
         assert_eq!(NETWORK_PACKET_DATA, binary_user_reader.read_blob().unwrap().as_slice());
+
+        assert_eq!(StreamItem::Null(IonType::Struct), binary_user_reader.next().unwrap());
+        assert_eq!("metadata", binary_user_reader.field_name().unwrap());
+
+        assert_eq!(StreamItem::Null(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!("codec", binary_user_reader.field_name().unwrap());
     }
 
     #[test]
     #[ignore]
     fn endec_network_paket() {
-        const FRAME_TIME: i64 = i64::MIN;
+        const FRAME_TIME: Timestamp = Timestamp::MIN;
         const SRC_ADDR: &str = "0.0.0.0:0000";
         const DST_ADDR: &str = "0.0.0.0:5656";
         const NETWORK_PACKET_DATA: &[u8] = "NETWORK_PACKET_DATA".as_bytes();
         let network_paket = NetworkPacketDTO::new(
             FRAME_TIME, 
-            SRC_ADDR, 
-            DST_ADDR, 
+            SRC_ADDR.parse().unwrap(), 
+            DST_ADDR.parse().unwrap(), 
             NETWORK_PACKET_DATA
         );
         assert_eq!(network_paket, NetworkPacketDTO::decode(&network_paket.encode()));
     }
 
+    #[test]
+    fn endec_network_packet_with_metadata() {
+        let network_packet = NetworkPacketDTO::new_with_metadata(
+            Timestamp::from_millis(1_700_000_000_000).unwrap(),
+            "10.0.0.1:443".parse().unwrap(),
+            "10.0.0.2:5656".parse().unwrap(),
+            &[1, 2, 3],
+            PacketMetadataDTO::new().with_lengths(3, 1514).with_protocols("IPv4", Some("TCP")),
+        );
+        assert_eq!(network_packet, NetworkPacketDTO::decode(&network_packet.encode()));
+        assert_eq!(network_packet.get_metadata().and_then(PacketMetadataDTO::get_captured_length), Some(3));
+    }
+
+    #[test]
+    fn endec_compressed_network_packet() {
+        let network_packet_data = "NETWORK_PACKET_DATA".repeat(64);
+        for compression in Compression::ALL {
+            let network_packet = NetworkPacketDTO::new(
+                Timestamp::from_millis(1_700_000_000_000).unwrap(),
+                "10.0.0.1:443".parse().unwrap(),
+                "10.0.0.2:5656".parse().unwrap(),
+                network_packet_data.as_bytes(),
+            ).with_compression(compression);
+            let encoded = network_packet.encode();
+            assert!(encoded.len() < network_packet_data.len());
+
+            let decoded = NetworkPacketDTO::decode(&encoded);
+            assert_eq!(decoded.get_network_packet_data(), network_packet_data.as_bytes());
+            assert_eq!(decoded.get_compression(), Some(compression));
+        }
+    }
+
+    #[test]
+    fn network_packet_with_string_addresses_is_decoded() {
+        const LEGACY_NETWORK_PACKET: &str = r#"v2::{
+            frame_time: 2023-11-14T22:13:20Z,
+            src_addr: "10.0.0.1:443",
+            dst_addr: "[::1]:5656",
+            network_packet_data: {{}},
+        }"#;
+        let network_packet = NetworkPacketDTO::try_decode(LEGACY_NETWORK_PACKET.as_bytes()).unwrap();
+
+        assert_eq!(network_packet.get_src_addr(), &"10.0.0.1:443".parse::<NetworkAddressDTO>().unwrap());
+        assert_eq!(network_packet.get_dst_addr().get_port(), Some(5656));
+        assert_eq!(network_packet.get_metadata(), None);
+
+        let error = NetworkPacketDTO::try_decode(LEGACY_NETWORK_PACKET.replace("10.0.0.1", "10.0.0.256").as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "src_addr");
+    }
+
     #[test]
     fn test_getting_data_types() {
-        const FRAME_TIME: i64 = i64::MIN;
+        const FRAME_TIME: Timestamp = Timestamp::MIN;
         const SRC_ADDR: &str = "0.0.0.0:0000";
         const DST_ADDR: &str = "0.0.0.0:5656";
         const NETWORK_PACKET_DATA: &[u8] = "NETWORK_PACKET_DATA".as_bytes();
         let network_paket = NetworkPacketDTO::new(
             FRAME_TIME,
-            SRC_ADDR,
-            DST_ADDR,
+            SRC_ADDR.parse().unwrap(),
+            DST_ADDR.parse().unwrap(),
             NETWORK_PACKET_DATA
         );
         assert_eq!(network_paket.get_type(), NetworkPacketDTO::get_data_type());
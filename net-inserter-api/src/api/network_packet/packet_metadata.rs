@@ -0,0 +1,201 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::packet_metadata_error::PacketMetadataError;
+use super::tcp_flags;
+
+
+const DATA_TYPE: &str = "packet_metadata";
+const VERSION: u32 = 1;
+
+pub const MAX_VLAN_ID: i64 = 4095;
+
+/// What the agent already knows about a captured frame, so that it can be stored and indexed without being dissected again.
+/// Every field is optional since not every frame carries it, e.g. frames out of a VLAN have no VLAN id.
+#[derive(Debug, PartialEq, Eq, Clone, Default, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PacketMetadataDTO {
+    captured_length: Option<i64>,
+    original_length: Option<i64>,
+    link_type: Option<i64>,
+    l3_protocol: Option<String>,
+    l4_protocol: Option<String>,
+    tcp_flags: Option<i64>,
+    vlan_id: Option<i64>,
+    interface_name: Option<String>,
+    agent_id: Option<String>,
+}
+impl API for PacketMetadataDTO { }
+
+impl PacketMetadataDTO {
+    /// Metadata knowing nothing yet, which the `with_` methods fill in.
+    pub fn new() -> Self {
+        PacketMetadataDTO::default()
+    }
+
+    /// Sets how many bytes of the frame were captured and how many it held on the wire.
+    pub fn with_lengths(self, captured_length: i64, original_length: i64) -> Self {
+        PacketMetadataDTO {
+            captured_length: Some(captured_length),
+            original_length: Some(original_length),
+            ..self
+        }
+    }
+
+    /// Sets the link-layer header type, as numbered by pcap, e.g. `1` for Ethernet.
+    pub fn with_link_type(self, link_type: i64) -> Self {
+        PacketMetadataDTO { link_type: Some(link_type), ..self }
+    }
+
+    /// Sets the network and transport protocols, named the way the reporter filters them, e.g. `IPv4` and `TCP`.
+    pub fn with_protocols(self, l3_protocol: &str, l4_protocol: Option<&str>) -> Self {
+        PacketMetadataDTO {
+            l3_protocol: Some(l3_protocol.into()),
+            l4_protocol: l4_protocol.map(|l4_protocol| l4_protocol.into()),
+            ..self
+        }
+    }
+
+    /// Sets the flags of the TCP header, a combination of the [`tcp_flags`] bits.
+    pub fn with_tcp_flags(self, tcp_flags: i64) -> Self {
+        PacketMetadataDTO { tcp_flags: Some(tcp_flags), ..self }
+    }
+
+    pub fn with_vlan_id(self, vlan_id: i64) -> Self {
+        PacketMetadataDTO { vlan_id: Some(vlan_id), ..self }
+    }
+
+    pub fn with_interface_name(self, interface_name: &str) -> Self {
+        PacketMetadataDTO { interface_name: Some(interface_name.into()), ..self }
+    }
+
+    pub fn with_agent_id(self, agent_id: &str) -> Self {
+        PacketMetadataDTO { agent_id: Some(agent_id.into()), ..self }
+    }
+
+    pub fn get_captured_length(&self) -> Option<i64> {
+        self.captured_length
+    }
+
+    pub fn get_original_length(&self) -> Option<i64> {
+        self.original_length
+    }
+
+    pub fn get_link_type(&self) -> Option<i64> {
+        self.link_type
+    }
+
+    pub fn get_l3_protocol(&self) -> Option<&str> {
+        self.l3_protocol.as_deref()
+    }
+
+    pub fn get_l4_protocol(&self) -> Option<&str> {
+        self.l4_protocol.as_deref()
+    }
+
+    pub fn get_tcp_flags(&self) -> Option<i64> {
+        self.tcp_flags
+    }
+
+    /// Whether every bit of `tcp_flag`, e.g. [`tcp_flags::SYN`], is set. Always false for packets without TCP flags.
+    pub fn has_tcp_flag(&self, tcp_flag: i64) -> bool {
+        self.tcp_flags.is_some_and(|tcp_flags| tcp_flags & tcp_flag == tcp_flag)
+    }
+
+    pub fn get_vlan_id(&self) -> Option<i64> {
+        self.vlan_id
+    }
+
+    pub fn get_interface_name(&self) -> Option<&str> {
+        self.interface_name.as_deref()
+    }
+
+    pub fn get_agent_id(&self) -> Option<&str> {
+        self.agent_id.as_deref()
+    }
+
+    /// Checks the lengths are not negative and no more bytes were captured than the frame held,
+    /// the link type is not negative, the TCP flags are flags and the VLAN id fits into 12 bits. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), PacketMetadataError> {
+        for length in [self.captured_length, self.original_length].into_iter().flatten() {
+            if length < 0 {
+                return Err(PacketMetadataError::NegativeLength(length));
+            }
+        }
+        if let (Some(captured_length), Some(original_length)) = (self.captured_length, self.original_length) {
+            if captured_length > original_length {
+                return Err(PacketMetadataError::CapturedLengthExceedsOriginal { captured_length, original_length });
+            }
+        }
+
+        match (self.link_type, self.tcp_flags, self.vlan_id) {
+            (Some(link_type), _, _) if link_type < 0 => Err(PacketMetadataError::NegativeLinkType(link_type)),
+            (_, Some(tcp_flags), _) if tcp_flags & !tcp_flags::ALL != 0 => Err(PacketMetadataError::InvalidTcpFlags(tcp_flags)),
+            (_, _, Some(vlan_id)) if !(0..=MAX_VLAN_ID).contains(&vlan_id) => Err(PacketMetadataError::InvalidVlanId(vlan_id)),
+            _ => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
+    use crate::api::network_packet::packet_metadata_error::PacketMetadataError;
+    use crate::api::network_packet::tcp_flags;
+
+
+    fn get_metadata() -> PacketMetadataDTO {
+        PacketMetadataDTO::new()
+            .with_lengths(64, 1514)
+            .with_link_type(1)
+            .with_protocols("IPv4", Some("TCP"))
+            .with_tcp_flags(tcp_flags::SYN | tcp_flags::ACK)
+            .with_vlan_id(42)
+            .with_interface_name("eth0")
+            .with_agent_id("agent-1")
+    }
+
+    #[test]
+    fn endec_packet_metadata() {
+        let metadata = get_metadata();
+        assert_eq!(metadata, PacketMetadataDTO::decode(&metadata.encode()));
+
+        let metadata = PacketMetadataDTO::new();
+        assert_eq!(metadata.get_l3_protocol(), None);
+        assert_eq!(metadata, PacketMetadataDTO::decode(&metadata.encode()));
+    }
+
+    #[test]
+    fn metadata_tells_which_tcp_flags_are_set() {
+        let metadata = get_metadata();
+        assert!(metadata.has_tcp_flag(tcp_flags::SYN));
+        assert!(metadata.has_tcp_flag(tcp_flags::SYN | tcp_flags::ACK));
+        assert!(!metadata.has_tcp_flag(tcp_flags::SYN | tcp_flags::FIN));
+        assert!(!PacketMetadataDTO::new().has_tcp_flag(tcp_flags::SYN));
+    }
+
+    #[test]
+    fn invalid_packet_metadata_is_not_decoded() {
+        assert_eq!(PacketMetadataDTO::new().with_lengths(-1, 0).validate(), Err(PacketMetadataError::NegativeLength(-1)));
+        assert_eq!(
+            PacketMetadataDTO::new().with_lengths(1515, 1514).validate(),
+            Err(PacketMetadataError::CapturedLengthExceedsOriginal { captured_length: 1515, original_length: 1514 }),
+        );
+        assert_eq!(PacketMetadataDTO::new().with_link_type(-1).validate(), Err(PacketMetadataError::NegativeLinkType(-1)));
+        assert_eq!(PacketMetadataDTO::new().with_tcp_flags(0x200).validate(), Err(PacketMetadataError::InvalidTcpFlags(0x200)));
+        assert_eq!(PacketMetadataDTO::new().with_tcp_flags(tcp_flags::ALL).validate(), Ok(()));
+
+        let error = PacketMetadataDTO::try_decode(&PacketMetadataDTO::new().with_vlan_id(4096).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(PacketMetadataError::InvalidVlanId(4096).to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why the metadata of a packet is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PacketMetadataError {
+    /// The captured or the original length is negative.
+    NegativeLength(i64),
+    /// More bytes were captured than the frame held.
+    CapturedLengthExceedsOriginal { captured_length: i64, original_length: i64 },
+    /// The link type is negative.
+    NegativeLinkType(i64),
+    /// The TCP flags set bits beyond the nine flags of a TCP header.
+    InvalidTcpFlags(i64),
+    /// The VLAN id does not fit into 12 bits.
+    InvalidVlanId(i64),
+}
+
+impl fmt::Display for PacketMetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketMetadataError::NegativeLength(length) => write!(f, "a length cannot be negative, it is {length}"),
+            PacketMetadataError::CapturedLengthExceedsOriginal { captured_length, original_length } => write!(
+                f,
+                "the captured length {captured_length} exceeds the original length {original_length}",
+            ),
+            PacketMetadataError::NegativeLinkType(link_type) => write!(f, "the link type cannot be negative, it is {link_type}"),
+            PacketMetadataError::InvalidTcpFlags(tcp_flags) => write!(f, "{tcp_flags:#x} are not TCP flags, which fit into 9 bits"),
+            PacketMetadataError::InvalidVlanId(vlan_id) => write!(f, "the VLAN id has to be between 0 and 4095, it is {vlan_id}"),
+        }
+    }
+}
+
+impl Error for PacketMetadataError { }
\ No newline at end of file
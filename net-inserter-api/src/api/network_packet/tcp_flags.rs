@@ -0,0 +1,14 @@
+//! Bits of the flags of a TCP header, as [`PacketMetadataDTO::get_tcp_flags`](super::packet_metadata::PacketMetadataDTO::get_tcp_flags) holds them.
+
+pub const FIN: i64 = 0x001;
+pub const SYN: i64 = 0x002;
+pub const RST: i64 = 0x004;
+pub const PSH: i64 = 0x008;
+pub const ACK: i64 = 0x010;
+pub const URG: i64 = 0x020;
+pub const ECE: i64 = 0x040;
+pub const CWR: i64 = 0x080;
+pub const NS: i64 = 0x100;
+
+/// Every flag set at once, the nine bits of the header reserved for flags.
+pub const ALL: i64 = FIN | SYN | RST | PSH | ACK | URG | ECE | CWR | NS;
\ No newline at end of file
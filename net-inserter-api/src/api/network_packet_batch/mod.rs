@@ -0,0 +1,3 @@
+#[allow(clippy::module_inception)]
+pub mod network_packet_batch;
+pub mod network_packet_batch_reader;
\ No newline at end of file
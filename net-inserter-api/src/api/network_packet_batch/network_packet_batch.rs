@@ -0,0 +1,127 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+use crate::api::network_packet::network_packet::NetworkPacketDTO;
+use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
+
+
+const DATA_TYPE: &str = "network_packet_batch";
+const VERSION: u32 = 2;
+
+/// Many packets sent as a single document. The agent id, interface name and link type the packets share are written once
+/// in the header of the batch rather than in the metadata of every packet.
+/// [`NetworkPacketBatchReader`](super::network_packet_batch_reader::NetworkPacketBatchReader) reads the packets one at a time.
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkPacketBatchDTO {
+    agent_id: Option<String>,
+    interface_name: Option<String>,
+    link_type: Option<i64>,
+    packets: Vec<NetworkPacketDTO>,
+}
+impl API for NetworkPacketBatchDTO { }
+
+impl NetworkPacketBatchDTO {
+    pub fn new(agent_id: Option<&str>, interface_name: Option<&str>, link_type: Option<i64>, packets: Vec<NetworkPacketDTO>) -> Self {
+        NetworkPacketBatchDTO {
+            agent_id: agent_id.map(|agent_id| agent_id.into()),
+            interface_name: interface_name.map(|interface_name| interface_name.into()),
+            link_type,
+            packets,
+        }
+    }
+
+    pub fn get_agent_id(&self) -> Option<&str> {
+        self.agent_id.as_deref()
+    }
+
+    pub fn get_interface_name(&self) -> Option<&str> {
+        self.interface_name.as_deref()
+    }
+
+    pub fn get_link_type(&self) -> Option<i64> {
+        self.link_type
+    }
+
+    pub fn get_packets(&self) -> &[NetworkPacketDTO] {
+        &self.packets
+    }
+
+    pub fn into_packets(self) -> Vec<NetworkPacketDTO> {
+        self.packets
+    }
+
+    /// The metadata of `packet` completed with the header of the batch.
+    pub fn get_packet_metadata(&self, packet: &NetworkPacketDTO) -> PacketMetadataDTO {
+        complete_packet_metadata(packet, self.get_agent_id(), self.get_interface_name(), self.link_type)
+    }
+}
+
+/// Fills the fields of the metadata of `packet` it leaves unset with the ones of the header of its batch.
+pub(crate) fn complete_packet_metadata(
+    packet: &NetworkPacketDTO,
+    agent_id: Option<&str>,
+    interface_name: Option<&str>,
+    link_type: Option<i64>,
+) -> PacketMetadataDTO {
+    let mut metadata = packet.get_metadata().cloned().unwrap_or_default();
+    if let (None, Some(agent_id)) = (metadata.get_agent_id(), agent_id) {
+        metadata = metadata.with_agent_id(agent_id);
+    }
+    if let (None, Some(interface_name)) = (metadata.get_interface_name(), interface_name) {
+        metadata = metadata.with_interface_name(interface_name);
+    }
+    if let (None, Some(link_type)) = (metadata.get_link_type(), link_type) {
+        metadata = metadata.with_link_type(link_type);
+    }
+    metadata
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::network_packet::network_packet::NetworkPacketDTO;
+    use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
+    use crate::api::network_packet_batch::network_packet_batch::NetworkPacketBatchDTO;
+
+
+    fn get_packet(metadata: Option<PacketMetadataDTO>) -> NetworkPacketDTO {
+        let frame_time = Timestamp::from_millis(1_700_000_000_000).unwrap();
+        let src_addr = "10.0.0.1:443".parse().unwrap();
+        let dst_addr = "10.0.0.2:5656".parse().unwrap();
+        match metadata {
+            Some(metadata) => NetworkPacketDTO::new_with_metadata(frame_time, src_addr, dst_addr, &[1, 2, 3], metadata),
+            None => NetworkPacketDTO::new(frame_time, src_addr, dst_addr, &[1, 2, 3]),
+        }
+    }
+
+    #[test]
+    fn endec_network_packet_batch() {
+        let batch = NetworkPacketBatchDTO::new(Some("agent-1"), Some("eth0"), Some(1), vec![get_packet(None), get_packet(None)]);
+        assert_eq!(batch, NetworkPacketBatchDTO::decode(&batch.encode()));
+
+        let batch = NetworkPacketBatchDTO::new(None, None, None, Vec::new());
+        assert_eq!(batch, NetworkPacketBatchDTO::decode(&batch.encode()));
+    }
+
+    #[test]
+    fn packet_metadata_is_completed_with_the_header() {
+        let batch = NetworkPacketBatchDTO::new(Some("agent-1"), Some("eth0"), Some(1), Vec::new());
+
+        assert_eq!(
+            batch.get_packet_metadata(&get_packet(None)),
+            PacketMetadataDTO::new().with_agent_id("agent-1").with_interface_name("eth0").with_link_type(1),
+        );
+        assert_eq!(
+            batch.get_packet_metadata(&get_packet(Some(PacketMetadataDTO::new().with_interface_name("eth1").with_vlan_id(42)))),
+            PacketMetadataDTO::new().with_agent_id("agent-1").with_interface_name("eth1").with_link_type(1).with_vlan_id(42),
+        );
+    }
+}
\ No newline at end of file
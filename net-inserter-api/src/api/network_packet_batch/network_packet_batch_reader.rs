@@ -0,0 +1,151 @@
+use ion_rs::IonType;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::list_reader::ListReader;
+
+use crate::api::network_packet::network_packet::NetworkPacketDTO;
+use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
+
+
+use super::network_packet_batch::complete_packet_metadata;
+
+
+/// Reads the packets of an encoded [`NetworkPacketBatchDTO`](super::network_packet_batch::NetworkPacketBatchDTO)
+/// one at a time, so that a large batch can be inserted without holding all of its packets in memory.
+///
+/// The header of the batch is read when the reader is created. It has to precede the packets,
+/// as it does in every batch `NetworkPacketBatchDTO` writes, header fields written after them are ignored.
+/// The reader stops after the first packet it fails to decode.
+pub struct NetworkPacketBatchReader<'a> {
+    packets: ListReader<'a, NetworkPacketDTO>,
+    agent_id: Option<String>,
+    interface_name: Option<String>,
+    link_type: Option<i64>,
+}
+
+impl<'a> NetworkPacketBatchReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut reader = DecodeReader::new(data)?;
+        let mut agent_id = None;
+        let mut interface_name = None;
+        let mut link_type = None;
+
+        reader.seek_field("packets", IonType::List, |reader, field| {
+            match field {
+                "agent_id" => agent_id = reader.read_optional_string()?,
+                "interface_name" => interface_name = reader.read_optional_string()?,
+                "link_type" => link_type = reader.read_optional_i64()?,
+                _ => (),
+            }
+            Ok(())
+        })?;
+        let packets = ListReader::new(reader)?;
+
+        Ok(NetworkPacketBatchReader { packets, agent_id, interface_name, link_type })
+    }
+
+    pub fn get_agent_id(&self) -> Option<&str> {
+        self.agent_id.as_deref()
+    }
+
+    pub fn get_interface_name(&self) -> Option<&str> {
+        self.interface_name.as_deref()
+    }
+
+    pub fn get_link_type(&self) -> Option<i64> {
+        self.link_type
+    }
+
+    /// The metadata of `packet` completed with the header of the batch.
+    pub fn get_packet_metadata(&self, packet: &NetworkPacketDTO) -> PacketMetadataDTO {
+        complete_packet_metadata(packet, self.get_agent_id(), self.get_interface_name(), self.link_type)
+    }
+}
+
+impl Iterator for NetworkPacketBatchReader<'_> {
+    type Item = Result<NetworkPacketDTO, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.packets.next()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonType;
+
+    use net_core_api::core::encoder_api::Encoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::network_packet::network_packet::NetworkPacketDTO;
+    use crate::api::network_packet::packet_metadata::PacketMetadataDTO;
+    use crate::api::network_packet_batch::network_packet_batch::NetworkPacketBatchDTO;
+    use crate::api::network_packet_batch::network_packet_batch_reader::NetworkPacketBatchReader;
+
+
+    fn get_packet(port: u16) -> NetworkPacketDTO {
+        NetworkPacketDTO::new(
+            Timestamp::from_millis(1_700_000_000_000).unwrap(),
+            "10.0.0.1:443".parse().unwrap(),
+            format!("10.0.0.2:{port}").parse().unwrap(),
+            &[1, 2, 3],
+        )
+    }
+
+    #[test]
+    fn packets_are_read_one_at_a_time() {
+        let batch = NetworkPacketBatchDTO::new(Some("agent-1"), Some("eth0"), Some(1), vec![get_packet(1), get_packet(2)]);
+        let data = batch.encode();
+        let mut reader = NetworkPacketBatchReader::new(&data).unwrap();
+
+        assert_eq!(reader.get_agent_id(), Some("agent-1"));
+        assert_eq!(reader.get_interface_name(), Some("eth0"));
+        assert_eq!(reader.get_link_type(), Some(1));
+
+        let packet = reader.next().unwrap().unwrap();
+        assert_eq!(packet, get_packet(1));
+        assert_eq!(reader.get_packet_metadata(&packet), PacketMetadataDTO::new().with_agent_id("agent-1").with_interface_name("eth0").with_link_type(1));
+        assert_eq!(reader.next(), Some(Ok(get_packet(2))));
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn empty_batch_has_no_packets() {
+        let data = NetworkPacketBatchDTO::new(None, None, None, Vec::new()).encode();
+        let mut reader = NetworkPacketBatchReader::new(&data).unwrap();
+
+        assert_eq!(reader.get_agent_id(), None);
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn reader_stops_at_the_first_invalid_packet() {
+        let data = r#"v1::{
+            agent_id: "agent-1",
+            packets: [
+                v4::{frame_time: 2023-11-14T22:13:20Z, src_addr: "10.0.0.1:443", dst_addr: "10.0.0.2:1", network_packet_data: {{AQID}}},
+                v4::{frame_time: 2023-11-14T22:13:20Z, src_addr: "10.0.0.1:443", network_packet_data: {{AQID}}},
+                v4::{frame_time: 2023-11-14T22:13:20Z, src_addr: "10.0.0.1:443", dst_addr: "10.0.0.2:3", network_packet_data: {{AQID}}},
+            ],
+        }"#;
+        let mut reader = NetworkPacketBatchReader::new(data.as_bytes()).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_packet(1))));
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(error.get_field(), "packets[1].dst_addr");
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn batch_without_packets_is_rejected() {
+        let error = NetworkPacketBatchReader::new(r#"v1::{agent_id: "agent-1"}"#.as_bytes()).err().unwrap();
+        assert_eq!(error.get_field(), "packets");
+        assert_eq!(error.get_expected_type(), Some(IonType::List));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::MissingField);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use net_codec_api::core::golden::check_golden;
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_inserter_api::api::network_address::network_address::NetworkAddressDTO;
+use net_inserter_api::api::network_packet::network_packet::NetworkPacketDTO;
+use net_inserter_api::api::network_packet::packet_metadata::PacketMetadataDTO;
+use net_inserter_api::api::network_packet::tcp_flags;
+use net_inserter_api::api::network_packet_batch::network_packet_batch::NetworkPacketBatchDTO;
+
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+
+#[test]
+fn golden_network_address() {
+    check_golden(
+        GOLDEN_DIR,
+        "network_address",
+        &NetworkAddressDTO::new("10.0.0.1".parse().unwrap(), Some(443), Some([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])),
+    );
+}
+
+#[test]
+fn golden_network_packet() {
+    check_golden(
+        GOLDEN_DIR,
+        "network_packet",
+        &NetworkPacketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), "0.0.0.0:0000".parse().unwrap(), "0.0.0.0:5656".parse().unwrap(), "NETWORK_PACKET_DATA".as_bytes()),
+    );
+}
+
+#[test]
+fn golden_packet_metadata() {
+    check_golden(
+        GOLDEN_DIR,
+        "packet_metadata",
+        &PacketMetadataDTO::new()
+            .with_lengths(64, 1514)
+            .with_link_type(1)
+            .with_protocols("IPv4", Some("TCP"))
+            .with_tcp_flags(tcp_flags::SYN | tcp_flags::ACK)
+            .with_vlan_id(42)
+            .with_interface_name("eth0")
+            .with_agent_id("agent-1"),
+    );
+}
+
+#[test]
+fn golden_network_packet_batch() {
+    let packets = vec![
+        NetworkPacketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), "0.0.0.0:0000".parse().unwrap(), "0.0.0.0:5656".parse().unwrap(), &[1, 2, 3]),
+        NetworkPacketDTO::new_with_metadata(
+            Timestamp::from_millis(1_700_000_000_001).unwrap(),
+            "0.0.0.0:5656".parse().unwrap(),
+            "0.0.0.0:0000".parse().unwrap(),
+            &[4, 5, 6],
+            PacketMetadataDTO::new().with_lengths(3, 3).with_vlan_id(42),
+        ),
+    ];
+    check_golden(GOLDEN_DIR, "network_packet_batch", &NetworkPacketBatchDTO::new(Some("agent-1"), Some("eth0"), Some(1), packets));
+}
\ No newline at end of file
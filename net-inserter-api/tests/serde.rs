@@ -0,0 +1,82 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+
+use net_core_api::core::decoder_api::Decoder;
+use net_core_api::core::encoder_api::Encoder;
+
+use net_codec_api::core::compression::Compression;
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_inserter_api::api::network_address::network_address::NetworkAddressDTO;
+use net_inserter_api::api::network_packet::network_packet::NetworkPacketDTO;
+use net_inserter_api::api::network_packet::packet_metadata::PacketMetadataDTO;
+use net_inserter_api::api::network_packet_batch::network_packet_batch::NetworkPacketBatchDTO;
+
+
+#[test]
+fn json_round_trip_network_packet() {
+    let network_packet = NetworkPacketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), "0.0.0.0:0000".parse().unwrap(), "0.0.0.0:5656".parse().unwrap(), &[1, 2, 3]);
+    let network_packet = NetworkPacketDTO::decode(&network_packet.encode());
+
+    let json = serde_json::to_value(&network_packet).unwrap();
+    assert_eq!(json, json!({
+        "frame_time": "2023-11-14T22:13:20Z",
+        "src_addr": {"ip": "0.0.0.0", "port": 0, "mac_address": null},
+        "dst_addr": {"ip": "0.0.0.0", "port": 5656, "mac_address": null},
+        "network_packet_data": [1, 2, 3],
+        "metadata": null,
+        "codec": null,
+    }));
+    assert_eq!(serde_json::from_value::<NetworkPacketDTO>(json).unwrap(), network_packet);
+}
+
+#[test]
+fn json_codec_is_a_known_codec() {
+    let network_packet = NetworkPacketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), "0.0.0.0:0000".parse().unwrap(), "0.0.0.0:5656".parse().unwrap(), &[1, 2, 3])
+        .with_compression(Compression::Zstd);
+
+    let mut json = serde_json::to_value(&network_packet).unwrap();
+    assert_eq!(json["codec"], "zstd");
+    assert_eq!(serde_json::from_value::<NetworkPacketDTO>(json.clone()).unwrap(), network_packet);
+
+    json["codec"] = "gzip".into();
+    assert!(serde_json::from_value::<NetworkPacketDTO>(json).is_err());
+}
+
+#[test]
+fn json_network_address_is_validated() {
+    let network_address = NetworkAddressDTO::new("::1".parse().unwrap(), Some(443), Some([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]));
+    let json = serde_json::to_value(&network_address).unwrap();
+    assert_eq!(json, json!({"ip": "::1", "port": 443, "mac_address": "00:1a:2b:3c:4d:5e"}));
+    assert_eq!(serde_json::from_value::<NetworkAddressDTO>(json).unwrap(), network_address);
+
+    for invalid in [
+        json!({"ip": [10, 0, 0, 1], "port": null, "mac_address": null}),
+        json!({"ip": "10.0.0.1", "port": 65536, "mac_address": null}),
+        json!({"ip": "10.0.0.1", "port": null, "mac_address": "00:1a:2b"}),
+    ] {
+        assert!(serde_json::from_value::<NetworkAddressDTO>(invalid).is_err());
+    }
+}
+
+#[test]
+fn json_round_trip_packet_metadata() {
+    let metadata = PacketMetadataDTO::new().with_lengths(64, 1514).with_protocols("IPv6", None).with_interface_name("eth0");
+
+    let json = serde_json::to_value(&metadata).unwrap();
+    assert_eq!(json["l3_protocol"], "IPv6");
+    assert_eq!(json["vlan_id"], serde_json::Value::Null);
+    assert_eq!(serde_json::from_value::<PacketMetadataDTO>(json).unwrap(), metadata);
+}
+
+#[test]
+fn json_round_trip_network_packet_batch() {
+    let network_packet = NetworkPacketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), "0.0.0.0:0000".parse().unwrap(), "0.0.0.0:5656".parse().unwrap(), &[1, 2, 3]);
+    let batch = NetworkPacketBatchDTO::new(Some("agent-1"), None, Some(1), vec![network_packet]);
+
+    let json = serde_json::to_value(&batch).unwrap();
+    assert_eq!(json["agent_id"], "agent-1");
+    assert_eq!(json["packets"][0]["frame_time"], "2023-11-14T22:13:20Z");
+    assert_eq!(serde_json::from_value::<NetworkPacketBatchDTO>(json).unwrap(), batch);
+}
\ No newline at end of file
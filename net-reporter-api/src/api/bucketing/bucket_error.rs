@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why the bucketing requested or returned for a time-series chart is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BucketError {
+    /// The bucket interval is neither fixed nor automatic, or both at once.
+    AmbiguousInterval,
+    /// The fixed bucket interval is not positive.
+    NonPositiveInterval(i64),
+    /// The target point count of an automatic bucket interval is not positive.
+    NonPositiveTargetPointCount(i64),
+    /// The time zone is neither `UTC`, an offset such as `+02:00`, nor a name such as `Europe/Kyiv`.
+    InvalidTimeZone(String),
+    /// A bucket interval or a time zone is requested from a chart which is not a time series.
+    NotBucketed { data_type: &'static str },
+}
+
+impl fmt::Display for BucketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BucketError::AmbiguousInterval => write!(f, "the bucket interval has to be either fixed or automatic"),
+            BucketError::NonPositiveInterval(interval) => write!(f, "the bucket interval has to be positive, it is {interval}"),
+            BucketError::NonPositiveTargetPointCount(target_point_count) => write!(
+                f,
+                "the target point count has to be positive, it is {target_point_count}",
+            ),
+            BucketError::InvalidTimeZone(time_zone) => write!(f, "`{time_zone}` is not a time zone"),
+            BucketError::NotBucketed { data_type } => write!(f, "`{data_type}` is not a time series, it cannot be bucketed"),
+        }
+    }
+}
+
+impl Error for BucketError { }
\ No newline at end of file
@@ -0,0 +1,98 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+use crate::api::time_range::time_unit::TimeUnit;
+
+
+use super::bucket_error::BucketError;
+use super::bucket_interval::LEGACY_UNIT;
+use super::time_zone::validate_time_zone;
+
+
+const DATA_TYPE: &str = "bucket_info";
+const VERSION: u32 = 2;
+
+/// Describes the buckets of a time-series chart response: how wide they are, a `bucket_interval` of `unit`,
+/// and the time zone they are aligned in, UTC when unset. An interval naming no unit, as written before version 2, is read as milliseconds.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = BucketInfoDTO::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BucketInfoDTO {
+    bucket_interval: i64,
+    #[ion(as_string, default = LEGACY_UNIT)]
+    unit: TimeUnit,
+    time_zone: Option<String>,
+}
+impl API for BucketInfoDTO { }
+
+impl BucketInfoDTO {
+    pub fn new(bucket_interval: i64, unit: TimeUnit, time_zone: Option<&str>) -> Self {
+        BucketInfoDTO {
+            bucket_interval,
+            unit,
+            time_zone: time_zone.map(|time_zone| time_zone.into()),
+        }
+    }
+
+    pub fn get_bucket_interval(&self) -> i64 {
+        self.bucket_interval
+    }
+
+    pub fn get_unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    pub fn get_time_zone(&self) -> Option<&str> {
+        self.time_zone.as_deref()
+    }
+
+    /// Checks the interval is positive and the time zone, if any, is valid. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), BucketError> {
+        if self.bucket_interval <= 0 {
+            return Err(BucketError::NonPositiveInterval(self.bucket_interval));
+        }
+
+        self.time_zone.as_deref().map_or(Ok(()), validate_time_zone)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::bucketing::bucket_error::BucketError;
+    use crate::api::bucketing::bucket_info::BucketInfoDTO;
+    use crate::api::time_range::time_unit::TimeUnit;
+
+    #[test]
+    fn endec_bucket_info() {
+        let bucket_info = BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, Some("Europe/Kyiv"));
+        assert_eq!(bucket_info, BucketInfoDTO::decode(&bucket_info.encode()));
+
+        let bucket_info = BucketInfoDTO::new(60, TimeUnit::Seconds, None);
+        assert_eq!(bucket_info, BucketInfoDTO::decode(&bucket_info.encode()));
+    }
+
+    #[test]
+    fn interval_naming_no_unit_is_read_as_milliseconds() {
+        let bucket_info = BucketInfoDTO::try_decode("v1::{bucket_interval: 60000, time_zone: null}".as_bytes());
+        assert_eq!(bucket_info, Ok(BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, None)));
+
+        let bucket_info = BucketInfoDTO::try_decode(r#"v2::{bucket_interval: 60, unit: "seconds"}"#.as_bytes());
+        assert_eq!(bucket_info.as_ref().map(BucketInfoDTO::get_unit), Ok(TimeUnit::Seconds));
+    }
+
+    #[test]
+    fn invalid_bucket_info_is_not_decoded() {
+        assert_eq!(BucketInfoDTO::new(0, TimeUnit::Milliseconds, None).validate(), Err(BucketError::NonPositiveInterval(0)));
+
+        let error = BucketInfoDTO::try_decode(&BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, Some("Europe Kyiv")).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(BucketError::InvalidTimeZone("Europe Kyiv".into()).to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+use crate::api::time_range::time_unit::TimeUnit;
+
+
+use super::bucket_error::BucketError;
+
+
+const DATA_TYPE: &str = "bucket_interval";
+const VERSION: u32 = 2;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The unit of intervals written before they named one, which every bucketed chart request was expressed in.
+pub(crate) const LEGACY_UNIT: TimeUnit = TimeUnit::Milliseconds;
+
+/// Intervals an automatic bucket interval is rounded up to, so that buckets start at round times.
+const AUTO_INTERVALS: &[Duration] = &[
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(15),
+    Duration::from_secs(30),
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(10 * 60),
+    Duration::from_secs(15 * 60),
+    Duration::from_secs(30 * 60),
+    Duration::from_secs(3_600),
+    Duration::from_secs(3 * 3_600),
+    Duration::from_secs(6 * 3_600),
+    Duration::from_secs(12 * 3_600),
+    Duration::from_secs(SECONDS_PER_DAY),
+    Duration::from_secs(7 * SECONDS_PER_DAY),
+];
+
+/// How wide the buckets of a time-series chart are: either a fixed `interval` of `unit`, whatever the time unit of the request,
+/// or automatic, the reporter picking a round interval yielding about `target_point_count` buckets.
+/// A fixed interval naming no unit, as written before version 2, is read as milliseconds.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = BucketIntervalDTO::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BucketIntervalDTO {
+    interval: Option<i64>,
+    #[ion(present_with = interval, as_string, default = LEGACY_UNIT)]
+    unit: TimeUnit,
+    target_point_count: Option<i64>,
+}
+impl API for BucketIntervalDTO { }
+
+impl BucketIntervalDTO {
+    pub fn fixed(interval: i64, unit: TimeUnit) -> Self {
+        BucketIntervalDTO {
+            interval: Some(interval),
+            unit,
+            target_point_count: None,
+        }
+    }
+
+    pub fn auto(target_point_count: i64) -> Self {
+        BucketIntervalDTO {
+            interval: None,
+            unit: LEGACY_UNIT,
+            target_point_count: Some(target_point_count),
+        }
+    }
+
+    pub fn get_interval(&self) -> Option<i64> {
+        self.interval
+    }
+
+    /// The unit of the fixed interval, meaningless for an automatic one.
+    pub fn get_unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    pub fn get_target_point_count(&self) -> Option<i64> {
+        self.target_point_count
+    }
+
+    pub fn is_auto(&self) -> bool {
+        self.interval.is_none()
+    }
+
+    /// The interval of the buckets of a range spanning `span`, both expressed in `time_unit`,
+    /// a fixed interval being converted from its own unit and rounded down to a whole unit.
+    /// An automatic interval is the smallest round interval, from a second to a week, then whole days,
+    /// splitting the range into `target_point_count` buckets at most.
+    pub fn resolve(&self, span: u64, time_unit: TimeUnit) -> i64 {
+        let interval = match self.interval {
+            Some(interval) => self.unit.to_duration(interval.unsigned_abs()),
+            None => {
+                let target_point_count = self.target_point_count.unwrap_or(1).max(1).unsigned_abs();
+                let ideal_interval = time_unit.to_duration(span.div_ceil(target_point_count).max(1));
+                AUTO_INTERVALS.iter()
+                    .copied()
+                    .find(|interval| *interval >= ideal_interval)
+                    .unwrap_or_else(|| {
+                        let seconds = ideal_interval.as_secs() + u64::from(ideal_interval.subsec_nanos() > 0);
+                        Duration::from_secs(seconds.div_ceil(SECONDS_PER_DAY) * SECONDS_PER_DAY)
+                    })
+            },
+        };
+
+        i64::try_from(time_unit.to_amount(interval).max(1)).unwrap_or(i64::MAX)
+    }
+
+    /// Checks the interval is either fixed or automatic and positive. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), BucketError> {
+        match (self.interval, self.target_point_count) {
+            (Some(interval), None) if interval <= 0 => Err(BucketError::NonPositiveInterval(interval)),
+            (None, Some(target_point_count)) if target_point_count <= 0 => {
+                Err(BucketError::NonPositiveTargetPointCount(target_point_count))
+            },
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(BucketError::AmbiguousInterval),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::bucketing::bucket_error::BucketError;
+    use crate::api::bucketing::bucket_interval::BucketIntervalDTO;
+    use crate::api::time_range::time_unit::TimeUnit;
+
+
+    const MINUTE: u64 = 60_000;
+    const DAY: u64 = 24 * 60 * MINUTE;
+
+    #[test]
+    fn endec_bucket_interval() {
+        let interval = BucketIntervalDTO::fixed(60, TimeUnit::Seconds);
+        assert_eq!(interval, BucketIntervalDTO::decode(&interval.encode()));
+
+        let interval = BucketIntervalDTO::auto(100);
+        assert_eq!(interval, BucketIntervalDTO::decode(&interval.encode()));
+    }
+
+    #[test]
+    fn fixed_interval_is_kept() {
+        assert_eq!(BucketIntervalDTO::fixed(42_000, TimeUnit::Milliseconds).resolve(DAY, TimeUnit::Milliseconds), 42_000);
+    }
+
+    #[test]
+    fn fixed_interval_is_converted_from_its_unit() {
+        assert_eq!(BucketIntervalDTO::fixed(42, TimeUnit::Seconds).resolve(DAY, TimeUnit::Milliseconds), 42_000);
+        assert_eq!(BucketIntervalDTO::fixed(1_500, TimeUnit::Microseconds).resolve(DAY, TimeUnit::Milliseconds), 1);
+        assert_eq!(BucketIntervalDTO::fixed(1, TimeUnit::Nanoseconds).resolve(DAY, TimeUnit::Milliseconds), 1);
+    }
+
+    #[test]
+    fn fixed_interval_naming_no_unit_is_read_as_milliseconds() {
+        let interval = BucketIntervalDTO::try_decode("v1::{interval: 60000, target_point_count: null}".as_bytes()).unwrap();
+        assert_eq!(interval, BucketIntervalDTO::fixed(60_000, TimeUnit::Milliseconds));
+
+        let interval = BucketIntervalDTO::try_decode(r#"v2::{interval: 60, unit: "seconds"}"#.as_bytes()).unwrap();
+        assert_eq!(interval.resolve(DAY, TimeUnit::Milliseconds), 60_000);
+    }
+
+    #[test]
+    fn auto_interval_is_rounded_up() {
+        assert_eq!(BucketIntervalDTO::auto(100).resolve(10 * MINUTE, TimeUnit::Milliseconds), 10_000);
+        assert_eq!(BucketIntervalDTO::auto(100).resolve(60 * MINUTE, TimeUnit::Milliseconds), 60_000);
+        assert_eq!(BucketIntervalDTO::auto(7).resolve(DAY, TimeUnit::Milliseconds), 6 * 60 * MINUTE as i64);
+        assert_eq!(BucketIntervalDTO::auto(1000).resolve(MINUTE, TimeUnit::Milliseconds), 1_000);
+        assert_eq!(BucketIntervalDTO::auto(1).resolve(10 * DAY + 1, TimeUnit::Milliseconds), 11 * DAY as i64);
+        assert_eq!(BucketIntervalDTO::auto(100).resolve(3_600, TimeUnit::Seconds), 60);
+    }
+
+    #[test]
+    fn interval_is_either_fixed_or_auto() {
+        assert_eq!(BucketIntervalDTO::fixed(0, TimeUnit::Seconds).validate(), Err(BucketError::NonPositiveInterval(0)));
+        assert_eq!(BucketIntervalDTO::auto(-1).validate(), Err(BucketError::NonPositiveTargetPointCount(-1)));
+
+        let error = BucketIntervalDTO::try_decode("v1::{interval: 1000, target_point_count: 100}".as_bytes()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(BucketError::AmbiguousInterval.to_string()));
+
+        let error = BucketIntervalDTO::try_decode("v1::{}".as_bytes()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(BucketError::AmbiguousInterval.to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod bucket_error;
+pub mod bucket_info;
+pub mod bucket_interval;
+pub mod time_zone;
\ No newline at end of file
@@ -0,0 +1,61 @@
+use super::bucket_error::BucketError;
+
+
+const MAX_OFFSET_HOURS: u32 = 14;
+
+/// Checks `time_zone` is `UTC`, an offset from it such as `+02:00`, or looks like a time zone database name
+/// such as `Europe/Kyiv` or `Etc/GMT+3`. Whether a name is actually known is up to the reporter.
+pub fn validate_time_zone(time_zone: &str) -> Result<(), BucketError> {
+    let is_valid = match time_zone.strip_prefix(['+', '-']) {
+        Some(offset) => is_offset(offset),
+        None => is_name(time_zone),
+    };
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(BucketError::InvalidTimeZone(time_zone.into())),
+    }
+}
+
+fn is_offset(offset: &str) -> bool {
+    let Some((hours, minutes)) = offset.split_once(':') else {
+        return false;
+    };
+    let is_two_digits = |part: &str| part.len() == 2 && part.bytes().all(|byte| byte.is_ascii_digit());
+    if !is_two_digits(hours) || !is_two_digits(minutes) {
+        return false;
+    }
+
+    match (hours.parse::<u32>(), minutes.parse::<u32>()) {
+        (Ok(hours), Ok(minutes)) => (hours < MAX_OFFSET_HOURS && minutes < 60) || (hours == MAX_OFFSET_HOURS && minutes == 0),
+        _ => false,
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    name.split('/').all(|part| {
+        part.starts_with(|first: char| first.is_ascii_alphabetic())
+            && part.chars().all(|character| character.is_ascii_alphanumeric() || "_-+".contains(character))
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bucketing::bucket_error::BucketError;
+    use crate::api::bucketing::time_zone::validate_time_zone;
+
+    #[test]
+    fn time_zones_are_names_or_offsets() {
+        for time_zone in ["UTC", "Europe/Kyiv", "America/Argentina/Buenos_Aires", "Etc/GMT+3", "+02:00", "-09:30", "+14:00"] {
+            assert_eq!(validate_time_zone(time_zone), Ok(()), "{time_zone}");
+        }
+    }
+
+    #[test]
+    fn malformed_time_zones_are_invalid() {
+        for time_zone in ["", "Europe/", "/Kyiv", "Europe Kyiv", "+2:00", "+0200", "+14:30", "-15:00", "+02:60"] {
+            assert_eq!(validate_time_zone(time_zone), Err(BucketError::InvalidTimeZone(time_zone.into())), "{time_zone}");
+        }
+    }
+}
\ No newline at end of file
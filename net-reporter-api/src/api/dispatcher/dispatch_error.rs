@@ -0,0 +1,32 @@
+use std::error::Error;
+use std::fmt;
+
+use net_codec_api::core::decode_error::DecodeError;
+
+
+/// Describes why an envelope could not be dispatched.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DispatchError {
+    /// No DTO is registered under the data type the envelope is tagged with.
+    UnknownType(String),
+    /// The payload could not be decoded as the DTO registered under its data type.
+    Decode(String, DecodeError),
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchError::UnknownType(data_type) => write!(f, "no DTO is registered under the data type `{data_type}`"),
+            DispatchError::Decode(data_type, error) => write!(f, "failed to decode a `{data_type}` payload: {error}"),
+        }
+    }
+}
+
+impl Error for DispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DispatchError::UnknownType(_) => None,
+            DispatchError::Decode(_, error) => Some(error),
+        }
+    }
+}
\ No newline at end of file
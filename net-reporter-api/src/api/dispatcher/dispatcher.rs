@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::aliased_api::Aliased;
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::try_decoder_api::TryDecoder;
+
+use crate::api::envelope::typed_envelope::TypedEnvelopeDTO;
+
+use super::dispatch_error::DispatchError;
+
+
+type DecodeFn<R> = fn(&[u8]) -> Result<R, DecodeError>;
+
+/// Decodes payloads into `R` according to the data type they are tagged with.
+/// Every DTO registered with the dispatcher has to be convertible into `R`, usually an enum wrapping all of them.
+pub struct Dispatcher<R> {
+    decoders: HashMap<&'static str, DecodeFn<R>>,
+}
+
+impl<R> Default for Dispatcher<R> {
+    fn default() -> Self {
+        Dispatcher::new()
+    }
+}
+
+impl<R> Dispatcher<R> {
+    pub fn new() -> Self {
+        Dispatcher {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers `T` under its data type and its aliases, so payloads of older producers are dispatched too.
+    ///
+    /// Panics if another DTO is already registered under one of them.
+    pub fn register<T>(mut self) -> Self
+    where T: TryDecoder + Typed + Aliased + Into<R> {
+        for data_type in [T::get_data_type()].iter().chain(T::get_data_type_aliases()) {
+            let previous = self.decoders.insert(data_type, decode_into::<T, R>);
+            assert!(previous.is_none(), "a DTO is already registered under the data type `{data_type}`");
+        }
+        self
+    }
+
+    pub fn is_registered(&self, data_type: &str) -> bool {
+        self.decoders.contains_key(data_type)
+    }
+
+    /// Decodes `payload` as the DTO registered under `data_type`.
+    pub fn decode(&self, data_type: &str, payload: &[u8]) -> Result<R, DispatchError> {
+        let decode = self.decoders.get(data_type)
+            .ok_or_else(|| DispatchError::UnknownType(data_type.into()))?;
+        decode(payload).map_err(|error| DispatchError::Decode(data_type.into(), error))
+    }
+
+    pub fn dispatch(&self, envelope: &TypedEnvelopeDTO) -> Result<R, DispatchError> {
+        self.decode(envelope.get_payload_type(), envelope.get_payload())
+    }
+}
+
+fn decode_into<T, R>(payload: &[u8]) -> Result<R, DecodeError>
+where T: TryDecoder + Into<R> {
+    T::try_decode(payload).map(Into::into)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+
+    use crate::api::dispatcher::dispatch_error::DispatchError;
+    use crate::api::dispatcher::dispatcher::Dispatcher;
+    use crate::api::envelope::typed_envelope::TypedEnvelopeDTO;
+    use crate::api::network_graph::graph_edge::GraphEdgeDTO;
+    use crate::api::network_graph::graph_node::GraphNodeDTO;
+
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum GraphElement {
+        Node(GraphNodeDTO),
+        Edge(GraphEdgeDTO),
+    }
+
+    impl From<GraphNodeDTO> for GraphElement {
+        fn from(node: GraphNodeDTO) -> Self {
+            GraphElement::Node(node)
+        }
+    }
+
+    impl From<GraphEdgeDTO> for GraphElement {
+        fn from(edge: GraphEdgeDTO) -> Self {
+            GraphElement::Edge(edge)
+        }
+    }
+
+    fn get_dispatcher() -> Dispatcher<GraphElement> {
+        Dispatcher::new()
+            .register::<GraphNodeDTO>()
+            .register::<GraphEdgeDTO>()
+    }
+
+    #[test]
+    fn dispatches_on_the_payload_type() {
+        let dispatcher = get_dispatcher();
+
+        let node = GraphNodeDTO::new("0.0.0.0:0000");
+        let envelope = TypedEnvelopeDTO::wrap(&node, None, None);
+        assert_eq!(dispatcher.dispatch(&envelope), Ok(GraphElement::Node(node)));
+
+        let edge = GraphEdgeDTO::new("0.0.0.0:0000", "0.0.0.0:5656", 123);
+        let envelope = TypedEnvelopeDTO::wrap(&edge, None, None);
+        assert_eq!(dispatcher.dispatch(&envelope), Ok(GraphElement::Edge(edge)));
+    }
+
+    #[test]
+    fn unknown_type_is_reported() {
+        let envelope = TypedEnvelopeDTO::new("graph_loop", &[], None, None);
+        assert_eq!(get_dispatcher().dispatch(&envelope), Err(DispatchError::UnknownType("graph_loop".into())));
+        assert!(!get_dispatcher().is_registered("graph_loop"));
+    }
+
+    #[test]
+    fn undecodable_payload_is_reported() {
+        let node = GraphNodeDTO::new("0.0.0.0:0000");
+        let envelope = TypedEnvelopeDTO::wrap(&node, None, None);
+        let truncated_payload = &envelope.get_payload()[..envelope.get_payload().len() - 2];
+
+        match get_dispatcher().decode(GraphNodeDTO::get_data_type(), truncated_payload) {
+            Err(DispatchError::Decode(data_type, error)) => {
+                assert_eq!(data_type, GraphNodeDTO::get_data_type());
+                assert!(matches!(error.get_kind(), DecodeErrorKind::Malformed(_)));
+            },
+            result => panic!("expected a decode error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered")]
+    fn registering_a_data_type_twice_panics() {
+        get_dispatcher().register::<GraphNodeDTO>();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod dispatch_error;
+#[allow(clippy::module_inception)]
+pub mod dispatcher;
+pub mod reporter_request;
\ No newline at end of file
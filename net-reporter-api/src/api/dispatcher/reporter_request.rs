@@ -0,0 +1,186 @@
+use crate::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+use crate::api::http_overview_dashboard_filters::http_overview_dashboard_filters_request::HttpOverviewDashboardFiltersRequestDTO;
+use crate::api::http_request_methods_distribution::http_request_methods_distribution_request::HttpRequestMethodsDistributionRequestDTO;
+use crate::api::http_responses::http_responses_request::HttpResponsesRequestDTO;
+use crate::api::http_responses_distribution::http_responses_distribution_request::HttpResponsesDistributionRequestDTO;
+use crate::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+use crate::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
+use crate::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_request::NetworkBandwidthPerProtocolRequestDTO;
+use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+use crate::api::network_overview_dashboard_filters::network_overview_dashboard_filters_request::NetworkOverviewDashboardFiltersRequestDTO;
+use crate::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+
+use super::dispatcher::Dispatcher;
+
+
+/// Any of the requests the reporter serves.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReporterRequest {
+    HttpClients(HttpClientsRequestDTO),
+    HttpOverviewDashboardFilters(HttpOverviewDashboardFiltersRequestDTO),
+    HttpRequestMethodsDistribution(HttpRequestMethodsDistributionRequestDTO),
+    HttpResponses(HttpResponsesRequestDTO),
+    HttpResponsesDistribution(HttpResponsesDistributionRequestDTO),
+    NetworkBandwidth(NetworkBandwidthRequestDTO),
+    NetworkBandwidthPerEndpoint(NetworkBandwidthPerEndpointRequestDTO),
+    NetworkBandwidthPerProtocol(NetworkBandwidthPerProtocolRequestDTO),
+    NetworkGraph(NetworkGraphRequestDTO),
+    NetworkOverviewDashboardFilters(NetworkOverviewDashboardFiltersRequestDTO),
+    TotalHttpRequests(RequestTotalHttpRequestsDTO),
+}
+
+impl ReporterRequest {
+    /// A dispatcher decoding every request the reporter serves, keyed on its data type.
+    pub fn dispatcher() -> Dispatcher<ReporterRequest> {
+        Dispatcher::new()
+            .register::<HttpClientsRequestDTO>()
+            .register::<HttpOverviewDashboardFiltersRequestDTO>()
+            .register::<HttpRequestMethodsDistributionRequestDTO>()
+            .register::<HttpResponsesRequestDTO>()
+            .register::<HttpResponsesDistributionRequestDTO>()
+            .register::<NetworkBandwidthRequestDTO>()
+            .register::<NetworkBandwidthPerEndpointRequestDTO>()
+            .register::<NetworkBandwidthPerProtocolRequestDTO>()
+            .register::<NetworkGraphRequestDTO>()
+            .register::<NetworkOverviewDashboardFiltersRequestDTO>()
+            .register::<RequestTotalHttpRequestsDTO>()
+    }
+}
+
+impl From<HttpClientsRequestDTO> for ReporterRequest {
+    fn from(request: HttpClientsRequestDTO) -> Self {
+        ReporterRequest::HttpClients(request)
+    }
+}
+
+impl From<HttpOverviewDashboardFiltersRequestDTO> for ReporterRequest {
+    fn from(request: HttpOverviewDashboardFiltersRequestDTO) -> Self {
+        ReporterRequest::HttpOverviewDashboardFilters(request)
+    }
+}
+
+impl From<HttpRequestMethodsDistributionRequestDTO> for ReporterRequest {
+    fn from(request: HttpRequestMethodsDistributionRequestDTO) -> Self {
+        ReporterRequest::HttpRequestMethodsDistribution(request)
+    }
+}
+
+impl From<HttpResponsesRequestDTO> for ReporterRequest {
+    fn from(request: HttpResponsesRequestDTO) -> Self {
+        ReporterRequest::HttpResponses(request)
+    }
+}
+
+impl From<HttpResponsesDistributionRequestDTO> for ReporterRequest {
+    fn from(request: HttpResponsesDistributionRequestDTO) -> Self {
+        ReporterRequest::HttpResponsesDistribution(request)
+    }
+}
+
+impl From<NetworkBandwidthRequestDTO> for ReporterRequest {
+    fn from(request: NetworkBandwidthRequestDTO) -> Self {
+        ReporterRequest::NetworkBandwidth(request)
+    }
+}
+
+impl From<NetworkBandwidthPerEndpointRequestDTO> for ReporterRequest {
+    fn from(request: NetworkBandwidthPerEndpointRequestDTO) -> Self {
+        ReporterRequest::NetworkBandwidthPerEndpoint(request)
+    }
+}
+
+impl From<NetworkBandwidthPerProtocolRequestDTO> for ReporterRequest {
+    fn from(request: NetworkBandwidthPerProtocolRequestDTO) -> Self {
+        ReporterRequest::NetworkBandwidthPerProtocol(request)
+    }
+}
+
+impl From<NetworkGraphRequestDTO> for ReporterRequest {
+    fn from(request: NetworkGraphRequestDTO) -> Self {
+        ReporterRequest::NetworkGraph(request)
+    }
+}
+
+impl From<NetworkOverviewDashboardFiltersRequestDTO> for ReporterRequest {
+    fn from(request: NetworkOverviewDashboardFiltersRequestDTO) -> Self {
+        ReporterRequest::NetworkOverviewDashboardFilters(request)
+    }
+}
+
+impl From<RequestTotalHttpRequestsDTO> for ReporterRequest {
+    fn from(request: RequestTotalHttpRequestsDTO) -> Self {
+        ReporterRequest::TotalHttpRequests(request)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::dispatcher::dispatch_error::DispatchError;
+    use crate::api::dispatcher::reporter_request::ReporterRequest;
+    use crate::api::envelope::typed_envelope::TypedEnvelopeDTO;
+    use crate::api::http_request_methods_distribution::http_request_methods_distribution_filters::HttpRequestMethodsDisributionFiltersDTO;
+    use crate::api::http_request_methods_distribution::http_request_methods_distribution_request::HttpRequestMethodsDistributionRequestDTO;
+    use crate::api::http_clients::http_clients_filters::HttpClientsFiltersDTO;
+    use crate::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+    use crate::api::network_graph::graph_node::GraphNodeDTO;
+    use crate::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+    use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+    use crate::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+    use crate::api::total_http_requests::total_http_requests_filters::TotalHttpRequestsFiltersDTO;
+
+
+    const START_DATE_TIME: Timestamp = Timestamp::from_nanos(1_700_000_000_000_000_000);
+    const END_DATE_TIME: Timestamp = Timestamp::from_nanos(1_700_000_600_000_000_000);
+    const CORRELATION_ID: &str = "9b2e4c1a";
+    const TENANT_ID: &str = "tenant-1";
+
+    #[test]
+    fn dispatches_requests_to_their_variant() {
+        let dispatcher = ReporterRequest::dispatcher();
+
+        let request = NetworkGraphRequestDTO::new(
+            START_DATE_TIME,
+            END_DATE_TIME,
+            NetworkGraphFiltersDTO::new(&["TCP".to_string()], Some(true), &[], None, None, Some(1000)),
+        );
+        let envelope = TypedEnvelopeDTO::wrap(&request, Some(CORRELATION_ID), Some(TENANT_ID));
+        assert_eq!(dispatcher.dispatch(&envelope), Ok(ReporterRequest::NetworkGraph(request)));
+
+        let request = HttpClientsRequestDTO::new(START_DATE_TIME, END_DATE_TIME, HttpClientsFiltersDTO::new(&[], None, &[], None, None, None));
+        let envelope = TypedEnvelopeDTO::wrap(&request, Some(CORRELATION_ID), None);
+        assert_eq!(dispatcher.dispatch(&envelope), Ok(ReporterRequest::HttpClients(request)));
+
+        let request = RequestTotalHttpRequestsDTO::new(START_DATE_TIME, END_DATE_TIME, TotalHttpRequestsFiltersDTO::new(&[], None, &[], None, None, None));
+        let envelope = TypedEnvelopeDTO::wrap(&request, None, None);
+        assert_eq!(dispatcher.dispatch(&envelope), Ok(ReporterRequest::TotalHttpRequests(request)));
+    }
+
+    #[test]
+    fn legacy_data_types_are_dispatched() {
+        let request = HttpRequestMethodsDistributionRequestDTO::new(
+            START_DATE_TIME,
+            END_DATE_TIME,
+            HttpRequestMethodsDisributionFiltersDTO::new(&[], None, None, None),
+        );
+        let envelope = TypedEnvelopeDTO::new("http_request_methods_disribution_request", &request.encode(), None, None);
+        assert_eq!(
+            ReporterRequest::dispatcher().dispatch(&envelope),
+            Ok(ReporterRequest::HttpRequestMethodsDistribution(request)),
+        );
+    }
+
+    #[test]
+    fn responses_are_not_dispatched() {
+        let envelope = TypedEnvelopeDTO::wrap(&GraphNodeDTO::new("0.0.0.0:0000"), None, None);
+        assert_eq!(
+            ReporterRequest::dispatcher().dispatch(&envelope),
+            Err(DispatchError::UnknownType(GraphNodeDTO::get_data_type().into())),
+        );
+    }
+}
\ No newline at end of file
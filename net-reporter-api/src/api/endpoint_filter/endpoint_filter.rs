@@ -0,0 +1,81 @@
+use std::net::IpAddr;
+
+use super::endpoint_pattern::EndpointPattern;
+use super::endpoint_pattern_error::EndpointPatternError;
+
+
+/// The endpoints of a filters DTO along with their include mode, ready to be matched against the captured traffic.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EndpointFilter {
+    patterns: Vec<EndpointPattern>,
+    include_mode: Option<bool>,
+}
+
+impl EndpointFilter {
+    pub fn new(endpoints: &[String], include_mode: Option<bool>) -> Result<Self, EndpointPatternError> {
+        Ok(EndpointFilter {
+            patterns: endpoints.iter().map(|endpoint| endpoint.parse()).collect::<Result<_, _>>()?,
+            include_mode,
+        })
+    }
+
+    pub fn get_patterns(&self) -> &[EndpointPattern] {
+        self.patterns.as_slice()
+    }
+
+    /// Whether `endpoint` passes the filter: it has to match one of the patterns in include mode, none in exclude mode,
+    /// and any endpoint passes when the mode is unset.
+    pub fn matches(&self, endpoint: &str) -> bool {
+        self.passes(|pattern| pattern.matches(endpoint))
+    }
+
+    pub fn matches_address(&self, address: IpAddr, port: Option<u16>) -> bool {
+        self.passes(|pattern| pattern.matches_address(address, port))
+    }
+
+    fn passes(&self, matches: impl Fn(&EndpointPattern) -> bool) -> bool {
+        match self.include_mode {
+            Some(include_mode) => self.patterns.iter().any(matches) == include_mode,
+            None => true,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::endpoint_filter::endpoint_filter::EndpointFilter;
+    use crate::api::endpoint_filter::endpoint_pattern_error::EndpointPatternError;
+
+    fn get_endpoints() -> Vec<String> {
+        vec!["10.0.0.0/8".to_string(), "192.168.1.1:443".to_string()]
+    }
+
+    #[test]
+    fn include_mode_keeps_matching_endpoints() {
+        let filter = EndpointFilter::new(&get_endpoints(), Some(true)).unwrap();
+        assert!(filter.matches("10.0.0.1:5656"));
+        assert!(filter.matches("192.168.1.1:443"));
+        assert!(!filter.matches("192.168.1.1:80"));
+    }
+
+    #[test]
+    fn exclude_mode_drops_matching_endpoints() {
+        let filter = EndpointFilter::new(&get_endpoints(), Some(false)).unwrap();
+        assert!(!filter.matches("10.0.0.1:5656"));
+        assert!(filter.matches("192.168.1.1:80"));
+    }
+
+    #[test]
+    fn unset_mode_keeps_every_endpoint() {
+        let filter = EndpointFilter::new(&get_endpoints(), None).unwrap();
+        assert!(filter.matches("10.0.0.1"));
+        assert!(filter.matches("172.16.0.1"));
+    }
+
+    #[test]
+    fn invalid_endpoints_are_reported() {
+        let endpoints = vec!["10.0.0.0/8".to_string(), "10.0.0.0/64".to_string()];
+        assert_eq!(EndpointFilter::new(&endpoints, Some(true)), Err(EndpointPatternError::InvalidPrefixLength("64".into())));
+    }
+}
\ No newline at end of file
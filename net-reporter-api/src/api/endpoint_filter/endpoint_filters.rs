@@ -0,0 +1,20 @@
+use super::endpoint_filter::EndpointFilter;
+use super::endpoint_pattern_error::EndpointPatternError;
+
+
+/// Filters DTOs whose endpoints are patterns, see [`EndpointPattern`](super::endpoint_pattern::EndpointPattern).
+pub trait EndpointFilters {
+    fn get_endpoints(&self) -> &[String];
+
+    fn is_include_endpoints_mode(&self) -> Option<bool>;
+
+    /// Parses the endpoints along with their include mode.
+    fn get_endpoint_filter(&self) -> Result<EndpointFilter, EndpointPatternError> {
+        EndpointFilter::new(self.get_endpoints(), self.is_include_endpoints_mode())
+    }
+
+    /// Checks every endpoint is a valid pattern. Decoding runs it as well.
+    fn validate_endpoints(&self) -> Result<(), EndpointPatternError> {
+        self.get_endpoint_filter().map(|_| ())
+    }
+}
\ No newline at end of file
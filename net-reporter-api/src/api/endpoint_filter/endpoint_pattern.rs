@@ -0,0 +1,214 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use crate::api::filter::filter_expression::FilterExpressionDTO;
+use crate::api::filter::filter_field::FilterField;
+
+use super::endpoint_pattern_error::EndpointPatternError;
+use super::ip_network::IpNetwork;
+use super::port_range::PortRange;
+
+
+/// An endpoint of a filters DTO, matching the endpoints of the captured traffic.
+///
+/// It is written as an address or a CIDR range, `*` standing for any address, optionally followed by a port range:
+/// `10.0.0.1`, `10.0.0.0/8:8000-8080`, `*:443` or `[2001:db8::/32]:80`, IPv6 addresses being bracketed when a port range follows.
+/// Endpoints which are not written this way, e.g. host names, are matched as they are.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EndpointPattern {
+    /// Endpoints with an address within `network`, any when unset, and a port within `ports`, any when unset.
+    Address { network: Option<IpNetwork>, ports: Option<PortRange> },
+    Exact(String),
+}
+
+impl EndpointPattern {
+    /// Whether the pattern matches one endpoint only, as endpoints of filters DTOs used to.
+    pub fn is_single_endpoint(&self) -> bool {
+        match self {
+            EndpointPattern::Address { network: Some(network), ports: None } => network.is_host(),
+            EndpointPattern::Address { .. } => false,
+            EndpointPattern::Exact(_) => true,
+        }
+    }
+
+    /// Whether the pattern matches `endpoint`, written as an address optionally followed by a port, e.g. `10.0.0.1:443`.
+    pub fn matches(&self, endpoint: &str) -> bool {
+        match (self, parse_endpoint(endpoint)) {
+            (EndpointPattern::Exact(pattern), _) => pattern == endpoint,
+            (EndpointPattern::Address { .. }, Some((address, port))) => self.matches_address(address, port),
+            (EndpointPattern::Address { .. }, None) => false,
+        }
+    }
+
+    pub fn matches_address(&self, address: IpAddr, port: Option<u16>) -> bool {
+        match self {
+            EndpointPattern::Address { network, ports } => {
+                network.is_none_or(|network| network.contains(address))
+                    && ports.is_none_or(|ports| port.is_some_and(|port| ports.contains(port)))
+            },
+            EndpointPattern::Exact(pattern) => {
+                *pattern == address.to_string()
+                    || port.is_some_and(|port| *pattern == SocketAddr::new(address, port).to_string())
+            },
+        }
+    }
+}
+
+fn parse_endpoint(endpoint: &str) -> Option<(IpAddr, Option<u16>)> {
+    match endpoint.parse::<SocketAddr>() {
+        Ok(endpoint) => Some((endpoint.ip(), Some(endpoint.port()))),
+        Err(_) => endpoint.parse().ok().map(|address| (address, None)),
+    }
+}
+
+/// Splits a pattern into its address and port range parts.
+fn split_port_range(pattern: &str) -> (&str, Option<&str>) {
+    if let Some((address, rest)) = pattern.strip_prefix('[').and_then(|pattern| pattern.split_once(']')) {
+        return match rest.strip_prefix(':') {
+            Some(port_range) => (address, Some(port_range)),
+            None if rest.is_empty() => (address, None),
+            None => (pattern, None),
+        };
+    }
+    match pattern.rsplit_once(':') {
+        // Unbracketed IPv6 addresses have no port range
+        Some((address, port_range)) if !address.contains(':') => (address, Some(port_range)),
+        _ => (pattern, None),
+    }
+}
+
+impl FromStr for EndpointPattern {
+    type Err = EndpointPatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let (address, port_range) = split_port_range(pattern);
+        let network = match address {
+            "*" => None,
+            address => match address.parse() {
+                Ok(network) => Some(network),
+                Err(EndpointPatternError::InvalidAddress(_)) if !pattern.contains(['/', '[', '*']) => {
+                    return Ok(EndpointPattern::Exact(pattern.into()));
+                },
+                Err(error) => return Err(error),
+            },
+        };
+        let ports = port_range.map(str::parse).transpose()?;
+        Ok(EndpointPattern::Address { network, ports })
+    }
+}
+
+impl fmt::Display for EndpointPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndpointPattern::Address { network: None, ports: None } => write!(f, "*"),
+            EndpointPattern::Address { network: None, ports: Some(ports) } => write!(f, "*:{ports}"),
+            EndpointPattern::Address { network: Some(network), ports: None } => write!(f, "{network}"),
+            EndpointPattern::Address { network: Some(network), ports: Some(ports) } => match network.get_address() {
+                IpAddr::V4(_) => write!(f, "{network}:{ports}"),
+                IpAddr::V6(_) => write!(f, "[{network}]:{ports}"),
+            },
+            EndpointPattern::Exact(pattern) => write!(f, "{pattern}"),
+        }
+    }
+}
+
+impl From<&EndpointPattern> for FilterExpressionDTO {
+    fn from(pattern: &EndpointPattern) -> Self {
+        match pattern {
+            EndpointPattern::Address { network, ports } => FilterExpressionDTO::from_conditions([
+                network.map(|network| match network.is_host() {
+                    true => FilterExpressionDTO::equals(FilterField::Endpoint, network.to_string()),
+                    false => FilterExpressionDTO::cidr(FilterField::Endpoint, &network.to_string()),
+                }),
+                ports.map(|ports| FilterExpressionDTO::range(
+                    FilterField::Port,
+                    Some(ports.get_first().into()),
+                    Some(ports.get_last().into()),
+                )),
+            ]),
+            EndpointPattern::Exact(pattern) => FilterExpressionDTO::equals(FilterField::Endpoint, pattern.as_str()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::endpoint_filter::endpoint_pattern::EndpointPattern;
+    use crate::api::endpoint_filter::endpoint_pattern_error::EndpointPatternError;
+    use crate::api::endpoint_filter::ip_network::IpNetwork;
+    use crate::api::endpoint_filter::port_range::PortRange;
+    use crate::api::filter::filter_expression::FilterExpressionDTO;
+    use crate::api::filter::filter_field::FilterField;
+
+    fn pattern(pattern: &str) -> EndpointPattern {
+        pattern.parse().unwrap()
+    }
+
+    #[test]
+    fn patterns_are_parsed() {
+        assert_eq!(pattern("10.0.0.0/8:8000-8080"), EndpointPattern::Address {
+            network: Some("10.0.0.0/8".parse().unwrap()),
+            ports: Some(PortRange::new(8000, 8080).unwrap()),
+        });
+        assert_eq!(pattern("[2001:db8::/32]:80"), EndpointPattern::Address {
+            network: Some("2001:db8::/32".parse().unwrap()),
+            ports: Some(PortRange::new(80, 80).unwrap()),
+        });
+        assert_eq!(pattern("2001:db8::1"), EndpointPattern::Address {
+            network: Some("2001:db8::1".parse::<IpNetwork>().unwrap()),
+            ports: None,
+        });
+        assert_eq!(pattern("*:443"), EndpointPattern::Address { network: None, ports: Some(PortRange::new(443, 443).unwrap()) });
+        assert_eq!(pattern("example.com"), EndpointPattern::Exact("example.com".into()));
+    }
+
+    #[test]
+    fn invalid_patterns_are_reported() {
+        assert_eq!("10.0.0.0/40".parse::<EndpointPattern>(), Err(EndpointPatternError::InvalidPrefixLength("40".into())));
+        assert_eq!("10.0.0/8".parse::<EndpointPattern>(), Err(EndpointPatternError::InvalidAddress("10.0.0".into())));
+        assert_eq!("10.0.0.1:90-80".parse::<EndpointPattern>(), Err(EndpointPatternError::InvalidPortRange("90-80".into())));
+        assert_eq!("[::1]:http".parse::<EndpointPattern>(), Err(EndpointPatternError::InvalidPortRange("http".into())));
+    }
+
+    #[test]
+    fn patterns_match_endpoints() {
+        assert!(pattern("10.0.0.0/8").matches("10.1.2.3"));
+        assert!(pattern("10.0.0.0/8").matches("10.1.2.3:5656"));
+        assert!(!pattern("10.0.0.0/8").matches("192.168.0.1"));
+
+        assert!(pattern("10.0.0.0/8:8000-8080").matches("10.1.2.3:8080"));
+        assert!(!pattern("10.0.0.0/8:8000-8080").matches("10.1.2.3:8081"));
+        assert!(!pattern("10.0.0.0/8:8000-8080").matches("10.1.2.3"));
+
+        assert!(pattern("[2001:db8::/32]:80").matches("[2001:db8::1]:80"));
+        assert!(pattern("*:443").matches("[::1]:443"));
+        assert!(pattern("1.1.1.1").matches("1.1.1.1"));
+        assert!(pattern("example.com").matches("example.com"));
+        assert!(!pattern("example.com").matches("example.org"));
+    }
+
+    #[test]
+    fn patterns_are_displayed_as_parsed() {
+        for endpoint in ["10.0.0.0/8:8000-8080", "[2001:db8::/32]:80", "*", "*:443", "1.1.1.1", "example.com"] {
+            assert_eq!(pattern(endpoint).to_string(), endpoint);
+        }
+    }
+
+    #[test]
+    fn patterns_convert_into_filter_expressions() {
+        assert_eq!(
+            FilterExpressionDTO::from(&pattern("10.0.0.0/8:8000-8080")),
+            FilterExpressionDTO::and(vec![
+                FilterExpressionDTO::cidr(FilterField::Endpoint, "10.0.0.0/8"),
+                FilterExpressionDTO::range(FilterField::Port, Some(8000), Some(8080)),
+            ]),
+        );
+        assert_eq!(
+            FilterExpressionDTO::from(&pattern("1.1.1.1")),
+            FilterExpressionDTO::and(vec![FilterExpressionDTO::equals(FilterField::Endpoint, "1.1.1.1")]),
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why an endpoint of a filters DTO is not a valid pattern.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EndpointPatternError {
+    /// The address of a CIDR range is not an IP address.
+    InvalidAddress(String),
+    /// The prefix length is not a number or exceeds the length of the address.
+    InvalidPrefixLength(String),
+    /// The port range is not a port, nor two ports separated by `-` with the first one not above the last one.
+    InvalidPortRange(String),
+}
+
+impl fmt::Display for EndpointPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndpointPatternError::InvalidAddress(address) => write!(f, "`{address}` is not an IP address"),
+            EndpointPatternError::InvalidPrefixLength(prefix_length) => write!(f, "`{prefix_length}` is not a valid prefix length"),
+            EndpointPatternError::InvalidPortRange(port_range) => write!(f, "`{port_range}` is not a valid port range"),
+        }
+    }
+}
+
+impl Error for EndpointPatternError { }
\ No newline at end of file
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use super::endpoint_pattern_error::EndpointPatternError;
+
+
+/// The IPv4 or IPv6 addresses sharing the first `prefix_length` bits of `address`, written as `10.0.0.0/8`.
+/// An address without a prefix length stands for itself alone.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct IpNetwork {
+    address: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpNetwork {
+    pub fn new(address: IpAddr, prefix_length: u8) -> Result<Self, EndpointPatternError> {
+        match prefix_length <= get_address_length(address) {
+            true => Ok(IpNetwork { address, prefix_length }),
+            false => Err(EndpointPatternError::InvalidPrefixLength(prefix_length.to_string())),
+        }
+    }
+
+    pub fn get_address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub fn get_prefix_length(&self) -> u8 {
+        self.prefix_length
+    }
+
+    /// Whether the network is a single address.
+    pub fn is_host(&self) -> bool {
+        self.prefix_length == get_address_length(self.address)
+    }
+
+    /// Whether `address` belongs to the network. IPv4 addresses mapped to IPv6 are compared as IPv4 ones.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        match (self.address, address.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                shares_prefix(u32::from(network).into(), u32::from(address).into(), 32, self.prefix_length)
+            },
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                shares_prefix(network.into(), address.into(), 128, self.prefix_length)
+            },
+            _ => false,
+        }
+    }
+}
+
+fn get_address_length(address: IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn shares_prefix(network: u128, address: u128, address_length: u8, prefix_length: u8) -> bool {
+    let host_length = u32::from(address_length - prefix_length);
+    network.checked_shr(host_length).unwrap_or(0) == address.checked_shr(host_length).unwrap_or(0)
+}
+
+impl FromStr for IpNetwork {
+    type Err = EndpointPatternError;
+
+    fn from_str(network: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_length) = match network.split_once('/') {
+            Some((address, prefix_length)) => (address, Some(prefix_length)),
+            None => (network, None),
+        };
+        let address: IpAddr = address.parse()
+            .map_err(|_| EndpointPatternError::InvalidAddress(address.into()))?;
+        let prefix_length = match prefix_length {
+            Some(prefix_length) => prefix_length.parse()
+                .map_err(|_| EndpointPatternError::InvalidPrefixLength(prefix_length.into()))?,
+            None => get_address_length(address),
+        };
+        IpNetwork::new(address, prefix_length)
+    }
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.is_host() {
+            true => write!(f, "{}", self.address),
+            false => write!(f, "{}/{}", self.address, self.prefix_length),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::api::endpoint_filter::endpoint_pattern_error::EndpointPatternError;
+    use crate::api::endpoint_filter::ip_network::IpNetwork;
+
+    fn address(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn ipv4_network_contains_its_addresses() {
+        let network: IpNetwork = "10.0.0.0/8".parse().unwrap();
+        assert!(network.contains(address("10.0.0.1")));
+        assert!(network.contains(address("10.255.255.255")));
+        assert!(network.contains(address("::ffff:10.1.2.3")));
+        assert!(!network.contains(address("11.0.0.1")));
+        assert!(!network.contains(address("::1")));
+    }
+
+    #[test]
+    fn ipv6_network_contains_its_addresses() {
+        let network: IpNetwork = "2001:db8::/32".parse().unwrap();
+        assert!(network.contains(address("2001:db8::1")));
+        assert!(network.contains(address("2001:db8:ffff::1")));
+        assert!(!network.contains(address("2001:db9::1")));
+        assert!(!network.contains(address("10.0.0.1")));
+    }
+
+    #[test]
+    fn network_bounds_are_handled() {
+        let everything: IpNetwork = "0.0.0.0/0".parse().unwrap();
+        assert!(everything.contains(address("255.255.255.255")));
+
+        let host: IpNetwork = "192.168.1.1".parse().unwrap();
+        assert!(host.is_host());
+        assert!(host.contains(address("192.168.1.1")));
+        assert!(!host.contains(address("192.168.1.2")));
+    }
+
+    #[test]
+    fn invalid_networks_are_reported() {
+        assert_eq!("10.0.0/8".parse::<IpNetwork>(), Err(EndpointPatternError::InvalidAddress("10.0.0".into())));
+        assert_eq!("10.0.0.0/33".parse::<IpNetwork>(), Err(EndpointPatternError::InvalidPrefixLength("33".into())));
+        assert_eq!("10.0.0.0/x".parse::<IpNetwork>(), Err(EndpointPatternError::InvalidPrefixLength("x".into())));
+    }
+
+    #[test]
+    fn networks_are_displayed_as_parsed() {
+        for network in ["10.0.0.0/8", "192.168.1.1", "2001:db8::/32", "::1"] {
+            assert_eq!(network.parse::<IpNetwork>().unwrap().to_string(), network);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+pub mod endpoint_filter;
+pub mod endpoint_filters;
+pub mod endpoint_pattern;
+pub mod endpoint_pattern_error;
+pub mod ip_network;
+pub mod port_range;
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::endpoint_pattern_error::EndpointPatternError;
+
+
+/// Ports from `first` to `last`, both included, written as `80` or `8000-8080`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PortRange {
+    first: u16,
+    last: u16,
+}
+
+impl PortRange {
+    pub fn new(first: u16, last: u16) -> Result<Self, EndpointPatternError> {
+        match first <= last {
+            true => Ok(PortRange { first, last }),
+            false => Err(EndpointPatternError::InvalidPortRange(format!("{first}-{last}"))),
+        }
+    }
+
+    pub fn get_first(&self) -> u16 {
+        self.first
+    }
+
+    pub fn get_last(&self) -> u16 {
+        self.last
+    }
+
+    pub fn contains(&self, port: u16) -> bool {
+        (self.first..=self.last).contains(&port)
+    }
+}
+
+impl FromStr for PortRange {
+    type Err = EndpointPatternError;
+
+    fn from_str(port_range: &str) -> Result<Self, Self::Err> {
+        let invalid = || EndpointPatternError::InvalidPortRange(port_range.into());
+        let (first, last) = port_range.split_once('-').unwrap_or((port_range, port_range));
+        let first = first.parse().map_err(|_| invalid())?;
+        let last = last.parse().map_err(|_| invalid())?;
+        PortRange::new(first, last).map_err(|_| invalid())
+    }
+}
+
+impl fmt::Display for PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.first == self.last {
+            true => write!(f, "{}", self.first),
+            false => write!(f, "{}-{}", self.first, self.last),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::endpoint_filter::endpoint_pattern_error::EndpointPatternError;
+    use crate::api::endpoint_filter::port_range::PortRange;
+
+    #[test]
+    fn port_ranges_are_parsed() {
+        assert_eq!("80".parse(), PortRange::new(80, 80));
+        assert_eq!("8000-8080".parse(), PortRange::new(8000, 8080));
+
+        for port_range in ["", "http", "80-", "8080-8000", "65536"] {
+            assert_eq!(port_range.parse::<PortRange>(), Err(EndpointPatternError::InvalidPortRange(port_range.into())));
+        }
+    }
+
+    #[test]
+    fn port_range_includes_both_ends() {
+        let port_range = PortRange::new(8000, 8080).unwrap();
+        assert!(port_range.contains(8000));
+        assert!(port_range.contains(8080));
+        assert!(!port_range.contains(7999));
+        assert!(!port_range.contains(8081));
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod typed_envelope;
\ No newline at end of file
@@ -0,0 +1,132 @@
+use net_core_api::core::api::API;
+use net_core_api::core::encoder_api::Encoder;
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_derive::IonDto;
+
+
+const DATA_TYPE: &str = "typed_envelope";
+const VERSION: u32 = 1;
+
+/// Carries an encoded DTO along with its data type, so the receiver knows which DTO to decode it as.
+/// The correlation id ties a response to its request, the tenant id tells which tenant the DTO belongs to.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypedEnvelopeDTO {
+    payload_type: String,
+    payload: Vec<u8>,
+    correlation_id: Option<String>,
+    tenant_id: Option<String>,
+}
+impl API for TypedEnvelopeDTO { }
+
+impl TypedEnvelopeDTO {
+    pub fn new(payload_type: &str, payload: &[u8], correlation_id: Option<&str>, tenant_id: Option<&str>) -> Self {
+        TypedEnvelopeDTO {
+            payload_type: payload_type.into(),
+            payload: payload.into(),
+            correlation_id: correlation_id.map(|correlation_id| correlation_id.into()),
+            tenant_id: tenant_id.map(|tenant_id| tenant_id.into()),
+        }
+    }
+
+    /// Encodes `dto` and tags it with its data type.
+    pub fn wrap<T: Encoder + Typed>(dto: &T, correlation_id: Option<&str>, tenant_id: Option<&str>) -> Self {
+        TypedEnvelopeDTO::new(T::get_data_type(), &dto.encode(), correlation_id, tenant_id)
+    }
+
+    pub fn get_payload_type(&self) -> &str {
+        &self.payload_type
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    pub fn get_correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    pub fn get_tenant_id(&self) -> Option<&str> {
+        self.tenant_id.as_deref()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::IonType;
+    use ion_rs::IonReader;
+    use ion_rs::ReaderBuilder;
+    use ion_rs::StreamItem;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::envelope::typed_envelope::TypedEnvelopeDTO;
+    use crate::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+    use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+
+
+    const PAYLOAD_TYPE: &str = "network_graph_request";
+    const PAYLOAD: &[u8] = &[0xE0, 0x01, 0x00, 0xEA];
+    const CORRELATION_ID: &str = "9b2e4c1a";
+    const TENANT_ID: &str = "tenant-1";
+
+    #[test]
+    fn reader_correctly_read_encoded_typed_envelope() {
+        let envelope = TypedEnvelopeDTO::new(PAYLOAD_TYPE, PAYLOAD, Some(CORRELATION_ID), None);
+        let mut binary_user_reader = ReaderBuilder::new().build(envelope.encode()).unwrap();
+
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
+        binary_user_reader.step_in().unwrap();
+
+        assert_eq!(StreamItem::Value(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!("payload_type", binary_user_reader.field_name().unwrap());
+        assert_eq!(PAYLOAD_TYPE, binary_user_reader.read_string().unwrap().text());
+
+        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!("payload", binary_user_reader.field_name().unwrap());
+        assert_eq!(PAYLOAD, binary_user_reader.read_blob().unwrap().as_slice());
+
+        assert_eq!(StreamItem::Value(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!("correlation_id", binary_user_reader.field_name().unwrap());
+        assert_eq!(CORRELATION_ID, binary_user_reader.read_string().unwrap().text());
+
+        assert_eq!(StreamItem::Null(IonType::String), binary_user_reader.next().unwrap());
+        assert_eq!("tenant_id", binary_user_reader.field_name().unwrap());
+
+        binary_user_reader.step_out().unwrap();
+    }
+
+    #[test]
+    fn endec_typed_envelope() {
+        let envelope = TypedEnvelopeDTO::new(PAYLOAD_TYPE, PAYLOAD, Some(CORRELATION_ID), Some(TENANT_ID));
+        assert_eq!(envelope, TypedEnvelopeDTO::decode(&envelope.encode()));
+
+        let envelope = TypedEnvelopeDTO::new(PAYLOAD_TYPE, PAYLOAD, None, None);
+        assert_eq!(envelope, TypedEnvelopeDTO::decode(&envelope.encode()));
+    }
+
+    #[test]
+    fn wrapped_dto_is_tagged_with_its_data_type() {
+        let request = NetworkGraphRequestDTO::new(Timestamp::from_nanos(1), Timestamp::from_nanos(2), NetworkGraphFiltersDTO::new(&[], None, &[], None, None, None));
+        let envelope = TypedEnvelopeDTO::wrap(&request, Some(CORRELATION_ID), Some(TENANT_ID));
+
+        assert_eq!(envelope.get_payload_type(), NetworkGraphRequestDTO::get_data_type());
+        assert_eq!(envelope.get_payload(), request.encode());
+        assert_eq!(envelope.get_correlation_id(), Some(CORRELATION_ID));
+        assert_eq!(envelope.get_tenant_id(), Some(TENANT_ID));
+    }
+
+    #[test]
+    fn test_getting_data_types() {
+        let envelope = TypedEnvelopeDTO::new(PAYLOAD_TYPE, PAYLOAD, None, None);
+        assert_eq!(envelope.get_type(), TypedEnvelopeDTO::get_data_type());
+        assert_eq!(envelope.get_type(), super::DATA_TYPE);
+    }
+}
\ No newline at end of file
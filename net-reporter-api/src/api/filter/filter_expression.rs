@@ -0,0 +1,466 @@
+use ion_rs::IonResult;
+use ion_rs::IonType;
+use ion_rs::IonWriter;
+
+use net_core_api::core::api::API;
+use net_core_api::core::decoder_api::Decoder;
+use net_core_api::core::encoder_api::Encoder;
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::aliased_api::Aliased;
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::format_encoder_api::FormatEncoder;
+use net_codec_api::core::ion_format::IonFormat;
+use net_codec_api::core::ion_struct_api::IonStruct;
+use net_codec_api::core::try_decoder_api::TryDecoder;
+use net_codec_api::core::version::version_annotation;
+use net_codec_api::core::versioned_api::Versioned;
+
+use crate::api::endpoint_filter::endpoint_pattern::EndpointPattern;
+use crate::api::endpoint_filter::ip_network::IpNetwork;
+
+use super::filter_field::FilterField;
+use super::filter_value::FilterValue;
+
+
+const DATA_TYPE: &str = "filter_expression";
+const VERSION: u32 = 1;
+
+/// Expressions nested deeper than that are rejected when decoding, rather than overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+const AND: &str = "and";
+const OR: &str = "or";
+const NOT: &str = "not";
+const EQUALS: &str = "eq";
+const IN: &str = "in";
+const RANGE: &str = "range";
+const PREFIX: &str = "prefix";
+const CIDR: &str = "cidr";
+
+/// A condition on the captured traffic, built out of comparisons of a [`FilterField`] combined with `and`, `or` and `not`.
+///
+/// Every node is written as a struct naming its operator in `op`, e.g.
+/// `{op: "and", operands: [{op: "in", field: "protocol", values: ["TCP", "UDP"]}, {op: "range", field: "bytes", lower_bound: 100, upper_bound: null}]}`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FilterExpressionDTO {
+    /// Matches when every operand does, so it matches anything when there are none.
+    And(Vec<FilterExpressionDTO>),
+    /// Matches when any operand does, so it matches nothing when there are none.
+    Or(Vec<FilterExpressionDTO>),
+    Not(Box<FilterExpressionDTO>),
+    Equals { field: FilterField, value: FilterValue },
+    In { field: FilterField, values: Vec<FilterValue> },
+    /// Matches values between both bounds, which are inclusive. An unset bound does not limit the range.
+    /// Decoding rejects a lower bound above the upper one.
+    Range { field: FilterField, lower_bound: Option<i64>, upper_bound: Option<i64> },
+    Prefix { field: FilterField, prefix: String },
+    /// Matches addresses within a CIDR block, e.g. `10.0.0.0/8`. Decoding rejects a block which does not parse as an [`IpNetwork`].
+    Cidr { field: FilterField, cidr: String },
+}
+impl API for FilterExpressionDTO { }
+
+impl FilterExpressionDTO {
+    pub fn and(operands: Vec<FilterExpressionDTO>) -> Self {
+        FilterExpressionDTO::And(operands)
+    }
+
+    pub fn or(operands: Vec<FilterExpressionDTO>) -> Self {
+        FilterExpressionDTO::Or(operands)
+    }
+
+    pub fn negate(operand: FilterExpressionDTO) -> Self {
+        FilterExpressionDTO::Not(Box::new(operand))
+    }
+
+    pub fn equals(field: FilterField, value: impl Into<FilterValue>) -> Self {
+        FilterExpressionDTO::Equals { field, value: value.into() }
+    }
+
+    pub fn is_in<V: Into<FilterValue>>(field: FilterField, values: impl IntoIterator<Item = V>) -> Self {
+        FilterExpressionDTO::In { field, values: values.into_iter().map(Into::into).collect() }
+    }
+
+    pub fn range(field: FilterField, lower_bound: Option<i64>, upper_bound: Option<i64>) -> Self {
+        FilterExpressionDTO::Range { field, lower_bound, upper_bound }
+    }
+
+    pub fn prefix(field: FilterField, prefix: &str) -> Self {
+        FilterExpressionDTO::Prefix { field, prefix: prefix.into() }
+    }
+
+    pub fn cidr(field: FilterField, cidr: &str) -> Self {
+        FilterExpressionDTO::Cidr { field, cidr: cidr.into() }
+    }
+
+    /// Translates a list of a chart filters DTO along with its include mode: the values are kept when it is `true`,
+    /// left out when it is `false`, and the list is ignored when it is unset.
+    pub(crate) fn from_list<V>(field: FilterField, values: &[V], include_mode: Option<bool>) -> Option<Self>
+    where V: Into<FilterValue> + Clone {
+        let is_in = FilterExpressionDTO::is_in(field, values.iter().cloned());
+        match include_mode? {
+            true => Some(is_in),
+            false => Some(FilterExpressionDTO::negate(is_in)),
+        }
+    }
+
+    /// Translates the endpoints of a chart filters DTO along with their include mode, like [`from_list`](Self::from_list)
+    /// unless some of them are ranges, see [`EndpointPattern`].
+    pub(crate) fn from_endpoints(endpoints: &[String], include_mode: Option<bool>) -> Option<Self> {
+        let patterns = endpoints.iter()
+            .map(|endpoint| endpoint.parse::<EndpointPattern>())
+            .collect::<Result<Vec<_>, _>>();
+        let patterns = match patterns {
+            Ok(patterns) if !patterns.iter().all(EndpointPattern::is_single_endpoint) => patterns,
+            _ => return FilterExpressionDTO::from_list(FilterField::Endpoint, endpoints, include_mode),
+        };
+
+        let any_of = FilterExpressionDTO::or(patterns.iter().map(FilterExpressionDTO::from).collect());
+        match include_mode? {
+            true => Some(any_of),
+            false => Some(FilterExpressionDTO::negate(any_of)),
+        }
+    }
+
+    /// Translates the bounds of a chart filters DTO, which are ignored when both are unset.
+    pub(crate) fn from_bounds(field: FilterField, lower_bound: Option<i64>, upper_bound: Option<i64>) -> Option<Self> {
+        match (lower_bound, upper_bound) {
+            (None, None) => None,
+            _ => Some(FilterExpressionDTO::range(field, lower_bound, upper_bound)),
+        }
+    }
+
+    /// Combines the conditions translated from a chart filters DTO.
+    pub(crate) fn from_conditions(conditions: impl IntoIterator<Item = Option<Self>>) -> Self {
+        FilterExpressionDTO::and(conditions.into_iter().flatten().collect())
+    }
+
+    fn get_operator(&self) -> &'static str {
+        match self {
+            FilterExpressionDTO::And(_) => AND,
+            FilterExpressionDTO::Or(_) => OR,
+            FilterExpressionDTO::Not(_) => NOT,
+            FilterExpressionDTO::Equals { .. } => EQUALS,
+            FilterExpressionDTO::In { .. } => IN,
+            FilterExpressionDTO::Range { .. } => RANGE,
+            FilterExpressionDTO::Prefix { .. } => PREFIX,
+            FilterExpressionDTO::Cidr { .. } => CIDR,
+        }
+    }
+}
+
+fn write_optional_i64<W: IonWriter>(writer: &mut W, value: Option<i64>) -> IonResult<()> {
+    match value {
+        Some(value) => writer.write_i64(value),
+        None => writer.write_null(IonType::Int),
+    }
+}
+
+impl IonStruct for FilterExpressionDTO {
+    fn write_struct<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+        writer.set_annotations([version_annotation(Self::get_version())]);
+        writer.step_in(IonType::Struct)?;
+
+        writer.set_field_name("op");
+        writer.write_string(self.get_operator())?;
+        match self {
+            FilterExpressionDTO::And(operands) | FilterExpressionDTO::Or(operands) => {
+                writer.set_field_name("operands");
+                writer.step_in(IonType::List)?;
+                for operand in operands {
+                    operand.write_struct(writer)?;
+                }
+                writer.step_out()?;
+            },
+            FilterExpressionDTO::Not(operand) => {
+                writer.set_field_name("operand");
+                operand.write_struct(writer)?;
+            },
+            FilterExpressionDTO::Equals { field, value } => {
+                writer.set_field_name("field");
+                field.write(writer)?;
+                writer.set_field_name("value");
+                value.write(writer)?;
+            },
+            FilterExpressionDTO::In { field, values } => {
+                writer.set_field_name("field");
+                field.write(writer)?;
+                writer.set_field_name("values");
+                writer.step_in(IonType::List)?;
+                for value in values {
+                    value.write(writer)?;
+                }
+                writer.step_out()?;
+            },
+            FilterExpressionDTO::Range { field, lower_bound, upper_bound } => {
+                writer.set_field_name("field");
+                field.write(writer)?;
+                writer.set_field_name("lower_bound");
+                write_optional_i64(writer, *lower_bound)?;
+                writer.set_field_name("upper_bound");
+                write_optional_i64(writer, *upper_bound)?;
+            },
+            FilterExpressionDTO::Prefix { field, prefix } => {
+                writer.set_field_name("field");
+                field.write(writer)?;
+                writer.set_field_name("prefix");
+                writer.write_string(prefix)?;
+            },
+            FilterExpressionDTO::Cidr { field, cidr } => {
+                writer.set_field_name("field");
+                field.write(writer)?;
+                writer.set_field_name("cidr");
+                writer.write_string(cidr)?;
+            },
+        }
+
+        writer.step_out()
+    }
+
+    fn read_struct(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+        if reader.get_depth() >= MAX_DEPTH {
+            return Err(reader.invalid("", IonType::Struct, &format!("filter expressions are limited to {MAX_DEPTH} levels")));
+        }
+        reader.step_in_struct()?;
+
+        let mut op = None;
+        let mut field = None;
+        let mut operands = None;
+        let mut operand = None;
+        let mut value = None;
+        let mut values = None;
+        let mut lower_bound = None;
+        let mut upper_bound = None;
+        let mut prefix = None;
+        let mut cidr = None;
+        while let Some(field_name) = reader.next_field_name()? {
+            match field_name.as_str() {
+                "op" => op = Some(reader.read_string()?),
+                "field" => field = Some(FilterField::read(reader, "field")?),
+                // Operands were never written as blobs, which a new reader would decode past `MAX_DEPTH`
+                "operands" => operands = Some(reader.read_list(FilterExpressionDTO::read_struct)?),
+                "operand" => operand = Some(FilterExpressionDTO::read_struct(reader)?),
+                "value" => value = Some(FilterValue::read(reader)?),
+                "values" => values = Some(reader.read_list(FilterValue::read)?),
+                "lower_bound" => lower_bound = reader.read_optional_i64()?,
+                "upper_bound" => upper_bound = reader.read_optional_i64()?,
+                "prefix" => prefix = Some(reader.read_string()?),
+                "cidr" => cidr = Some(reader.read_string()?),
+                _ => (),
+            }
+        }
+
+        let op = reader.require("op", IonType::String, op)?;
+        let expression = match op.as_str() {
+            AND => FilterExpressionDTO::And(reader.require("operands", IonType::List, operands)?),
+            OR => FilterExpressionDTO::Or(reader.require("operands", IonType::List, operands)?),
+            NOT => FilterExpressionDTO::negate(reader.require("operand", IonType::Struct, operand)?),
+            EQUALS => FilterExpressionDTO::Equals {
+                field: reader.require("field", IonType::String, field)?,
+                value: reader.require("value", IonType::String, value)?,
+            },
+            IN => FilterExpressionDTO::In {
+                field: reader.require("field", IonType::String, field)?,
+                values: reader.require("values", IonType::List, values)?,
+            },
+            RANGE => match (lower_bound, upper_bound) {
+                (Some(lower), Some(upper)) if lower > upper => return Err(reader.invalid(
+                    "lower_bound",
+                    IonType::Int,
+                    &format!("the lower bound {lower} is above the upper bound {upper}"),
+                )),
+                _ => FilterExpressionDTO::Range {
+                    field: reader.require("field", IonType::String, field)?,
+                    lower_bound,
+                    upper_bound,
+                },
+            },
+            PREFIX => FilterExpressionDTO::Prefix {
+                field: reader.require("field", IonType::String, field)?,
+                prefix: reader.require("prefix", IonType::String, prefix)?,
+            },
+            CIDR => {
+                let cidr = reader.require("cidr", IonType::String, cidr)?;
+                if let Err(error) = cidr.parse::<IpNetwork>() {
+                    return Err(reader.invalid("cidr", IonType::String, &error.to_string()));
+                }
+                FilterExpressionDTO::Cidr { field: reader.require("field", IonType::String, field)?, cidr }
+            },
+            op => return Err(reader.invalid("op", IonType::String, &format!("unknown filter operator `{op}`"))),
+        };
+
+        reader.step_out()?;
+
+        Ok(expression)
+    }
+}
+
+impl Encoder for FilterExpressionDTO {
+    fn encode(&self) -> Vec<u8> {
+        self.encode_as(IonFormat::Binary)
+    }
+}
+
+impl TryDecoder for FilterExpressionDTO {
+    fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DecodeReader::open(data)?;
+        FilterExpressionDTO::read_struct(&mut reader)
+    }
+}
+
+impl Decoder for FilterExpressionDTO {
+    fn decode(data: &[u8]) -> Self {
+        FilterExpressionDTO::try_decode(data).unwrap()
+    }
+}
+
+impl Typed for FilterExpressionDTO {
+    fn get_data_type() -> &'static str {
+        DATA_TYPE
+    }
+
+    fn get_type(&self) -> &str {
+        Self::get_data_type()
+    }
+}
+
+impl Aliased for FilterExpressionDTO {
+    fn get_data_type_aliases() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+impl Versioned for FilterExpressionDTO {
+    fn get_version() -> u32 {
+        VERSION
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use ion_rs::element::Element;
+    use ion_rs::IonType;
+    use ion_rs::IonWriter;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::format_encoder_api::FormatEncoder;
+    use net_codec_api::core::ion_format::IonFormat;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::filter::filter_expression::FilterExpressionDTO;
+    use crate::api::filter::filter_field::FilterField;
+
+
+    fn get_test_expression() -> FilterExpressionDTO {
+        FilterExpressionDTO::and(vec![
+            FilterExpressionDTO::or(vec![
+                FilterExpressionDTO::cidr(FilterField::Endpoint, "10.0.0.0/8"),
+                FilterExpressionDTO::prefix(FilterField::Endpoint, "192.168."),
+            ]),
+            FilterExpressionDTO::negate(FilterExpressionDTO::is_in(FilterField::Protocol, ["TCP", "UDP"])),
+            FilterExpressionDTO::is_in(FilterField::ResponseCode, [200, 404]),
+            FilterExpressionDTO::equals(FilterField::HttpMethod, "GET"),
+            FilterExpressionDTO::range(FilterField::Bytes, Some(100), None),
+        ])
+    }
+
+    #[test]
+    fn endec_filter_expression() {
+        let expression = get_test_expression();
+        assert_eq!(expression, FilterExpressionDTO::decode(&expression.encode()));
+        assert_eq!(expression, FilterExpressionDTO::decode(&expression.encode_as(IonFormat::Text)));
+    }
+
+    #[test]
+    fn filter_expression_is_written_as_nested_structs() {
+        let expression = FilterExpressionDTO::and(vec![
+            FilterExpressionDTO::is_in(FilterField::Protocol, ["TCP"]),
+            FilterExpressionDTO::range(FilterField::Bytes, Some(100), None),
+        ]);
+        let expected = Element::read_one(
+            r#"v1::{op: "and", operands: [
+                v1::{op: "in", field: "protocol", values: ["TCP"]},
+                v1::{op: "range", field: "bytes", lower_bound: 100, upper_bound: null.int},
+            ]}"#,
+        ).unwrap();
+
+        assert_eq!(Element::read_one(expression.encode()).unwrap(), expected);
+    }
+
+    #[test]
+    fn unknown_operator_is_reported() {
+        let error = FilterExpressionDTO::try_decode(r#"{op: "and", operands: [{op: "xor", operands: []}]}"#.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "operands[0].op");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn unknown_field_is_reported() {
+        let error = FilterExpressionDTO::try_decode(r#"{op: "eq", field: "host", value: "example.com"}"#.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "field");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn reversed_range_is_rejected() {
+        let error = FilterExpressionDTO::try_decode(&FilterExpressionDTO::range(FilterField::Bytes, Some(100), Some(10)).encode()).unwrap_err();
+        assert_eq!(error.get_field(), "lower_bound");
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+
+        let range = FilterExpressionDTO::range(FilterField::Bytes, Some(10), Some(10));
+        assert_eq!(range, FilterExpressionDTO::decode(&range.encode()));
+    }
+
+    #[test]
+    fn invalid_cidr_is_rejected() {
+        for cidr in ["10.0.0.0/33", "10.0.0/8", "example.com"] {
+            let error = FilterExpressionDTO::try_decode(&FilterExpressionDTO::cidr(FilterField::Endpoint, cidr).encode()).unwrap_err();
+            assert_eq!(error.get_field(), "cidr");
+            assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+        }
+    }
+
+    #[test]
+    fn deeply_nested_expression_is_rejected() {
+        let mut expression = FilterExpressionDTO::and(Vec::new());
+        for _ in 0..100 {
+            expression = FilterExpressionDTO::negate(expression);
+        }
+        let error = FilterExpressionDTO::try_decode(&expression.encode()).unwrap_err();
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn blob_nested_expression_is_rejected() {
+        let mut encoded = FilterExpressionDTO::and(Vec::new()).encode();
+        for _ in 0..100 {
+            let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+            writer.step_in(IonType::Struct).unwrap();
+            writer.set_field_name("op");
+            writer.write_string("not").unwrap();
+            writer.set_field_name("operand");
+            writer.write_blob(&encoded).unwrap();
+            writer.step_out().unwrap();
+            writer.flush().unwrap();
+            encoded = writer.output().clone();
+        }
+
+        let error = FilterExpressionDTO::try_decode(&encoded).unwrap_err();
+        assert_eq!(error.get_field(), "operand");
+    }
+
+    #[test]
+    fn test_getting_data_types() {
+        let expression = get_test_expression();
+        assert_eq!(expression.get_type(), FilterExpressionDTO::get_data_type());
+        assert_eq!(expression.get_type(), super::DATA_TYPE);
+    }
+}
\ No newline at end of file
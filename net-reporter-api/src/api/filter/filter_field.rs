@@ -0,0 +1,76 @@
+use ion_rs::IonResult;
+use ion_rs::IonType;
+use ion_rs::IonWriter;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+
+
+/// A field of the captured traffic a filter expression can match on.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FilterField {
+    Endpoint,
+    Port,
+    Protocol,
+    HttpMethod,
+    ResponseCode,
+    Bytes,
+}
+
+impl FilterField {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FilterField::Endpoint => "endpoint",
+            FilterField::Port => "port",
+            FilterField::Protocol => "protocol",
+            FilterField::HttpMethod => "http_method",
+            FilterField::ResponseCode => "response_code",
+            FilterField::Bytes => "bytes",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "endpoint" => Some(FilterField::Endpoint),
+            "port" => Some(FilterField::Port),
+            "protocol" => Some(FilterField::Protocol),
+            "http_method" => Some(FilterField::HttpMethod),
+            "response_code" => Some(FilterField::ResponseCode),
+            "bytes" => Some(FilterField::Bytes),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn write<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+        writer.write_string(self.get_name())
+    }
+
+    pub(crate) fn read(reader: &mut DecodeReader, field: &str) -> Result<Self, DecodeError> {
+        let name = reader.read_string()?;
+        FilterField::from_name(&name)
+            .ok_or_else(|| reader.invalid(field, IonType::String, &format!("unknown filter field `{name}`")))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::filter::filter_field::FilterField;
+
+    #[test]
+    fn fields_are_parsed_from_their_name() {
+        for field in [
+            FilterField::Endpoint,
+            FilterField::Port,
+            FilterField::Protocol,
+            FilterField::HttpMethod,
+            FilterField::ResponseCode,
+            FilterField::Bytes,
+        ] {
+            assert_eq!(FilterField::from_name(field.get_name()), Some(field));
+        }
+        assert_eq!(FilterField::from_name("host"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use ion_rs::IonResult;
+use ion_rs::IonType;
+use ion_rs::IonWriter;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+
+
+/// A value a filter expression compares a field with, written as an Ion string or int.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum FilterValue {
+    String(String),
+    Int(i64),
+}
+
+impl FilterValue {
+    pub(crate) fn write<W: IonWriter>(&self, writer: &mut W) -> IonResult<()> {
+        match self {
+            FilterValue::String(value) => writer.write_string(value),
+            FilterValue::Int(value) => writer.write_i64(*value),
+        }
+    }
+
+    pub(crate) fn read(reader: &mut DecodeReader) -> Result<Self, DecodeError> {
+        match reader.get_current_type() {
+            Some(IonType::Int) => reader.read_i64().map(FilterValue::Int),
+            _ => reader.read_string().map(FilterValue::String),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.into())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        FilterValue::Int(value)
+    }
+}
\ No newline at end of file
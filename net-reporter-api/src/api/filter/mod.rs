@@ -0,0 +1,3 @@
+pub mod filter_expression;
+pub mod filter_field;
+pub mod filter_value;
\ No newline at end of file
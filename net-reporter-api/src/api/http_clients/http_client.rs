@@ -1,20 +1,14 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::StreamItem;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 const DATA_TYPE: &str = "http_client";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpClientDTO {
     endpoint: String,
     user_agent: Option<String>,
@@ -44,83 +38,21 @@ impl HttpClientDTO {
     }
 }
 
-impl Encoder for HttpClientDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("endpoint");
-        writer.write_string(&self.endpoint).unwrap();
-
-        writer.set_field_name("user_agent");
-        match &self.user_agent {
-            Some(user_agent) => writer.write_string(user_agent).unwrap(),
-            None => writer.write_null(IonType::String).unwrap(),
-        }
-
-        writer.set_field_name("requests_amount");
-        writer.write_i64(self.requests_amount).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpClientDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let endpoint = binding.text();
-
-        binary_user_reader.next().unwrap();
-
-        let user_agent = match binary_user_reader.current() {
-            StreamItem::Value(_) => {
-                let binding = binary_user_reader.read_string().unwrap();
-                Some(binding.text().to_string())
-            },
-            _ => None,
-        };
-
-        binary_user_reader.next().unwrap();
-        let requests_amount = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        HttpClientDTO::new(endpoint, user_agent.as_deref(), requests_amount)
-    }
-}
-
-impl Typed for HttpClientDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
     use ion_rs::IonType;
     use ion_rs::IonReader;
+    use ion_rs::IonWriter;
     use ion_rs::ReaderBuilder;
     use ion_rs::StreamItem;
     
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
     use crate::api::http_clients::http_client::HttpClientDTO;
 
     #[test]
@@ -157,4 +89,37 @@ mod tests {
         let http_client = HttpClientDTO::new(ENDPOINT, Some(USER_AGENT), REQUESTS_AMOUNT);
         assert_eq!(http_client, HttpClientDTO::decode(&http_client.encode()));
     }
-}
+
+    #[test]
+    fn decode_http_client_with_reordered_and_unknown_fields() {
+        const ENDPOINT: &str = "0.0.0.0";
+        const USER_AGENT: &str = "Mozilla/5.0";
+        const REQUESTS_AMOUNT: i64 = 123123;
+
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("requests_amount");
+        writer.write_i64(REQUESTS_AMOUNT).unwrap();
+        writer.set_field_name("agent_id");
+        writer.write_string("SOME_AGENT_ID").unwrap();
+        writer.set_field_name("user_agent");
+        writer.write_string(USER_AGENT).unwrap();
+        writer.set_field_name("endpoint");
+        writer.write_string(ENDPOINT).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+
+        let http_client = HttpClientDTO::new(ENDPOINT, Some(USER_AGENT), REQUESTS_AMOUNT);
+        assert_eq!(http_client, HttpClientDTO::decode(writer.output().as_slice()));
+    }
+
+    #[test]
+    fn try_decode_http_client_without_endpoint() {
+        const HTTP_CLIENT: &str = "{user_agent: null.string, requests_amount: 123123}";
+
+        let error = HttpClientDTO::try_decode(HTTP_CLIENT.as_bytes()).unwrap_err();
+        assert_eq!(error.get_field(), "endpoint");
+        assert_eq!(error.get_expected_type(), Some(IonType::String));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::MissingField);
+    }
+}
\ No newline at end of file
@@ -1,25 +1,22 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_derive::IonDto;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use crate::api::pagination::page_info::PageInfoDTO;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::http_client::HttpClientDTO;
 
 
 const DATA_TYPE: &str = "http_clients";
+const VERSION: u32 = 3;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpClientsDTO {
     http_clients: Vec<HttpClientDTO>,
+    page: Option<PageInfoDTO>,
 }
 impl API for HttpClientsDTO { }
 
@@ -27,65 +24,24 @@ impl HttpClientsDTO {
     pub fn new(http_clients: &[HttpClientDTO],) -> Self {
         HttpClientsDTO {
             http_clients: http_clients.to_vec(),
+            page: None,
         }
     }
 
-    pub fn get_http_clients(&self) -> &[HttpClientDTO] {
-        &self.http_clients
-    }
-}
-
-impl Encoder for HttpClientsDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("http_clients");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        self.http_clients.iter().for_each(|http_clients| {
-            let data = http_clients.encode();
-            writer.write_blob(data.as_slice()).unwrap();
-        });
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpClientsDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let http_clients_elements = binary_user_reader.read_all_elements().unwrap();
-        let mut http_clients = Vec::with_capacity(http_clients_elements.len());
-        http_clients_elements.iter().for_each(|element| {
-            let data = element.as_blob().unwrap();
-            let bucket = HttpClientDTO::decode(data);
-            http_clients.push(bucket);
-        });
-
-        Self::new(&http_clients)
+    pub fn new_page(http_clients: &[HttpClientDTO], page: PageInfoDTO) -> Self {
+        HttpClientsDTO {
+            http_clients: http_clients.to_vec(),
+            page: Some(page),
+        }
     }
-}
 
-impl Typed for HttpClientsDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+    pub fn get_http_clients(&self) -> &[HttpClientDTO] {
+        &self.http_clients
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// The page the response holds, unset when the request did not ask for one.
+    pub fn get_page(&self) -> Option<&PageInfoDTO> {
+        self.page.as_ref()
     }
 }
 
@@ -103,6 +59,7 @@ mod tests {
 
     use crate::api::http_clients::http_client::HttpClientDTO;
     use crate::api::http_clients::http_clients::HttpClientsDTO;
+    use crate::api::pagination::page_info::PageInfoDTO;
     
 
 
@@ -132,7 +89,7 @@ mod tests {
         let elements = binary_user_reader.read_all_elements().unwrap();
         assert_eq!(elements.len(), http_clients.len());
         for (element, http_client_core) in elements.iter().zip(http_clients.as_slice()) {
-            let encoded_endpoint = HttpClientDTO::decode(element.as_blob().unwrap());
+            let encoded_endpoint = HttpClientDTO::decode(&element.to_binary().unwrap());
             assert_eq!(encoded_endpoint, *http_client_core);
         }
         binary_user_reader.step_out().unwrap();
@@ -157,4 +114,13 @@ mod tests {
 
         assert_eq!(http_clients_dto, HttpClientsDTO::decode(&http_clients_dto.encode()));
     }
+
+    #[test]
+    fn endec_http_clients_page() {
+        let http_clients = vec![HttpClientDTO::new("0.0.0.0", None, 1)];
+        let http_clients_dto = HttpClientsDTO::new_page(&http_clients, PageInfoDTO::new(Some("b2Zmc2V0OjUw"), 1234));
+
+        assert_eq!(http_clients_dto.get_page().and_then(PageInfoDTO::get_next_cursor), Some("b2Zmc2V0OjUw"));
+        assert_eq!(http_clients_dto, HttpClientsDTO::decode(&http_clients_dto.encode()));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,160 @@
+use std::io::Read;
+use std::io::Write;
+
+use ion_rs::IonResult;
+use ion_rs::IonType;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::ion_format::IonFormat;
+use net_codec_api::core::list_reader::ListReader;
+use net_codec_api::core::struct_writer::StructWriter;
+use net_codec_api::core::versioned_api::Versioned;
+
+use crate::api::pagination::page_info::PageInfoDTO;
+
+
+use super::http_client::HttpClientDTO;
+use super::http_clients::HttpClientsDTO;
+
+
+/// Reads the clients of an encoded [`HttpClientsDTO`] one at a time,
+/// so that a large page can be handled without holding all of its clients in memory.
+///
+/// The page is returned by [`HttpClientsReader::finish`] once the clients are read.
+/// The reader stops after the first client it fails to decode.
+pub struct HttpClientsReader<'a> {
+    http_clients: ListReader<'a, HttpClientDTO>,
+    page: Option<PageInfoDTO>,
+}
+
+impl<'a> HttpClientsReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::new(data)?)
+    }
+
+    pub fn from_reader<R: Read + 'a>(input: R) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::from_reader(input)?)
+    }
+
+    fn open(mut reader: DecodeReader<'a>) -> Result<Self, DecodeError> {
+        let mut page = None;
+        reader.seek_field("http_clients", IonType::List, |reader, field| read_page(reader, field, &mut page))?;
+        let http_clients = ListReader::new(reader)?;
+
+        Ok(HttpClientsReader { http_clients, page })
+    }
+
+    /// Skips the clients which were not read and returns the page the clients belong to.
+    pub fn finish(self) -> Result<Option<PageInfoDTO>, DecodeError> {
+        let mut reader = self.http_clients.finish()?;
+        let mut page = self.page;
+        while let Some(field) = reader.next_field_name()? {
+            read_page(&mut reader, &field, &mut page)?;
+        }
+        reader.step_out()?;
+
+        Ok(page)
+    }
+}
+
+impl Iterator for HttpClientsReader<'_> {
+    type Item = Result<HttpClientDTO, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.http_clients.next()
+    }
+}
+
+fn read_page(reader: &mut DecodeReader, field: &str, page: &mut Option<PageInfoDTO>) -> Result<(), DecodeError> {
+    if field == "page" {
+        *page = reader.read_optional(IonType::Struct, |reader| reader.read_struct())?;
+    }
+    Ok(())
+}
+
+/// Writes an [`HttpClientsDTO`] into `output` one client at a time,
+/// so that the clients don't have to be collected first. The payload decodes as an `HttpClientsDTO`.
+pub struct HttpClientsWriter<W: Write> {
+    writer: StructWriter<W>,
+}
+
+impl<W: Write> HttpClientsWriter<W> {
+    pub fn new(output: W, format: IonFormat) -> IonResult<Self> {
+        let mut writer = StructWriter::new(output, format, HttpClientsDTO::get_version())?;
+        writer.begin_list("http_clients")?;
+
+        Ok(HttpClientsWriter { writer })
+    }
+
+    pub fn write(&mut self, http_client: &HttpClientDTO) -> IonResult<()> {
+        self.writer.write_element(http_client)
+    }
+
+    /// Ends the clients with the page they belong to, unset when the request did not ask for one.
+    pub fn finish(mut self, page: Option<&PageInfoDTO>) -> IonResult<()> {
+        self.writer.end_list()?;
+        self.writer.write_optional_struct("page", page)?;
+        self.writer.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::ion_format::IonFormat;
+
+    use crate::api::http_clients::http_client::HttpClientDTO;
+    use crate::api::http_clients::http_clients::HttpClientsDTO;
+    use crate::api::http_clients::http_clients_stream::HttpClientsReader;
+    use crate::api::http_clients::http_clients_stream::HttpClientsWriter;
+    use crate::api::pagination::page_info::PageInfoDTO;
+
+
+    fn get_http_client(requests_amount: i64) -> HttpClientDTO {
+        HttpClientDTO::new("0.0.0.0", Some("curl/8.4.0"), requests_amount)
+    }
+
+    #[test]
+    fn streamed_clients_decode_as_the_dto() {
+        let http_clients = [get_http_client(10), get_http_client(3)];
+        let page = PageInfoDTO::new(Some("cursor"), 2);
+
+        let mut output = Vec::new();
+        let mut writer = HttpClientsWriter::new(&mut output, IonFormat::Binary).unwrap();
+        http_clients.iter().for_each(|http_client| writer.write(http_client).unwrap());
+        writer.finish(Some(&page)).unwrap();
+
+        assert_eq!(HttpClientsDTO::decode(&output), HttpClientsDTO::new_page(&http_clients, page));
+    }
+
+    #[test]
+    fn clients_are_read_one_at_a_time() {
+        let page = PageInfoDTO::new(None, 2);
+        let data = HttpClientsDTO::new_page(&[get_http_client(10), get_http_client(3)], page.clone()).encode();
+        let mut reader = HttpClientsReader::from_reader(data.as_slice()).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_http_client(10))));
+        assert_eq!(reader.next(), Some(Ok(get_http_client(3))));
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.finish(), Ok(Some(page)));
+    }
+
+    #[test]
+    fn page_is_read_even_if_clients_are_left() {
+        let page = PageInfoDTO::new(None, 2);
+        let data = HttpClientsDTO::new_page(&[get_http_client(10), get_http_client(3)], page.clone()).encode();
+        let mut reader = HttpClientsReader::new(&data).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_http_client(10))));
+        assert_eq!(reader.finish(), Ok(Some(page)));
+
+        let data = HttpClientsDTO::new(&[]).encode();
+        let mut reader = HttpClientsReader::new(&data).unwrap();
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.finish(), Ok(None));
+    }
+}
\ No newline at end of file
@@ -3,3 +3,4 @@ pub mod http_clients_filters;
 pub mod http_clients_request;
 #[allow(clippy::module_inception)]
 pub mod http_clients;
+pub mod http_clients_stream;
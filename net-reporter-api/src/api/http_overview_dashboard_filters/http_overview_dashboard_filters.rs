@@ -1,20 +1,14 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 const DATA_TYPE: &str = "http_overview_dashboard_filters";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpOverviewDashboardFiltersDTO {
     endpoints: Vec<String>,
     http_request_methods: Vec<String>,
@@ -44,96 +38,6 @@ impl HttpOverviewDashboardFiltersDTO {
     }
 }
 
-impl Encoder for HttpOverviewDashboardFiltersDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("endpoints");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for endpoint in &self.endpoints {
-            writer.write_string(endpoint).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.set_field_name("http_request_methods");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for method in &self.http_request_methods {
-            writer.write_string(method).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.set_field_name("http_response_codes");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for code in &self.http_response_codes {
-            writer.write_string(code).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpOverviewDashboardFiltersDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut endpoints = Vec::<String>::with_capacity(elements.len());
-        for element in elements {
-            endpoints.push(element.as_text().unwrap().to_string());
-        }
-        binary_user_reader.step_out().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut http_request_methods = Vec::<String>::with_capacity(elements.len());
-        for element in elements {
-            http_request_methods.push(element.as_text().unwrap().to_string());
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut http_response_codes = Vec::<String>::with_capacity(elements.len());
-        for element in elements {
-            http_response_codes.push(element.as_text().unwrap().to_string());
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        HttpOverviewDashboardFiltersDTO::new(
-            endpoints.as_slice(),
-            http_request_methods.as_slice(),
-            http_response_codes.as_slice(),
-        )
-    }
-}
-
-impl Typed for HttpOverviewDashboardFiltersDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -1,90 +1,61 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
+use std::time::Duration;
 
 use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_codec_derive::IonDto;
+
+use crate::api::time_range::time_range_error::TimeRangeError;
+use crate::api::time_range::time_range_request::validate_time_range;
+use crate::api::time_range::time_unit::TimeUnit;
+use crate::core::query_api::Query;
+
+
+use super::http_overview_dashboard_filters::HttpOverviewDashboardFiltersDTO;
+
 
 const DATA_TYPE: &str = "http_overview_dashboard_filters_request";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpOverviewDashboardFiltersRequestDTO {
-    start_date_time: i64,
-    end_date_time: i64,
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    start_date_time: Timestamp,
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    end_date_time: Timestamp,
 }
 impl API for HttpOverviewDashboardFiltersRequestDTO { }
 
+impl Query for HttpOverviewDashboardFiltersRequestDTO {
+    type Response = HttpOverviewDashboardFiltersDTO;
+}
+
 impl HttpOverviewDashboardFiltersRequestDTO {
-    pub fn new (start_date_time: i64, end_date_time: i64) -> Self {
+    pub fn new (start_date_time: Timestamp, end_date_time: Timestamp) -> Self {
         HttpOverviewDashboardFiltersRequestDTO {
             start_date_time,
             end_date_time,
         }
     }
 
-    pub fn get_start_date_time (&self) -> i64 {
+    pub fn get_start_date_time (&self) -> Timestamp {
         self.start_date_time
     }
 
-    pub fn get_end_date_time (&self) -> i64 {
+    pub fn get_end_date_time (&self) -> Timestamp {
         self.end_date_time
     }
-}
-
-impl Encoder for HttpOverviewDashboardFiltersRequestDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();    
-
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("start_date_time");
-        writer.write_i64(self.start_date_time).unwrap();
-
-        writer.set_field_name("end_date_time");
-        writer.write_i64(self.end_date_time).unwrap();
 
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpOverviewDashboardFiltersRequestDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let start_date_time = binary_user_reader.read_i64().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        let end_date_time = binary_user_reader.read_i64().unwrap();
-
-        HttpOverviewDashboardFiltersRequestDTO::new(
-            start_date_time,
-            end_date_time
-        )
+    pub fn get_time_unit(&self) -> TimeUnit {
+        TimeUnit::Milliseconds
     }
-}
 
-impl Typed for HttpOverviewDashboardFiltersRequestDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// Checks the range ends after it starts and, when `max_span` is set, does not span more than it.
+    pub fn validate(&self, max_span: Option<Duration>) -> Result<(), TimeRangeError> {
+        validate_time_range(self.start_date_time, self.end_date_time, max_span)
     }
 }
 
@@ -99,13 +70,15 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::http_overview_dashboard_filters::http_overview_dashboard_filters_request::HttpOverviewDashboardFiltersRequestDTO;
 
     // ovdf - overview dashboard filters
     #[test]
     fn reader_correctly_read_encoded_hvdf_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_bandwidth_request = HttpOverviewDashboardFiltersRequestDTO::new(
             START_DATE_TIME,
@@ -117,19 +90,19 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("start_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(START_DATE_TIME, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(START_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
         
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("end_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(END_DATE_TIME,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(END_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
     }
 
     #[test]
     fn endec_hvdf_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_bandwidth_request = HttpOverviewDashboardFiltersRequestDTO::new(
             START_DATE_TIME,
@@ -1,23 +1,17 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::http_request_method::HttpRequestMethodDTO;
 
 
 const DATA_TYPE: &str = "http_request_methods_distribution";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpRequestMethodsDistributionDTO {
     http_requests: Vec<HttpRequestMethodDTO>,
 }
@@ -35,59 +29,6 @@ impl HttpRequestMethodsDistributionDTO {
     }
 }
 
-impl Encoder for HttpRequestMethodsDistributionDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("http_requests");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for http_request in &self.http_requests {
-            writer.write_blob(http_request.encode()).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpRequestMethodsDistributionDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut http_requests = Vec::<HttpRequestMethodDTO>::with_capacity(elements.len());
-        for element in elements {
-            http_requests.push(HttpRequestMethodDTO::decode(element.as_blob().unwrap()));
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        HttpRequestMethodsDistributionDTO::new(http_requests.as_slice())
-    }
-}
-
-impl Typed for HttpRequestMethodsDistributionDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -125,7 +66,7 @@ mod tests {
         binary_user_reader.step_in().unwrap();
 
         let endeced_http_requests: Vec<HttpRequestMethodDTO> = binary_user_reader.read_all_elements().unwrap().iter().map(|element| {
-            HttpRequestMethodDTO::decode(element.as_blob().unwrap())
+            HttpRequestMethodDTO::decode(&element.to_binary().unwrap())
         }).collect();
 
         assert_eq!(dist.get_http_requests(), endeced_http_requests);
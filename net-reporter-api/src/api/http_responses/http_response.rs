@@ -1,21 +1,21 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_api::core::timestamp::Timestamp;
 
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
+
+use crate::api::time_range::time_unit::TimeUnit;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 const DATA_TYPE: &str = "http_response";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpResponseDTO {
-    date: i64,
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    date: Timestamp,
     client: String,
     server: String,
     response: i64,
@@ -23,7 +23,7 @@ pub struct HttpResponseDTO {
 impl API for HttpResponseDTO { }
 
 impl HttpResponseDTO {
-    pub fn new(date: i64, client: &str, server: &str, response: i64) -> Self {
+    pub fn new(date: Timestamp, client: &str, server: &str, response: i64) -> Self {
         HttpResponseDTO {
             date,
             client: client.to_string(),
@@ -32,7 +32,7 @@ impl HttpResponseDTO {
         }
     }
 
-    pub fn get_date(&self) -> i64 {
+    pub fn get_date(&self) -> Timestamp {
         self.date
     }
 
@@ -49,69 +49,6 @@ impl HttpResponseDTO {
     }
 }
 
-impl Encoder for HttpResponseDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("date");
-        writer.write_i64(self.date).unwrap();
-
-        writer.set_field_name("client");
-        writer.write_string(&self.client).unwrap();
-
-        writer.set_field_name("server");
-        writer.write_string(&self.server).unwrap();
-
-        writer.set_field_name("response");
-        writer.write_i64(self.response).unwrap();
-        
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpResponseDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        let date = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let client = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let server = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let response = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        HttpResponseDTO::new(date, client, server, response)
-    }
-}
-
-impl Typed for HttpResponseDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -123,11 +60,13 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::http_responses::http_response::HttpResponseDTO;
 
     #[test]
     fn reader_correctly_read_encoded_http_response() {
-        const BUCKET_TIMESTAMP: i64 = 123456789;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::from_nanos(123456789);
         const CLIENT: &str = "0.0.0.0";
         const SERVER: &str = "1.1.1.1";
         const RESPONSE: i64 = 200;
@@ -137,9 +76,9 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("date", binary_user_reader.field_name().unwrap());
-        assert_eq!(BUCKET_TIMESTAMP, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(BUCKET_TIMESTAMP), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
         
         assert_eq!(StreamItem::Value(IonType::String), binary_user_reader.next().unwrap());
         assert_eq!("client", binary_user_reader.field_name().unwrap());
@@ -158,7 +97,7 @@ mod tests {
 
     #[test]
     fn endec_http_response() {
-        const BUCKET_TIMESTAMP: i64 = 123456789;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::from_nanos(123456789);
         const CLIENT: &str = "0.0.0.0";
         const SERVER: &str = "1.1.1.1";
         const RESPONSE: i64 = 200;
@@ -1,113 +1,31 @@
-use ion_rs;
+use crate::api::time_range::request_filters::RequestFilters;
+use crate::api::time_range::time_range_request::TimeRangeRequestDTO;
+use crate::api::time_range::time_unit::TimeUnit;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
-
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::http_responses_filters::HttpResponsesFiltersDTO;
+use super::http_responses::HttpResponsesDTO;
 
 
 const DATA_TYPE: &str = "http_responses_request";
+const VERSION: u32 = 5;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct HttpResponsesRequestDTO {
-    start_date_time: i64,
-    end_date_time: i64,
-    filters: HttpResponsesFiltersDTO,
-}
-impl API for HttpResponsesRequestDTO { }
-
-impl HttpResponsesRequestDTO {
-    pub fn new(start_date_time: i64, end_date_time: i64, filters: HttpResponsesFiltersDTO) -> Self {
-        HttpResponsesRequestDTO {
-            start_date_time,
-            end_date_time,
-            filters,
-        }
-    }
-
-    pub fn get_start_date_time(&self) -> i64 {
-        self.start_date_time
-    }
-
-    pub fn get_end_date_time(&self) -> i64 {
-        self.end_date_time
-    }
-
-    pub fn get_filters(&self) -> &HttpResponsesFiltersDTO {
-        &self.filters
-    }
-}
-
-impl Encoder for HttpResponsesRequestDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("start_date_time");
-        writer.write_i64(self.start_date_time).unwrap();
-
-        writer.set_field_name("end_date_time");
-        writer.write_i64(self.end_date_time).unwrap();
-
-        writer.set_field_name("filters");
-        writer.write_blob(self.filters.encode().as_slice()).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
+impl RequestFilters for HttpResponsesFiltersDTO {
+    const REQUEST_DATA_TYPE: &'static str = DATA_TYPE;
+    const REQUEST_VERSION: u32 = VERSION;
+    const TIME_UNIT: TimeUnit = TimeUnit::Milliseconds;
+    const PAGED: bool = true;
+    const SORT_KEYS: &'static [&'static str] = &["date", "client", "server", "response"];
 
-        writer.output().as_slice().into()
-    }
+    type Response = HttpResponsesDTO;
 }
 
-impl Decoder for HttpResponsesRequestDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let start_date_time = binary_user_reader.read_i64().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        let end_date_time = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        
-        let data = binary_user_reader.read_blob().unwrap();
-        let filters = HttpResponsesFiltersDTO::decode(data.as_slice());
-        
-        HttpResponsesRequestDTO::new(
-            start_date_time,
-            end_date_time,
-            filters,
-        )
-    }
-}
-
-impl Typed for HttpResponsesRequestDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
+pub type HttpResponsesRequestDTO = TimeRangeRequestDTO<HttpResponsesFiltersDTO>;
 
 
 #[cfg(test)]
 mod tests {
+    use ion_rs::element::Element;
     use ion_rs::IonType;
     use ion_rs::IonReader;
     use ion_rs::ReaderBuilder;
@@ -116,6 +34,8 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::http_responses::http_responses_request::HttpResponsesRequestDTO;
     use crate::api::http_responses::http_responses_filters::HttpResponsesFiltersDTO;
 
@@ -137,8 +57,8 @@ mod tests {
 
     #[test]
     fn reader_correctly_read_encoded_http_responses_request_some_filters() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_bandwidth_request = HttpResponsesRequestDTO::new(
             START_DATE_TIME,
@@ -151,25 +71,27 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("start_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(START_DATE_TIME, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(START_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
         
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("end_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(END_DATE_TIME,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(END_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
-        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         assert_eq!("filters", binary_user_reader.field_name().unwrap());
-        assert_eq!(get_test_filters(), HttpResponsesFiltersDTO::decode(binary_user_reader.read_blob().unwrap().as_slice()));
+        let request = Element::read_one(network_bandwidth_request.encode()).unwrap();
+        let filters = request.as_struct().unwrap().get("filters").unwrap();
+        assert_eq!(get_test_filters(), HttpResponsesFiltersDTO::decode(&filters.to_binary().unwrap()));
         
         binary_user_reader.step_out().unwrap();
     }
 
     #[test]
     fn endec_http_responses_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_bandwidth_request = HttpResponsesRequestDTO::new(
             START_DATE_TIME,
@@ -0,0 +1,166 @@
+use std::io::Read;
+use std::io::Write;
+
+use ion_rs::IonResult;
+use ion_rs::IonType;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::ion_format::IonFormat;
+use net_codec_api::core::list_reader::ListReader;
+use net_codec_api::core::struct_writer::StructWriter;
+use net_codec_api::core::versioned_api::Versioned;
+
+use crate::api::pagination::page_info::PageInfoDTO;
+
+
+use super::http_response::HttpResponseDTO;
+use super::http_responses::HttpResponsesDTO;
+
+
+/// Reads the responses of an encoded [`HttpResponsesDTO`] one at a time,
+/// so that a large page can be handled without holding all of its responses in memory.
+///
+/// The page is returned by [`HttpResponsesReader::finish`] once the responses are read.
+/// The reader stops after the first response it fails to decode.
+pub struct HttpResponsesReader<'a> {
+    http_responses: ListReader<'a, HttpResponseDTO>,
+    page: Option<PageInfoDTO>,
+}
+
+impl<'a> HttpResponsesReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::new(data)?)
+    }
+
+    pub fn from_reader<R: Read + 'a>(input: R) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::from_reader(input)?)
+    }
+
+    fn open(mut reader: DecodeReader<'a>) -> Result<Self, DecodeError> {
+        let mut page = None;
+        reader.seek_field("http_responses", IonType::List, |reader, field| read_page(reader, field, &mut page))?;
+        let http_responses = ListReader::new(reader)?;
+
+        Ok(HttpResponsesReader { http_responses, page })
+    }
+
+    /// Skips the responses which were not read and returns the page the responses belong to.
+    pub fn finish(self) -> Result<Option<PageInfoDTO>, DecodeError> {
+        let mut reader = self.http_responses.finish()?;
+        let mut page = self.page;
+        while let Some(field) = reader.next_field_name()? {
+            read_page(&mut reader, &field, &mut page)?;
+        }
+        reader.step_out()?;
+
+        Ok(page)
+    }
+}
+
+impl Iterator for HttpResponsesReader<'_> {
+    type Item = Result<HttpResponseDTO, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.http_responses.next()
+    }
+}
+
+fn read_page(reader: &mut DecodeReader, field: &str, page: &mut Option<PageInfoDTO>) -> Result<(), DecodeError> {
+    if field == "page" {
+        *page = reader.read_optional(IonType::Struct, |reader| reader.read_struct())?;
+    }
+    Ok(())
+}
+
+/// Writes an [`HttpResponsesDTO`] into `output` one response at a time,
+/// so that the responses don't have to be collected first. The payload decodes as an `HttpResponsesDTO`.
+pub struct HttpResponsesWriter<W: Write> {
+    writer: StructWriter<W>,
+}
+
+impl<W: Write> HttpResponsesWriter<W> {
+    pub fn new(output: W, format: IonFormat) -> IonResult<Self> {
+        let mut writer = StructWriter::new(output, format, HttpResponsesDTO::get_version())?;
+        writer.begin_list("http_responses")?;
+
+        Ok(HttpResponsesWriter { writer })
+    }
+
+    pub fn write(&mut self, http_response: &HttpResponseDTO) -> IonResult<()> {
+        self.writer.write_element(http_response)
+    }
+
+    /// Ends the responses with the page they belong to, unset when the request did not ask for one.
+    pub fn finish(mut self, page: Option<&PageInfoDTO>) -> IonResult<()> {
+        self.writer.end_list()?;
+        self.writer.write_optional_struct("page", page)?;
+        self.writer.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::ion_format::IonFormat;
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::http_responses::http_response::HttpResponseDTO;
+    use crate::api::http_responses::http_responses::HttpResponsesDTO;
+    use crate::api::http_responses::http_responses_stream::HttpResponsesReader;
+    use crate::api::http_responses::http_responses_stream::HttpResponsesWriter;
+    use crate::api::pagination::page_info::PageInfoDTO;
+
+
+    fn get_http_response(status: i64) -> HttpResponseDTO {
+        HttpResponseDTO::new(
+            Timestamp::from_millis(1_700_000_000_000).unwrap(),
+            "0.0.0.0",
+            "0.0.0.1",
+            status,
+        )
+    }
+
+    #[test]
+    fn streamed_responses_decode_as_the_dto() {
+        let http_responses = [get_http_response(200), get_http_response(404)];
+        let page = PageInfoDTO::new(Some("cursor"), 2);
+
+        let mut output = Vec::new();
+        let mut writer = HttpResponsesWriter::new(&mut output, IonFormat::Binary).unwrap();
+        http_responses.iter().for_each(|http_response| writer.write(http_response).unwrap());
+        writer.finish(Some(&page)).unwrap();
+
+        assert_eq!(HttpResponsesDTO::decode(&output), HttpResponsesDTO::new_page(&http_responses, page));
+    }
+
+    #[test]
+    fn responses_are_read_one_at_a_time() {
+        let page = PageInfoDTO::new(None, 2);
+        let data = HttpResponsesDTO::new_page(&[get_http_response(200), get_http_response(404)], page.clone()).encode();
+        let mut reader = HttpResponsesReader::from_reader(data.as_slice()).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_http_response(200))));
+        assert_eq!(reader.next(), Some(Ok(get_http_response(404))));
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.finish(), Ok(Some(page)));
+    }
+
+    #[test]
+    fn page_is_read_even_if_responses_are_left() {
+        let page = PageInfoDTO::new(None, 2);
+        let data = HttpResponsesDTO::new_page(&[get_http_response(200), get_http_response(404)], page.clone()).encode();
+        let mut reader = HttpResponsesReader::new(&data).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_http_response(200))));
+        assert_eq!(reader.finish(), Ok(Some(page)));
+
+        let data = HttpResponsesDTO::new(&[]).encode();
+        let mut reader = HttpResponsesReader::new(&data).unwrap();
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.finish(), Ok(None));
+    }
+}
\ No newline at end of file
@@ -3,3 +3,4 @@ pub mod http_responses_filters;
 pub mod http_responses_request;
 #[allow(clippy::module_inception)]
 pub mod http_responses;
+pub mod http_responses_stream;
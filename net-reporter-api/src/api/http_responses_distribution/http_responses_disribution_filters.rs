@@ -1,21 +1,20 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::element::reader::ElementReader;
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
-use ion_rs::StreamItem;
+use net_codec_derive::IonDto;
+
+use crate::api::endpoint_filter::endpoint_filters::EndpointFilters;
+use crate::api::filter::filter_expression::FilterExpressionDTO;
+use crate::api::filter::filter_field::FilterField;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 const DATA_TYPE: &str = "http_responses_distribution_filters";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate_endpoints)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpResponsesDistributionFiltersDTO {
+    #[ion(present_with = include_endpoints_mode)]
     endpoints: Vec<String>,
     include_endpoints_mode: Option<bool>,
     bytes_lower_bound: Option<i64>,
@@ -55,105 +54,26 @@ impl HttpResponsesDistributionFiltersDTO {
     }
 }
 
-impl Encoder for HttpResponsesDistributionFiltersDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("include_endpoints_mode");
-        match self.include_endpoints_mode {
-            Some(include_endpoints_mode) => {
-                writer.write_bool(include_endpoints_mode).unwrap();
-                writer.set_field_name("endpoints");
-                writer.step_in(IonType::List).expect("Error while entering an ion list");
-                self.endpoints.iter().for_each(|endpoint| {
-                    writer.write_string(endpoint).unwrap();
-                });
-                writer.step_out().unwrap();
-            },
-            None => writer.write_null(IonType::Bool).unwrap(),
-        }
-
-        writer.set_field_name("bytes_lower_bound");
-        match self.bytes_lower_bound {
-            Some(bytes_lower_bound) => writer.write_i64(bytes_lower_bound).unwrap(),
-            None => writer.write_null(IonType::Int).unwrap(),
-        }
-
-        writer.set_field_name("bytes_upper_bound");
-        match self.bytes_upper_bound {
-            Some(bytes_upper_bound) => writer.write_i64(bytes_upper_bound).unwrap(),
-            None => writer.write_null(IonType::Int).unwrap(),
-        }
-        
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
+impl EndpointFilters for HttpResponsesDistributionFiltersDTO {
+    fn get_endpoints(&self) -> &[String] {
+        self.endpoints.as_slice()
     }
-}
-
-impl Decoder for HttpResponsesDistributionFiltersDTO {
-    fn decode(data: &[u8]) -> Self {
 
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let (include_endpoints, endpoints) = match binary_user_reader.current() {
-            StreamItem::Value(_) => {
-                let include_endpoints = binary_user_reader.read_bool().unwrap();
-                binary_user_reader.next().unwrap();
-                binary_user_reader.step_in().unwrap();
-                let endpoints_elements = binary_user_reader.read_all_elements().unwrap();
-                let mut endpoints = Vec::with_capacity(endpoints_elements.len());
-                endpoints_elements.iter().for_each(|element| {
-                    let endpoint = element.as_string().unwrap();
-                    endpoints.push(endpoint.to_owned());
-                });
-                
-                binary_user_reader.step_out().unwrap();
-                (Some(include_endpoints), endpoints)
-            },
-            _ => (None, vec![]),
-        };
-
-        binary_user_reader.next().unwrap();
-        let bytes_lower_bound = match binary_user_reader.current() {
-            StreamItem::Value(_) => Some(binary_user_reader.read_i64().unwrap()),
-            _ => None,
-        };
-
-        binary_user_reader.next().unwrap();
-        let bytes_upper_bound = match binary_user_reader.current() {
-            StreamItem::Value(_) => Some(binary_user_reader.read_i64().unwrap()),
-            _ => None,
-        };
-
-        HttpResponsesDistributionFiltersDTO::new(
-            endpoints.as_slice(),
-            include_endpoints,
-            bytes_lower_bound,
-            bytes_upper_bound,
-        )
+    fn is_include_endpoints_mode(&self) -> Option<bool> {
+        self.include_endpoints_mode
     }
 }
 
-impl Typed for HttpResponsesDistributionFiltersDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+impl From<&HttpResponsesDistributionFiltersDTO> for FilterExpressionDTO {
+    fn from(filters: &HttpResponsesDistributionFiltersDTO) -> Self {
+        FilterExpressionDTO::from_conditions([
+            FilterExpressionDTO::from_endpoints(&filters.endpoints, filters.include_endpoints_mode),
+            FilterExpressionDTO::from_bounds(FilterField::Bytes, filters.bytes_lower_bound, filters.bytes_upper_bound),
+        ])
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use ion_rs::element::reader::ElementReader;
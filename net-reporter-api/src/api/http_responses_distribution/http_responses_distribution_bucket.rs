@@ -1,29 +1,28 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_api::core::timestamp::Timestamp;
 
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+use crate::api::time_range::time_unit::TimeUnit;
 
 
 const DATA_TYPE: &str = "http_responses_distribution_bucket";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpResponsesDistributionBucketDTO {
-    bucket_timestamp: i64,
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    bucket_timestamp: Timestamp,
     response_code: i64,
     amount: i64,
 }
 impl API for HttpResponsesDistributionBucketDTO { }
 
 impl HttpResponsesDistributionBucketDTO {
-    pub fn new(bucket_timestamp: i64, response_code: i64, amount: i64) -> Self {
+    pub fn new(bucket_timestamp: Timestamp, response_code: i64, amount: i64) -> Self {
         HttpResponsesDistributionBucketDTO {
             bucket_timestamp,
             response_code,
@@ -31,7 +30,7 @@ impl HttpResponsesDistributionBucketDTO {
         }
     }
 
-    pub fn get_bucket_timestamp(&self) -> i64 {
+    pub fn get_bucket_timestamp(&self) -> Timestamp {
         self.bucket_timestamp
     }
 
@@ -44,64 +43,6 @@ impl HttpResponsesDistributionBucketDTO {
     }
 }
 
-impl Encoder for HttpResponsesDistributionBucketDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new(); 
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("bucket_timestamp");
-        writer.write_i64(self.bucket_timestamp).unwrap();
-
-        writer.set_field_name("response_code");
-        writer.write_i64(self.response_code).unwrap();
-
-        writer.set_field_name("amount");
-        writer.write_i64(self.amount).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpResponsesDistributionBucketDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let bucket_timestamp = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let response_code = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let amount = binary_user_reader.read_i64().unwrap();
-
-        HttpResponsesDistributionBucketDTO::new(
-            bucket_timestamp,
-            response_code,
-            amount,
-        )
-    }
-}
-
-impl Typed for HttpResponsesDistributionBucketDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -113,11 +54,13 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::http_responses_distribution::http_responses_distribution_bucket::HttpResponsesDistributionBucketDTO;
 
     #[test]
     fn reader_correctly_read_encoded_http_responses_bucket() {
-        const BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const RESPONSE_CODE: i64 = i64::MIN;
         const AMOUNT: i64 = 0;
 
@@ -131,9 +74,9 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
         
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("bucket_timestamp", binary_user_reader.field_name().unwrap());
-        assert_eq!(BUCKET_TIMESTAMP,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(BUCKET_TIMESTAMP), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
         assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
         assert_eq!("response_code", binary_user_reader.field_name().unwrap());
@@ -148,7 +91,7 @@ mod tests {
 
     #[test]
     fn endec_http_responses_bucket() {
-        const BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const RESPONSE_CODE: i64 = i64::MIN;
         const AMOUNT: i64 = 0;
 
@@ -1,5 +1,3 @@
-//TODO: Rewrite all the inbound ion structs encode to `write_blob(*.encode())`
-
 pub mod http_overview_dashboard_filters;
 pub mod http_responses;
 pub mod http_request_methods_distribution;
@@ -12,4 +10,11 @@ pub mod network_overview_dashboard_filters;
 
 pub mod network_bandwidth;
 pub mod network_graph;
-pub mod total_http_requests;
\ No newline at end of file
+pub mod total_http_requests;
+
+pub mod envelope;
+pub mod dispatcher;
+pub mod time_range;
+pub mod filter;
+pub mod endpoint_filter;pub mod pagination;
+pub mod bucketing;
\ No newline at end of file
@@ -3,3 +3,4 @@ pub mod bandwidth_bucket;
 pub mod network_bandwidth;
 pub mod network_bandwidth_request;
 pub mod network_bandwidth_filters;
+pub mod network_bandwidth_stream;
@@ -1,25 +1,22 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_derive::IonDto;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use crate::api::bucketing::bucket_info::BucketInfoDTO;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::bandwidth_bucket::BandwidthBucketDTO;
 
 
 const DATA_TYPE: &str = "network_bandwidth";
+const VERSION: u32 = 4;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkBandwidthDTO {
     bandwidth_buckets: Vec<BandwidthBucketDTO>,
+    bucket_info: Option<BucketInfoDTO>,
 }
 impl API for NetworkBandwidthDTO { }
 
@@ -27,68 +24,24 @@ impl NetworkBandwidthDTO {
     pub fn new ( bandwidth_buckets: &[BandwidthBucketDTO],) -> Self {
         NetworkBandwidthDTO {
             bandwidth_buckets: bandwidth_buckets.to_vec(),
+            bucket_info: None,
         }
     }
 
-    pub fn get_bandwidth_buckets (&self) -> &[BandwidthBucketDTO] {
-        &self.bandwidth_buckets
-    }
-}
-
-impl Encoder for NetworkBandwidthDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("bandwidth_buckets");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        self.bandwidth_buckets.iter().for_each(|bandwidth_bucket| {
-            let data = bandwidth_bucket.encode();
-            writer.write_blob(data.as_slice()).unwrap();
-        });
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkBandwidthDTO {
-    fn decode(data: &[u8]) -> Self {
-        
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let bandwidth_bucket_elements = binary_user_reader.read_all_elements().unwrap();
-        let mut endpoints = Vec::with_capacity(bandwidth_bucket_elements.len());
-        bandwidth_bucket_elements.iter().for_each(|element| {
-            let data = element.as_blob().unwrap();
-            let endpoint = BandwidthBucketDTO::decode(data);
-            endpoints.push(endpoint);
-        });
-
-        Self::new(
-            &endpoints
-        )
+    pub fn new_bucketed(bandwidth_buckets: &[BandwidthBucketDTO], bucket_info: BucketInfoDTO) -> Self {
+        NetworkBandwidthDTO {
+            bandwidth_buckets: bandwidth_buckets.to_vec(),
+            bucket_info: Some(bucket_info),
+        }
     }
-}
 
-impl Typed for NetworkBandwidthDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+    pub fn get_bandwidth_buckets (&self) -> &[BandwidthBucketDTO] {
+        &self.bandwidth_buckets
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// How wide the buckets of the response are and the time zone they are aligned in, as resolved from the request.
+    pub fn get_bucket_info(&self) -> Option<&BucketInfoDTO> {
+        self.bucket_info.as_ref()
     }
 }
 
@@ -104,13 +57,17 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::bucketing::bucket_info::BucketInfoDTO;
     use crate::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
     use crate::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+    use crate::api::time_range::time_unit::TimeUnit;
 
 
     #[test]
     fn reader_correctly_read_encoded_graph_edge() {
-        const FIRST_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const FIRST_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const FIRST_TOTAL_BYTES: i64 = i64::MAX;
 
         let first_bandwidth_bucket = BandwidthBucketDTO::new(
@@ -118,7 +75,7 @@ mod tests {
             FIRST_TOTAL_BYTES
         );
 
-        const SECOND_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const SECOND_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const SECOND_TOTAL_BYTES: i64 = i64::MAX;
 
         let second_bandwidth_bucket = BandwidthBucketDTO::new(
@@ -146,7 +103,7 @@ mod tests {
         let elements = binary_user_reader.read_all_elements().unwrap();
         assert_eq!(elements.len(), bandwidth_buckets.len());
         for (element, bandwidth_bucket_core) in elements.iter().zip(bandwidth_buckets.as_slice()) {
-            let encoded_endpoint = BandwidthBucketDTO::decode(element.as_blob().unwrap());
+            let encoded_endpoint = BandwidthBucketDTO::decode(&element.to_binary().unwrap());
             assert_eq!(encoded_endpoint, *bandwidth_bucket_core);
         }
         binary_user_reader.step_out().unwrap();
@@ -156,7 +113,7 @@ mod tests {
 
     #[test]
     fn endec_network_bandwidth() {
-        const FIRST_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const FIRST_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const FIRST_TOTAL_BYTES: i64 = i64::MAX;
 
         let first_bandwidth_bucket = BandwidthBucketDTO::new(
@@ -164,7 +121,7 @@ mod tests {
             FIRST_TOTAL_BYTES
         );
 
-        const SECOND_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const SECOND_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const SECOND_TOTAL_BYTES: i64 = i64::MAX;
 
         let second_bandwidth_bucket = BandwidthBucketDTO::new(
@@ -183,4 +140,13 @@ mod tests {
 
         assert_eq!(network_bandwidth, NetworkBandwidthDTO::decode(&network_bandwidth.encode()));
     }
+
+    #[test]
+    fn endec_bucketed_network_bandwidth() {
+        let bandwidth_buckets = vec![BandwidthBucketDTO::new(Timestamp::from_millis(1_700_000_000_000).unwrap(), 1024)];
+        let network_bandwidth = NetworkBandwidthDTO::new_bucketed(&bandwidth_buckets, BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, Some("Europe/Kyiv")));
+
+        assert_eq!(network_bandwidth.get_bucket_info().map(BucketInfoDTO::get_bucket_interval), Some(60_000));
+        assert_eq!(network_bandwidth, NetworkBandwidthDTO::decode(&network_bandwidth.encode()));
+    }
 }
\ No newline at end of file
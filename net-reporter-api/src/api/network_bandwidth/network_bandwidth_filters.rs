@@ -1,24 +1,23 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::element::reader::ElementReader;
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
-use ion_rs::StreamItem;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+use crate::api::endpoint_filter::endpoint_filters::EndpointFilters;
+use crate::api::filter::filter_expression::FilterExpressionDTO;
+use crate::api::filter::filter_field::FilterField;
 
 
 const DATA_TYPE: &str = "network_bandwidth_filters";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate_endpoints)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkBandwidthFiltersDTO {
+    #[ion(present_with = include_protocols_mode)]
     protocols: Vec<String>,
     include_protocols_mode: Option<bool>,
+    #[ion(present_with = include_endpoints_mode)]
     endpoints: Vec<String>,
     include_endpoints_mode: Option<bool>, 
 }
@@ -56,113 +55,13 @@ impl NetworkBandwidthFiltersDTO {
     }
 }
 
-impl Encoder for NetworkBandwidthFiltersDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("include_protocols_mode");
-        match self.include_protocols_mode {
-            Some(include_protocols_mode) => {
-                writer.write_bool(include_protocols_mode).unwrap();
-                writer.set_field_name("protocols");
-                writer.step_in(IonType::List).expect("Error while entering an ion list");
-                self.protocols.iter().for_each(|protocol| {
-                    writer.write_string(protocol).unwrap();
-                });
-                writer.step_out().unwrap();
-            },
-            None => writer.write_null(IonType::Bool).unwrap(),
-        };
-
-        writer.set_field_name("include_endpoints_mode");
-        match self.include_endpoints_mode {
-            Some(include_endpoints_mode) => {
-                writer.write_bool(include_endpoints_mode).unwrap();
-                writer.set_field_name("endpoints");
-                writer.step_in(IonType::List).expect("Error while entering an ion list");
-                self.endpoints.iter().for_each(|endpoint| {
-                    writer.write_string(endpoint).unwrap();
-                });
-                writer.step_out().unwrap();
-            },
-            None => writer.write_null(IonType::Bool).unwrap(),
-        }
-        
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkBandwidthFiltersDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-
-        let (include_protocols, protocols) = match binary_user_reader.current()  {
-            StreamItem::Value(_) => {
-                let include_protocols = binary_user_reader.read_bool().unwrap();
-                binary_user_reader.next().unwrap();
-                binary_user_reader.step_in().unwrap();
-                let protocols_elements = binary_user_reader.read_all_elements().unwrap();
-                let mut protocols = Vec::with_capacity(protocols_elements.len());
-                protocols_elements.iter().for_each(|element| {
-                    let protocol = element.as_string().unwrap();
-                    protocols.push(protocol.to_owned());
-                });
-
-                binary_user_reader.step_out().unwrap();
-
-                (Some(include_protocols), protocols)
-            },
-            _ => (None, vec![]),
-        };
-
-        binary_user_reader.next().unwrap();
-        let (include_endpoints, endpoints) = match binary_user_reader.current() {
-            StreamItem::Value(_) => {
-                let include_endpoints = binary_user_reader.read_bool().unwrap();
-                binary_user_reader.next().unwrap();
-                binary_user_reader.step_in().unwrap();
-                let endpoints_elements = binary_user_reader.read_all_elements().unwrap();
-                let mut endpoints = Vec::with_capacity(endpoints_elements.len());
-                endpoints_elements.iter().for_each(|element| {
-                    let endpoint = element.as_string().unwrap();
-                    endpoints.push(endpoint.to_owned());
-                });
-                
-                binary_user_reader.step_out().unwrap();
-                (Some(include_endpoints), endpoints)
-            },
-            _ => (None, vec![]),
-        };
-
-        NetworkBandwidthFiltersDTO::new(
-            protocols.as_slice(),
-            include_protocols,
-            endpoints.as_slice(),
-            include_endpoints
-        )
-    }
-}
-
-impl Typed for NetworkBandwidthFiltersDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+impl EndpointFilters for NetworkBandwidthFiltersDTO {
+    fn get_endpoints(&self) -> &[String] {
+        self.endpoints.as_slice()
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    fn is_include_endpoints_mode(&self) -> Option<bool> {
+        self.include_endpoints_mode
     }
 }
 
@@ -177,6 +76,15 @@ impl From<&NetworkBandwidthFiltersDTO> for NetworkBandwidthFiltersDTO {
     }
 }
 
+impl From<&NetworkBandwidthFiltersDTO> for FilterExpressionDTO {
+    fn from(filters: &NetworkBandwidthFiltersDTO) -> Self {
+        FilterExpressionDTO::from_conditions([
+            FilterExpressionDTO::from_list(FilterField::Protocol, &filters.protocols, filters.include_protocols_mode),
+            FilterExpressionDTO::from_endpoints(&filters.endpoints, filters.include_endpoints_mode),
+        ])
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,154 @@
+use std::io::Read;
+use std::io::Write;
+
+use ion_rs::IonResult;
+use ion_rs::IonType;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::ion_format::IonFormat;
+use net_codec_api::core::list_reader::ListReader;
+use net_codec_api::core::struct_writer::StructWriter;
+use net_codec_api::core::versioned_api::Versioned;
+
+use crate::api::bucketing::bucket_info::BucketInfoDTO;
+
+
+use super::bandwidth_bucket::BandwidthBucketDTO;
+use super::network_bandwidth::NetworkBandwidthDTO;
+
+
+/// Reads the buckets of an encoded [`NetworkBandwidthDTO`] one at a time,
+/// so that a bandwidth over a long time range can be handled without holding all of its buckets in memory.
+///
+/// The bucket info is returned by [`NetworkBandwidthReader::finish`] once the buckets are read.
+/// The reader stops after the first bucket it fails to decode.
+pub struct NetworkBandwidthReader<'a> {
+    bandwidth_buckets: ListReader<'a, BandwidthBucketDTO>,
+    bucket_info: Option<BucketInfoDTO>,
+}
+
+impl<'a> NetworkBandwidthReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::new(data)?)
+    }
+
+    pub fn from_reader<R: Read + 'a>(input: R) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::from_reader(input)?)
+    }
+
+    fn open(mut reader: DecodeReader<'a>) -> Result<Self, DecodeError> {
+        let mut bucket_info = None;
+        reader.seek_field("bandwidth_buckets", IonType::List, |reader, field| read_bucket_info(reader, field, &mut bucket_info))?;
+        let bandwidth_buckets = ListReader::new(reader)?;
+
+        Ok(NetworkBandwidthReader { bandwidth_buckets, bucket_info })
+    }
+
+    /// Skips the buckets which were not read and returns how wide they are and the time zone they are aligned in.
+    pub fn finish(self) -> Result<Option<BucketInfoDTO>, DecodeError> {
+        let mut reader = self.bandwidth_buckets.finish()?;
+        let mut bucket_info = self.bucket_info;
+        while let Some(field) = reader.next_field_name()? {
+            read_bucket_info(&mut reader, &field, &mut bucket_info)?;
+        }
+        reader.step_out()?;
+
+        Ok(bucket_info)
+    }
+}
+
+impl Iterator for NetworkBandwidthReader<'_> {
+    type Item = Result<BandwidthBucketDTO, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bandwidth_buckets.next()
+    }
+}
+
+fn read_bucket_info(reader: &mut DecodeReader, field: &str, bucket_info: &mut Option<BucketInfoDTO>) -> Result<(), DecodeError> {
+    if field == "bucket_info" {
+        *bucket_info = reader.read_optional(IonType::Struct, |reader| reader.read_struct())?;
+    }
+    Ok(())
+}
+
+/// Writes a [`NetworkBandwidthDTO`] into `output` one bucket at a time,
+/// so that the buckets don't have to be collected first. The payload decodes as a `NetworkBandwidthDTO`.
+pub struct NetworkBandwidthWriter<W: Write> {
+    writer: StructWriter<W>,
+}
+
+impl<W: Write> NetworkBandwidthWriter<W> {
+    pub fn new(output: W, format: IonFormat) -> IonResult<Self> {
+        let mut writer = StructWriter::new(output, format, NetworkBandwidthDTO::get_version())?;
+        writer.begin_list("bandwidth_buckets")?;
+
+        Ok(NetworkBandwidthWriter { writer })
+    }
+
+    pub fn write(&mut self, bandwidth_bucket: &BandwidthBucketDTO) -> IonResult<()> {
+        self.writer.write_element(bandwidth_bucket)
+    }
+
+    /// Ends the buckets with how wide they are, unset when the buckets were not resolved from a bucketed request.
+    pub fn finish(mut self, bucket_info: Option<&BucketInfoDTO>) -> IonResult<()> {
+        self.writer.end_list()?;
+        self.writer.write_optional_struct("bucket_info", bucket_info)?;
+        self.writer.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::ion_format::IonFormat;
+    use net_codec_api::core::timestamp::Timestamp;
+
+    use crate::api::bucketing::bucket_info::BucketInfoDTO;
+    use crate::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
+    use crate::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+    use crate::api::network_bandwidth::network_bandwidth_stream::NetworkBandwidthReader;
+    use crate::api::network_bandwidth::network_bandwidth_stream::NetworkBandwidthWriter;
+    use crate::api::time_range::time_unit::TimeUnit;
+
+
+    fn get_bandwidth_bucket(minute: i64) -> BandwidthBucketDTO {
+        BandwidthBucketDTO::new(Timestamp::from_secs(1_700_000_000 + minute * 60).unwrap(), minute * 1024)
+    }
+
+    #[test]
+    fn streamed_buckets_decode_as_the_dto() {
+        let bandwidth_buckets: Vec<BandwidthBucketDTO> = (0..3).map(get_bandwidth_bucket).collect();
+        let bucket_info = BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, Some("Europe/Kyiv"));
+
+        for format in [IonFormat::Binary, IonFormat::Text] {
+            let mut output = Vec::new();
+            let mut writer = NetworkBandwidthWriter::new(&mut output, format).unwrap();
+            bandwidth_buckets.iter().for_each(|bandwidth_bucket| writer.write(bandwidth_bucket).unwrap());
+            writer.finish(Some(&bucket_info)).unwrap();
+
+            let mut reader = NetworkBandwidthReader::from_reader(output.as_slice()).unwrap();
+            assert_eq!(reader.by_ref().collect::<Result<Vec<_>, _>>(), Ok(bandwidth_buckets.clone()));
+            assert_eq!(reader.finish(), Ok(Some(bucket_info.clone())));
+        }
+
+        let mut output = Vec::new();
+        let writer = NetworkBandwidthWriter::new(&mut output, IonFormat::Binary).unwrap();
+        writer.finish(None).unwrap();
+        assert_eq!(NetworkBandwidthDTO::decode(&output), NetworkBandwidthDTO::new(&[]));
+    }
+
+    #[test]
+    fn bucket_info_is_read_even_if_buckets_are_left() {
+        let bucket_info = BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, None);
+        let data = NetworkBandwidthDTO::new_bucketed(&[get_bandwidth_bucket(0), get_bandwidth_bucket(1)], bucket_info.clone()).encode();
+        let mut reader = NetworkBandwidthReader::new(&data).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(get_bandwidth_bucket(0))));
+        assert_eq!(reader.finish(), Ok(Some(bucket_info)));
+    }
+}
\ No newline at end of file
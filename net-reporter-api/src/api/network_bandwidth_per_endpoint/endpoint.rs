@@ -1,20 +1,14 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-
-use ion_rs::ReaderBuilder;
-
 use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+
+use net_codec_derive::IonDto;
 
 
 const DATA_TYPE: &str = "endpoint";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EndpointDTO {
     id: String,
     total_bytes_received: i64,
@@ -50,64 +44,6 @@ impl EndpointDTO {
     }
 }
 
-impl Encoder for EndpointDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("id");
-        writer.write_string(self.id.as_str()).unwrap();
-
-        writer.set_field_name("total_bytes_received");
-        writer.write_i64(self.total_bytes_received).unwrap();
-
-        writer.set_field_name("total_bytes_sent");
-        writer.write_i64(self.total_bytes_sent).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for EndpointDTO {
-    fn decode(data: &[u8]) -> Self where Self: Sized  {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let id = binary_user_reader.read_str().unwrap().to_string();
-
-        binary_user_reader.next().unwrap();
-        let total_bytes_received = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let total_bytes_sent = binary_user_reader.read_i64().unwrap();
-
-        EndpointDTO::m_new(
-            id,
-            total_bytes_received,
-            total_bytes_sent,
-        )
-    }
-}
-
-impl Typed for EndpointDTO {
-    fn get_data_type() -> &'static str where Self: Sized {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
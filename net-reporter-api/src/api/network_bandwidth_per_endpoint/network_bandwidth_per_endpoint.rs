@@ -1,94 +1,41 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-
-use ion_rs::ReaderBuilder;
+use net_core_api::core::api::API;
 
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use crate::api::network_bandwidth_per_endpoint::endpoint::EndpointDTO;
+use crate::api::pagination::page_info::PageInfoDTO;
 
 
-const DATA_TYPE: &str = "bandwidth-per-endpoint";
+const DATA_TYPE: &str = "network_bandwidth_per_endpoint";
+const LEGACY_DATA_TYPES: &[&str] = &["bandwidth-per-endpoint"];
+const VERSION: u32 = 3;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, aliases = LEGACY_DATA_TYPES, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkBandwidthPerEndpointDTO {
     endpoints: Vec<EndpointDTO>,
+    page: Option<PageInfoDTO>,
 }
 impl API for  NetworkBandwidthPerEndpointDTO { }
 
 impl NetworkBandwidthPerEndpointDTO {
     pub fn new(endpoints: &[EndpointDTO]) -> Self {
-        Self { endpoints: endpoints.to_vec() }
+        Self { endpoints: endpoints.to_vec(), page: None }
     }
 
-    fn m_new(endpoints: Vec<EndpointDTO>) -> Self {
-        Self { endpoints }
+    pub fn new_page(endpoints: &[EndpointDTO], page: PageInfoDTO) -> Self {
+        Self { endpoints: endpoints.to_vec(), page: Some(page) }
     }
 
     pub fn get_endpoints(&self) -> &[EndpointDTO] {
         self.endpoints.as_slice()
     }
-}
-
-impl Encoder for NetworkBandwidthPerEndpointDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("endpoints");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        self.endpoints.iter().for_each(|endpoint| {
-            let data = endpoint.encode();
-            writer.write_blob(data.as_slice()).unwrap();
-        });
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkBandwidthPerEndpointDTO {
-    fn decode(data: &[u8]) -> Self where Self: Sized {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let endpoint_elements = binary_user_reader.read_all_elements().unwrap();
-        let mut endpoints = Vec::with_capacity(endpoint_elements.len());
-        endpoint_elements.iter().for_each(|element| {
-            let data = element.as_blob().unwrap();
-            let endpoint = EndpointDTO::decode(data);
-            endpoints.push(endpoint);
-        });
-
-        Self::m_new(endpoints)
-    }
-}
-
-impl Typed for NetworkBandwidthPerEndpointDTO {
-    fn get_data_type() -> &'static str where Self: Sized {
-        DATA_TYPE
-    }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// The page the response holds, unset when the request did not ask for one.
+    pub fn get_page(&self) -> Option<&PageInfoDTO> {
+        self.page.as_ref()
     }
 }
 
@@ -142,7 +89,7 @@ mod tests {
         let elements = binary_user_reader.read_all_elements().unwrap();
         assert_eq!(elements.len(), endpoints.len());
         for (element, endpoint_core) in elements.iter().zip(endpoints.as_slice()) {
-            let encoded_endpoint = EndpointDTO::decode(element.as_blob().unwrap());
+            let encoded_endpoint = EndpointDTO::decode(&element.to_binary().unwrap());
             assert_eq!(encoded_endpoint, *endpoint_core);
         }
         binary_user_reader.step_out().unwrap();
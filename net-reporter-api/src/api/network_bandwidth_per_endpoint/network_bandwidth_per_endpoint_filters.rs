@@ -1,23 +1,23 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::element::reader::ElementReader;
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
+
+use crate::api::endpoint_filter::endpoint_filters::EndpointFilters;
+use crate::api::filter::filter_expression::FilterExpressionDTO;
+use crate::api::filter::filter_field::FilterField;
 
-use ion_rs::StreamItem;
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 const DATA_TYPE: &str = "network_bandwidth_per_endpoint_filters";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = Self::validate_endpoints)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkBandwidthPerEndpointFiltersDTO {
+    #[ion(present_with = include_protocols_mode)]
     protocols: Vec<String>,
     include_protocols_mode: Option<bool>,
+    #[ion(present_with = include_endpoints_mode)]
     endpoints: Vec<String>,
     include_endpoints_mode: Option<bool>,
     bytes_lower_bound: Option<i64>,
@@ -69,142 +69,27 @@ impl NetworkBandwidthPerEndpointFiltersDTO {
     }
 }
 
-impl Encoder for NetworkBandwidthPerEndpointFiltersDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("include_protocols_mode");
-        match self.include_protocols_mode {
-            Some(include_protocols_mode) => {
-                writer.write_bool(include_protocols_mode).unwrap();
-                writer.set_field_name("protocols");
-                writer.step_in(IonType::List).expect("Error while entering an ion list");
-                self.protocols.iter().for_each(|protocol| {
-                    writer.write_string(protocol).unwrap();
-                });
-                writer.step_out().unwrap();
-            },
-            None => writer.write_null(IonType::Bool).unwrap(),
-        };
-
-        writer.set_field_name("include_endpoints_mode");
-        match self.include_endpoints_mode {
-            Some(include_endpoints_mode) => {
-                writer.write_bool(include_endpoints_mode).unwrap();
-                writer.set_field_name("endpoints");
-                writer.step_in(IonType::List).expect("Error while entering an ion list");
-                self.endpoints.iter().for_each(|endpoint| {
-                    writer.write_string(endpoint).unwrap();
-                });
-                writer.step_out().unwrap();
-            },
-            None => writer.write_null(IonType::Bool).unwrap(),
-        }
-
-        writer.set_field_name("bytes_lower_bound");
-        match self.bytes_lower_bound {
-            Some(bytes_lower_bound) => writer.write_i64(bytes_lower_bound).unwrap(),
-            None => writer.write_null(IonType::Int).unwrap(),
-        }
-
-        writer.set_field_name("bytes_upper_bound");
-        match self.bytes_upper_bound {
-            Some(bytes_upper_bound) => writer.write_i64(bytes_upper_bound).unwrap(),
-            None => writer.write_null(IonType::Int).unwrap(),
-        }
-        
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
+impl EndpointFilters for NetworkBandwidthPerEndpointFiltersDTO {
+    fn get_endpoints(&self) -> &[String] {
+        self.endpoints.as_slice()
     }
-}
-
-impl Decoder for NetworkBandwidthPerEndpointFiltersDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
 
-        binary_user_reader.next().unwrap();
-
-        let (include_protocols, protocols) = match binary_user_reader.current()  {
-            StreamItem::Value(_) => {
-                let include_protocols = binary_user_reader.read_bool().unwrap();
-                binary_user_reader.next().unwrap();
-                binary_user_reader.step_in().unwrap();
-                let protocols_elements = binary_user_reader.read_all_elements().unwrap();
-                let mut protocols = Vec::with_capacity(protocols_elements.len());
-                protocols_elements.iter().for_each(|element| {
-                    let protocol = element.as_string().unwrap();
-                    protocols.push(protocol.to_owned());
-                });
-
-                binary_user_reader.step_out().unwrap();
-
-                (Some(include_protocols), protocols)
-            },
-            _ => (None, vec![]),
-        };
-
-        binary_user_reader.next().unwrap();
-        let (include_endpoints, endpoints) = match binary_user_reader.current() {
-            StreamItem::Value(_) => {
-                let include_endpoints = binary_user_reader.read_bool().unwrap();
-                binary_user_reader.next().unwrap();
-                binary_user_reader.step_in().unwrap();
-                let endpoints_elements = binary_user_reader.read_all_elements().unwrap();
-                let mut endpoints = Vec::with_capacity(endpoints_elements.len());
-                endpoints_elements.iter().for_each(|element| {
-                    let endpoint = element.as_string().unwrap();
-                    endpoints.push(endpoint.to_owned());
-                });
-                
-                binary_user_reader.step_out().unwrap();
-                (Some(include_endpoints), endpoints)
-            },
-            _ => (None, vec![]),
-        };
-
-        binary_user_reader.next().unwrap();
-        let bytes_lower_bound = match binary_user_reader.current() {
-            StreamItem::Value(_) => Some(binary_user_reader.read_i64().unwrap()),
-            _ => None,
-        };
-
-        binary_user_reader.next().unwrap();
-        let bytes_upper_bound = match binary_user_reader.current() {
-            StreamItem::Value(_) => Some(binary_user_reader.read_i64().unwrap()),
-            _ => None,
-        };
-
-        NetworkBandwidthPerEndpointFiltersDTO::new(
-            protocols.as_slice(),
-            include_protocols,
-            endpoints.as_slice(),
-            include_endpoints,
-            bytes_lower_bound,
-            bytes_upper_bound,
-        )
+    fn is_include_endpoints_mode(&self) -> Option<bool> {
+        self.include_endpoints_mode
     }
 }
 
-impl Typed for NetworkBandwidthPerEndpointFiltersDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+impl From<&NetworkBandwidthPerEndpointFiltersDTO> for FilterExpressionDTO {
+    fn from(filters: &NetworkBandwidthPerEndpointFiltersDTO) -> Self {
+        FilterExpressionDTO::from_conditions([
+            FilterExpressionDTO::from_list(FilterField::Protocol, &filters.protocols, filters.include_protocols_mode),
+            FilterExpressionDTO::from_endpoints(&filters.endpoints, filters.include_endpoints_mode),
+            FilterExpressionDTO::from_bounds(FilterField::Bytes, filters.bytes_lower_bound, filters.bytes_upper_bound),
+        ])
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use ion_rs::element::reader::ElementReader;
@@ -398,4 +283,26 @@ mod tests {
         );
         assert_eq!(network_bandwidth_filters, NetworkBandwidthPerEndpointFiltersDTO::decode(&network_bandwidth_filters.encode()));
     }
+
+    #[test]
+    fn decode_nbpe_filters_in_any_order() {
+        const NBPE_FILTERS: &str = r#"{
+            bytes_upper_bound: 1000,
+            endpoints: ["0.0.0.0", "1.1.1.1"],
+            agent_id: "SOME_AGENT_ID",
+            include_endpoints_mode: true,
+            bytes_lower_bound: null.int,
+            include_protocols_mode: null.bool,
+        }"#;
+
+        let network_bandwidth_filters = NetworkBandwidthPerEndpointFiltersDTO::new(
+            &[],
+            None,
+            &["0.0.0.0".to_string(), "1.1.1.1".to_string()],
+            Some(true),
+            None,
+            Some(1000),
+        );
+        assert_eq!(network_bandwidth_filters, NetworkBandwidthPerEndpointFiltersDTO::decode(NBPE_FILTERS.as_bytes()));
+    }
 }
\ No newline at end of file
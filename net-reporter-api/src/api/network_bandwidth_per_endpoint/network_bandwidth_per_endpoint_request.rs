@@ -1,114 +1,33 @@
-use ion_rs;
+use crate::api::time_range::request_filters::RequestFilters;
+use crate::api::time_range::time_range_request::TimeRangeRequestDTO;
+use crate::api::time_range::time_unit::TimeUnit;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-
-use ion_rs::ReaderBuilder;
-
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::network_bandwidth_per_endpoint_filters::NetworkBandwidthPerEndpointFiltersDTO;
+use super::network_bandwidth_per_endpoint::NetworkBandwidthPerEndpointDTO;
 
 
-const DATA_TYPE: &str = "bandwidth-per-endpoint-request";
+const DATA_TYPE: &str = "network_bandwidth_per_endpoint_request";
+const LEGACY_DATA_TYPES: &[&str] = &["bandwidth-per-endpoint-request"];
+const VERSION: u32 = 5;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct NetworkBandwidthPerEndpointRequestDTO {
-    start_date_time: i64,
-    end_date_time: i64,
-    filters: NetworkBandwidthPerEndpointFiltersDTO,
-}
-impl API for NetworkBandwidthPerEndpointRequestDTO { }
-
-impl NetworkBandwidthPerEndpointRequestDTO {
-    pub fn new(start_date_time: i64, end_date_time: i64, filters: NetworkBandwidthPerEndpointFiltersDTO) -> Self {
-        NetworkBandwidthPerEndpointRequestDTO {
-            start_date_time,
-            end_date_time,
-            filters,
-        }
-    }
-    pub fn get_start_date_time(&self) -> i64 {
-        self.start_date_time
-    }
+impl RequestFilters for NetworkBandwidthPerEndpointFiltersDTO {
+    const REQUEST_DATA_TYPE: &'static str = DATA_TYPE;
+    const REQUEST_DATA_TYPE_ALIASES: &'static [&'static str] = LEGACY_DATA_TYPES;
+    const REQUEST_VERSION: u32 = VERSION;
+    const TIME_UNIT: TimeUnit = TimeUnit::Milliseconds;
+    const PAGED: bool = true;
+    const SORT_KEYS: &'static [&'static str] = &["id", "total_bytes_received", "total_bytes_sent"];
 
-    pub fn get_end_date_time(&self) -> i64 {
-        self.end_date_time
-    }
-
-    pub fn get_filters(&self) -> &NetworkBandwidthPerEndpointFiltersDTO {
-        &self.filters
-    }
+    type Response = NetworkBandwidthPerEndpointDTO;
 }
 
-impl Encoder for NetworkBandwidthPerEndpointRequestDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("start_date_time");
-        writer.write_i64(self.start_date_time).unwrap();
-
-        writer.set_field_name("end_date_time");
-        writer.write_i64(self.end_date_time).unwrap();
-
-        writer.set_field_name("filters");
-        writer.write_blob(self.filters.encode()).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkBandwidthPerEndpointRequestDTO {
-    fn decode(data: &[u8]) -> Self where Self: Sized {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let start_date_time = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let end_date_time = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let filters = NetworkBandwidthPerEndpointFiltersDTO::decode(
-            binary_user_reader.read_blob().unwrap().as_slice()
-        );
-
-        NetworkBandwidthPerEndpointRequestDTO::new(
-            start_date_time,
-            end_date_time,
-            filters
-        )
-    }
-}
-
-impl Typed for NetworkBandwidthPerEndpointRequestDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
+pub type NetworkBandwidthPerEndpointRequestDTO = TimeRangeRequestDTO<NetworkBandwidthPerEndpointFiltersDTO>;
 
 
 #[cfg(test)]
 mod tests {
+    use ion_rs::element::Element;
     use ion_rs::IonType;
     use ion_rs::IonReader;
     use ion_rs::ReaderBuilder;
@@ -118,6 +37,8 @@ mod tests {
     use net_core_api::core::decoder_api::Decoder;
     use net_core_api::core::typed_api::Typed;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_filters::NetworkBandwidthPerEndpointFiltersDTO;
     use crate::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
 
@@ -141,8 +62,8 @@ mod tests {
 
     #[test]
     fn reader_correctly_read_encoded_bandwidth_per_endpoint_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let bandwidth_per_endpoint_request = NetworkBandwidthPerEndpointRequestDTO::new(
             START_DATE_TIME,
@@ -155,23 +76,25 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("start_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(START_DATE_TIME, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(START_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("end_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(END_DATE_TIME,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(END_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
-        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         assert_eq!("filters", binary_user_reader.field_name().unwrap());
-        assert_eq!(get_test_filters(),  NetworkBandwidthPerEndpointFiltersDTO::decode(binary_user_reader.read_blob().unwrap().as_slice()));
+        let request = Element::read_one(bandwidth_per_endpoint_request.encode()).unwrap();
+        let filters = request.as_struct().unwrap().get("filters").unwrap();
+        assert_eq!(get_test_filters(), NetworkBandwidthPerEndpointFiltersDTO::decode(&filters.to_binary().unwrap()));
     }
 
     #[test]
     fn endec_bandwidth_per_endpoint_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let bandwidth_per_endpoint_request = NetworkBandwidthPerEndpointRequestDTO::new(
             START_DATE_TIME,
@@ -183,8 +106,8 @@ mod tests {
 
     #[test]
     fn test_getting_data_types() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let bandwidth_per_endpoint_request = NetworkBandwidthPerEndpointRequestDTO::new(
             START_DATE_TIME,
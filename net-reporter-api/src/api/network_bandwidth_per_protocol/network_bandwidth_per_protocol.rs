@@ -1,24 +1,17 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-
-use ion_rs::ReaderBuilder;
+use net_core_api::core::api::API;
 
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::protocol::ProtocolDTO;
 
 
 const DATA_TYPE: &str = "network_bandwidth_per_protocol";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkBandwidthPerProtocolDTO {
     protocols: Vec<ProtocolDTO>,
 }
@@ -34,62 +27,6 @@ impl NetworkBandwidthPerProtocolDTO {
     }
 }
 
-impl Encoder for NetworkBandwidthPerProtocolDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("protocols");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        self.protocols.iter().for_each(|protocol| {
-            let data = protocol.encode();
-            writer.write_blob(data.as_slice()).unwrap();
-        });
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkBandwidthPerProtocolDTO {
-    fn decode(data: &[u8]) -> Self where Self: Sized {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let protocol_elements = binary_user_reader.read_all_elements().unwrap();
-        let mut protocols = Vec::with_capacity(protocol_elements.len());
-        protocol_elements.iter().for_each(|protocol| {
-            let data = protocol.as_blob().unwrap();
-            let protocol = ProtocolDTO::decode(data);
-            protocols.push(protocol);
-        });
-
-        Self::new(
-            &protocols
-        )
-    }
-}
-
-impl Typed for NetworkBandwidthPerProtocolDTO {
-    fn get_data_type() -> &'static str where Self: Sized {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -132,7 +69,7 @@ mod tests {
         let elements = binary_user_reader.read_all_elements().unwrap();
         assert_eq!(elements.len(), protocols.len());
         for (element, protocol_core) in elements.iter().zip(protocols.as_slice()) {
-            let encoded_protocol = ProtocolDTO::decode(element.as_blob().unwrap());
+            let encoded_protocol = ProtocolDTO::decode(&element.to_binary().unwrap());
             assert_eq!(encoded_protocol, *protocol_core);
         }
         binary_user_reader.step_out().unwrap();
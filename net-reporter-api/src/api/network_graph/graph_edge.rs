@@ -1,20 +1,14 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-
-use ion_rs::ReaderBuilder;
-
 use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+
+use net_codec_derive::IonDto;
 
 
 const DATA_TYPE: &str = "graph_edge";
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphEdgeDTO {
     src_id: String,
     dst_id: String,
@@ -44,68 +38,6 @@ impl GraphEdgeDTO {
     }
 }
 
-impl Encoder for GraphEdgeDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("src_id");
-        writer.write_string(&self.src_id).unwrap();
-
-        writer.set_field_name("dst_id");
-        writer.write_string(&self.dst_id).unwrap();
-
-        writer.set_field_name("value");
-        writer.write_i64(self.value).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for GraphEdgeDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let src_id = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let dst_id = binding.text();
-
-        binary_user_reader.next().unwrap();
-        let value = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        GraphEdgeDTO::new(
-            src_id,
-            dst_id,
-            value,
-        )
-    }
-}
-
-impl Typed for GraphEdgeDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -4,3 +4,4 @@ pub mod graph_node;
 pub mod network_graph;
 pub mod network_graph_request;
 pub mod network_graph_filters;
+pub mod network_graph_stream;
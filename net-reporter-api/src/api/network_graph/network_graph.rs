@@ -1,24 +1,18 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::graph_edge::GraphEdgeDTO;
 use super::graph_node::GraphNodeDTO;
 
 
 const DATA_TYPE: &str = "network_graph";
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkGraphDTO {
     graph_nodes: Vec<GraphNodeDTO>,
     graph_edges: Vec<GraphEdgeDTO>,
@@ -42,80 +36,6 @@ impl NetworkGraphDTO {
     }
 }
 
-impl Encoder for NetworkGraphDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("graph_nodes");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for graph_node in &self.graph_nodes {
-            writer.write_blob(graph_node.encode()).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.set_field_name("graph_edges");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for graph_edge in &self.graph_edges {
-            writer.write_blob(graph_edge.encode()).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkGraphDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut graph_nodes = Vec::<GraphNodeDTO>::with_capacity(elements.capacity());
-        for element in elements {
-            graph_nodes.push(GraphNodeDTO::decode(element.as_blob().unwrap()));
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut graph_edges = Vec::<GraphEdgeDTO>::with_capacity(elements.capacity());
-        for element in elements {
-            graph_edges.push(GraphEdgeDTO::decode(element.as_blob().unwrap()));
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        NetworkGraphDTO {
-            graph_nodes,
-            graph_edges,
-        }
-    }
-}
-
-impl Typed for NetworkGraphDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -164,7 +84,7 @@ mod tests {
         binary_user_reader.step_in().unwrap();
 
         let endeced_graph_nodes: Vec<GraphNodeDTO> = binary_user_reader.read_all_elements().unwrap().iter().map(|element| {
-            GraphNodeDTO::decode(element.as_blob().unwrap())
+            GraphNodeDTO::decode(&element.to_binary().unwrap())
         }).collect();
 
         assert_eq!(endeced_graph_nodes, graph_nodes);
@@ -177,7 +97,7 @@ mod tests {
         binary_user_reader.step_in().unwrap();
 
         let encoded_graph_edges: Vec<GraphEdgeDTO> = binary_user_reader.read_all_elements().unwrap().iter().map(|element| {
-            GraphEdgeDTO::decode(element.as_blob().unwrap())
+            GraphEdgeDTO::decode(&element.to_binary().unwrap())
         }).collect();
 
         assert_eq!(encoded_graph_edges, graph_edges);
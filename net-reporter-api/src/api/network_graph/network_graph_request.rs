@@ -1,114 +1,31 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
-use ion_rs::ReaderBuilder;
-
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+use crate::api::time_range::request_filters::RequestFilters;
+use crate::api::time_range::time_range_request::TimeRangeRequestDTO;
+use crate::api::time_range::time_unit::TimeUnit;
 
 use super::network_graph_filters::NetworkGraphFiltersDTO;
+use super::network_graph::NetworkGraphDTO;
 
 
 const DATA_TYPE: &str = "network_graph_request";
+const VERSION: u32 = 5;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct NetworkGraphRequestDTO {
-    start_date_time: i64,
-    end_date_time: i64,
-    filters: NetworkGraphFiltersDTO,
-}
-impl API for NetworkGraphRequestDTO { }
-
-impl NetworkGraphRequestDTO {
-    pub fn new(start_date_time: i64, end_date_time: i64, filters: NetworkGraphFiltersDTO) -> Self {
-        NetworkGraphRequestDTO {
-            start_date_time,
-            end_date_time,
-            filters,
-        }
-    }
-
-    pub fn get_start_date_time(&self) -> i64 {
-        self.start_date_time
-    }
-
-    pub fn get_end_date_time(&self) -> i64 {
-        self.end_date_time
-    }
-
-    pub fn get_filters(&self) -> &NetworkGraphFiltersDTO {
-        &self.filters
-    }
-}
-
-impl Encoder for NetworkGraphRequestDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("start_date_time");
-        writer.write_i64(self.start_date_time).unwrap();
-
-        writer.set_field_name("end_date_time");
-        writer.write_i64(self.end_date_time).unwrap();
-
-        writer.set_field_name("filters");
-        writer.write_blob(self.filters.encode().as_slice()).unwrap();
+impl RequestFilters for NetworkGraphFiltersDTO {
+    const REQUEST_DATA_TYPE: &'static str = DATA_TYPE;
+    const REQUEST_VERSION: u32 = VERSION;
+    const TIME_UNIT: TimeUnit = TimeUnit::Milliseconds;
 
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkGraphRequestDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let start_date_time = binary_user_reader.read_i64().unwrap();
-        
-        binary_user_reader.next().unwrap();
-        let end_date_time = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let filters = NetworkGraphFiltersDTO::decode(binary_user_reader.read_blob().unwrap().as_slice());
-
-        NetworkGraphRequestDTO::new(
-            start_date_time,
-            end_date_time,
-            filters
-        )
-    }
+    type Response = NetworkGraphDTO;
 }
 
-impl Typed for NetworkGraphRequestDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
+pub type NetworkGraphRequestDTO = TimeRangeRequestDTO<NetworkGraphFiltersDTO>;
 
 
 #[cfg(test)]
 mod tests {
+    use ion_rs::element::Element;
     use ion_rs::IonType;
     use ion_rs::IonReader;
+    use ion_rs::IonWriter;
     use ion_rs::ReaderBuilder;
     use ion_rs::StreamItem;
 
@@ -116,6 +33,13 @@ mod tests {
     use net_core_api::core::decoder_api::Decoder;
     use net_core_api::core::typed_api::Typed;
 
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::format_encoder_api::FormatEncoder;
+    use net_codec_api::core::ion_format::IonFormat;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
     use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
 
@@ -139,8 +63,8 @@ mod tests {
 
     #[test]
     fn reader_correctly_read_encoded_ng_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_graph_request = NetworkGraphRequestDTO::new(
             START_DATE_TIME,
@@ -153,23 +77,25 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
 
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("start_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(START_DATE_TIME, binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(START_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
         
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("end_date_time", binary_user_reader.field_name().unwrap());
-        assert_eq!(END_DATE_TIME,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(END_DATE_TIME), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
-        assert_eq!(StreamItem::Value(IonType::Blob), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         assert_eq!("filters", binary_user_reader.field_name().unwrap());
-        assert_eq!(get_test_filters(), NetworkGraphFiltersDTO::decode(binary_user_reader.read_blob().unwrap().as_slice()));
+        let request = Element::read_one(network_graph_request.encode()).unwrap();
+        let filters = request.as_struct().unwrap().get("filters").unwrap();
+        assert_eq!(get_test_filters(), NetworkGraphFiltersDTO::decode(&filters.to_binary().unwrap()));
     }
 
     #[test]
     fn endec_ng_request() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_graph_request = NetworkGraphRequestDTO::new(
             START_DATE_TIME,
@@ -178,10 +104,61 @@ mod tests {
         );
         assert_eq!(network_graph_request, NetworkGraphRequestDTO::decode(&network_graph_request.encode()));
     }
+
+    #[test]
+    fn endec_ng_request_as_text() {
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
+
+        let network_graph_request = NetworkGraphRequestDTO::new(
+            START_DATE_TIME,
+            END_DATE_TIME,
+            get_test_filters(),
+        );
+
+        let text = String::from_utf8(network_graph_request.encode_as(IonFormat::Text)).unwrap();
+        assert!(text.starts_with("v5::{start_date_time: "));
+        assert_eq!(network_graph_request, NetworkGraphRequestDTO::decode(text.as_bytes()));
+
+        let pretty = String::from_utf8(network_graph_request.encode_as(IonFormat::Pretty)).unwrap();
+        assert!(pretty.lines().count() > 1);
+        assert_eq!(network_graph_request, NetworkGraphRequestDTO::decode(pretty.as_bytes()));
+    }
+
+    fn get_invalid_test_filters() -> Vec<u8> {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("bytes_lower_bound");
+        writer.write_string("100").unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+        writer.output().as_slice().into()
+    }
+
+    #[test]
+    fn try_decode_ng_request_with_invalid_filters() {
+        let mut writer = ion_rs::BinaryWriterBuilder::new().build(Vec::new()).unwrap();
+        writer.step_in(IonType::Struct).unwrap();
+        writer.set_field_name("start_date_time");
+        writer.write_i64(0).unwrap();
+        writer.set_field_name("end_date_time");
+        writer.write_i64(1).unwrap();
+        writer.set_field_name("filters");
+        writer.write_blob(get_invalid_test_filters()).unwrap();
+        writer.step_out().unwrap();
+        writer.flush().unwrap();
+
+        let error = NetworkGraphRequestDTO::try_decode(writer.output().as_slice()).unwrap_err();
+        assert_eq!(error.get_field(), "filters.bytes_lower_bound");
+        assert_eq!(error.get_expected_type(), Some(IonType::Int));
+        assert_eq!(error.get_kind(), &DecodeErrorKind::UnexpectedType(StreamItem::Value(IonType::String)));
+        assert!(error.get_offset().is_some());
+    }
+
     #[test]
     fn test_getting_data_types() {
-        const START_DATE_TIME: i64 = i64::MIN;
-        const END_DATE_TIME: i64 = i64::MAX;
+        const START_DATE_TIME: Timestamp = Timestamp::MIN;
+        const END_DATE_TIME: Timestamp = Timestamp::MAX;
 
         let network_graph_request = NetworkGraphRequestDTO::new(
             START_DATE_TIME,
@@ -0,0 +1,169 @@
+use std::io::Read;
+use std::io::Write;
+
+use ion_rs::IonResult;
+use ion_rs::IonType;
+
+use net_codec_api::core::decode_error::DecodeError;
+use net_codec_api::core::decode_reader::DecodeReader;
+use net_codec_api::core::ion_format::IonFormat;
+use net_codec_api::core::list_reader::ListReader;
+use net_codec_api::core::struct_writer::StructWriter;
+use net_codec_api::core::versioned_api::Versioned;
+
+
+use super::graph_edge::GraphEdgeDTO;
+use super::graph_node::GraphNodeDTO;
+use super::network_graph::NetworkGraphDTO;
+
+
+/// Reads the nodes of an encoded [`NetworkGraphDTO`] one at a time, and then its edges,
+/// so that a large graph can be handled without holding all of it in memory.
+///
+/// The edges have to follow the nodes, as they do in every graph `NetworkGraphDTO` writes.
+/// The reader stops after the first node it fails to decode.
+pub struct NetworkGraphReader<'a> {
+    graph_nodes: ListReader<'a, GraphNodeDTO>,
+}
+
+impl<'a> NetworkGraphReader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::new(data)?)
+    }
+
+    pub fn from_reader<R: Read + 'a>(input: R) -> Result<Self, DecodeError> {
+        Self::open(DecodeReader::from_reader(input)?)
+    }
+
+    fn open(mut reader: DecodeReader<'a>) -> Result<Self, DecodeError> {
+        reader.seek_field("graph_nodes", IonType::List, |_, _| Ok(()))?;
+        let graph_nodes = ListReader::new(reader)?;
+
+        Ok(NetworkGraphReader { graph_nodes })
+    }
+
+    /// Skips the nodes which were not read and moves on to the edges of the graph.
+    pub fn into_edges(self) -> Result<ListReader<'a, GraphEdgeDTO>, DecodeError> {
+        let mut reader = self.graph_nodes.finish()?;
+        reader.seek_field("graph_edges", IonType::List, |_, _| Ok(()))?;
+        ListReader::new(reader)
+    }
+}
+
+impl Iterator for NetworkGraphReader<'_> {
+    type Item = Result<GraphNodeDTO, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.graph_nodes.next()
+    }
+}
+
+/// Writes a [`NetworkGraphDTO`] into `output` one node at a time, and then one edge at a time,
+/// so that the graph doesn't have to be collected first. The payload decodes as a `NetworkGraphDTO`.
+pub struct NetworkGraphWriter<W: Write> {
+    writer: StructWriter<W>,
+}
+
+impl<W: Write> NetworkGraphWriter<W> {
+    pub fn new(output: W, format: IonFormat) -> IonResult<Self> {
+        let mut writer = StructWriter::new(output, format, NetworkGraphDTO::get_version())?;
+        writer.begin_list("graph_nodes")?;
+
+        Ok(NetworkGraphWriter { writer })
+    }
+
+    pub fn write_node(&mut self, graph_node: &GraphNodeDTO) -> IonResult<()> {
+        self.writer.write_element(graph_node)
+    }
+
+    /// Ends the nodes and moves on to the edges of the graph.
+    pub fn into_edges(mut self) -> IonResult<NetworkGraphEdgesWriter<W>> {
+        self.writer.end_list()?;
+        self.writer.begin_list("graph_edges")?;
+
+        Ok(NetworkGraphEdgesWriter { writer: self.writer })
+    }
+}
+
+/// Writes the edges of a graph whose nodes a [`NetworkGraphWriter`] has written.
+pub struct NetworkGraphEdgesWriter<W: Write> {
+    writer: StructWriter<W>,
+}
+
+impl<W: Write> NetworkGraphEdgesWriter<W> {
+    pub fn write_edge(&mut self, graph_edge: &GraphEdgeDTO) -> IonResult<()> {
+        self.writer.write_element(graph_edge)
+    }
+
+    pub fn finish(mut self) -> IonResult<()> {
+        self.writer.end_list()?;
+        self.writer.finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::ion_format::IonFormat;
+
+    use crate::api::network_graph::graph_edge::GraphEdgeDTO;
+    use crate::api::network_graph::graph_node::GraphNodeDTO;
+    use crate::api::network_graph::network_graph::NetworkGraphDTO;
+    use crate::api::network_graph::network_graph_stream::NetworkGraphReader;
+    use crate::api::network_graph::network_graph_stream::NetworkGraphWriter;
+
+
+    fn get_graph() -> NetworkGraphDTO {
+        NetworkGraphDTO::new(
+            &[GraphNodeDTO::new("0.0.0.0:0000"), GraphNodeDTO::new("0.0.0.0:5656")],
+            &[GraphEdgeDTO::new("0.0.0.0:0000", "0.0.0.0:5656", 123)],
+        )
+    }
+
+    #[test]
+    fn streamed_graph_decodes_as_the_dto() {
+        let graph = get_graph();
+
+        let mut output = Vec::new();
+        let mut writer = NetworkGraphWriter::new(&mut output, IonFormat::Binary).unwrap();
+        graph.get_graph_nodes().iter().for_each(|graph_node| writer.write_node(graph_node).unwrap());
+        let mut writer = writer.into_edges().unwrap();
+        graph.get_graph_edges().iter().for_each(|graph_edge| writer.write_edge(graph_edge).unwrap());
+        writer.finish().unwrap();
+
+        assert_eq!(NetworkGraphDTO::decode(&output), graph);
+
+        let mut reader = NetworkGraphReader::from_reader(output.as_slice()).unwrap();
+        assert_eq!(reader.next(), Some(Ok(graph.get_graph_nodes()[0].clone())));
+        let edges = reader.into_edges().unwrap();
+        assert_eq!(edges.collect::<Result<Vec<_>, _>>(), Ok(graph.get_graph_edges().to_vec()));
+    }
+
+    #[test]
+    fn nodes_and_edges_are_read_one_at_a_time() {
+        let graph = get_graph();
+        let data = graph.encode();
+        let mut reader = NetworkGraphReader::from_reader(data.as_slice()).unwrap();
+
+        assert_eq!(reader.next(), Some(Ok(graph.get_graph_nodes()[0].clone())));
+        assert_eq!(reader.next(), Some(Ok(graph.get_graph_nodes()[1].clone())));
+        assert_eq!(reader.next(), None);
+
+        let mut edges = reader.into_edges().unwrap();
+        assert_eq!(edges.next(), Some(Ok(graph.get_graph_edges()[0].clone())));
+        assert_eq!(edges.next(), None);
+    }
+
+    #[test]
+    fn unread_nodes_are_skipped() {
+        let graph = get_graph();
+        let data = graph.encode();
+        let reader = NetworkGraphReader::new(&data).unwrap();
+
+        let edges = reader.into_edges().unwrap();
+        assert_eq!(edges.collect::<Result<Vec<_>, _>>(), Ok(graph.get_graph_edges().to_vec()));
+    }
+}
\ No newline at end of file
@@ -1,20 +1,15 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
-const DATA_TYPE: &str = "filter-entry";
+const DATA_TYPE: &str = "filter_entry";
+const LEGACY_DATA_TYPES: &[&str] = &["filter-entry"];
+const VERSION: u32 = 1;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, aliases = LEGACY_DATA_TYPES, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FilterEntryDTO {
     endpoint: String,
     protocols: Vec<String>,
@@ -44,77 +39,6 @@ impl FilterEntryDTO {
     }
 }
 
-impl Encoder for FilterEntryDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("endpoint");
-        writer.write_string(&self.endpoint).unwrap();
-
-        writer.set_field_name("protocols");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for protocol in &self.protocols {
-            writer.write_string(protocol).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.set_field_name("total_bytes");
-        writer.write_i64(self.total_bytes).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for FilterEntryDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let binding = binary_user_reader.read_string().unwrap();
-        let endpoint = binding.text();
-        
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut protocols = Vec::<String>::with_capacity(elements.len());
-        for element in elements {
-            protocols.push(element.as_text().unwrap().to_string());
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let total_bytes = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        FilterEntryDTO::new(
-            endpoint,
-            protocols.as_slice(),
-            total_bytes,
-        )
-    }
-}
-
-impl Typed for FilterEntryDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
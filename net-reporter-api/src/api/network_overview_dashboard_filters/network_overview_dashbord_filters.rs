@@ -1,23 +1,18 @@
-use ion_rs;
-
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_core_api::core::api::API;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::filter_entry::FilterEntryDTO;
 
 
-const DATA_TYPE: &str = "network-overview-dashboard-filters";
+const DATA_TYPE: &str = "network_overview_dashboard_filters";
+const LEGACY_DATA_TYPES: &[&str] = &["network-overview-dashboard-filters"];
+const VERSION: u32 = 2;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, aliases = LEGACY_DATA_TYPES, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkOverviewDashboardFiltersDTO {
     entries: Vec<FilterEntryDTO>,
 }
@@ -35,62 +30,6 @@ impl NetworkOverviewDashboardFiltersDTO {
     }
 }
 
-impl Encoder for NetworkOverviewDashboardFiltersDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("entries");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        for entry in &self.entries {
-            writer.write_blob(entry.encode()).unwrap();
-        }
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for NetworkOverviewDashboardFiltersDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let elements = binary_user_reader.read_all_elements().unwrap();
-        let mut entries = Vec::<FilterEntryDTO>::with_capacity(elements.len());
-        for element in elements {
-            entries.push(FilterEntryDTO::decode(element.as_blob().unwrap()));
-        }
-        binary_user_reader.step_out().unwrap();
-
-        binary_user_reader.step_out().unwrap();
-
-        NetworkOverviewDashboardFiltersDTO::new(
-            entries.as_slice(),
-        )
-    }
-}
-
-impl Typed for NetworkOverviewDashboardFiltersDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -143,7 +82,7 @@ mod tests {
         binary_user_reader.step_in().unwrap();
 
         let endeced_entries: Vec<FilterEntryDTO> = binary_user_reader.read_all_elements().unwrap().iter().map(|element| {
-            FilterEntryDTO::decode(element.as_blob().unwrap())
+            FilterEntryDTO::decode(&element.to_binary().unwrap())
         }).collect();
 
         assert_eq!(filters.get_entries(), endeced_entries);
@@ -0,0 +1,4 @@
+pub mod page_error;
+pub mod page_info;
+pub mod page_request;
+pub mod sort_direction;
\ No newline at end of file
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Describes why a page requested or returned is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PageError {
+    /// The limit is not positive or exceeds the maximum limit.
+    InvalidLimit { limit: i64, max_limit: i64 },
+    /// The offset is negative.
+    NegativeOffset(i64),
+    /// Both an offset and a cursor are set, a page is located by one of them only.
+    CursorWithOffset,
+    /// The cursor is set but empty.
+    EmptyCursor,
+    /// The sort direction is neither `asc` nor `desc`.
+    UnknownSortDirection(String),
+    /// The sort direction is set without a sort key.
+    SortDirectionWithoutKey,
+    /// The chart cannot be sorted by the sort key.
+    UnknownSortKey { sort_key: String, sort_keys: &'static [&'static str] },
+    /// The total count is negative.
+    NegativeTotalCount(i64),
+    /// A page is requested from a chart which is not list-shaped.
+    NotPaged { data_type: &'static str },
+}
+
+impl fmt::Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PageError::InvalidLimit { limit, max_limit } => write!(f, "the limit has to be between 1 and {max_limit}, it is {limit}"),
+            PageError::NegativeOffset(offset) => write!(f, "the offset cannot be negative, it is {offset}"),
+            PageError::CursorWithOffset => write!(f, "a page is located by either a cursor or an offset, not both"),
+            PageError::EmptyCursor => write!(f, "the cursor cannot be empty"),
+            PageError::UnknownSortDirection(sort_direction) => write!(f, "`{sort_direction}` is not a sort direction, expected `asc` or `desc`"),
+            PageError::SortDirectionWithoutKey => write!(f, "the sort direction is set without a sort key"),
+            PageError::UnknownSortKey { sort_key, sort_keys } => write!(
+                f,
+                "`{sort_key}` is not a sort key, expected one of {}",
+                sort_keys.iter().map(|sort_key| format!("`{sort_key}`")).collect::<Vec<_>>().join(", "),
+            ),
+            PageError::NegativeTotalCount(total_count) => write!(f, "the total count cannot be negative, it is {total_count}"),
+            PageError::NotPaged { data_type } => write!(f, "`{data_type}` does not return a list, it cannot be paged"),
+        }
+    }
+}
+
+impl Error for PageError { }
\ No newline at end of file
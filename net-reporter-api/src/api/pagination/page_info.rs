@@ -0,0 +1,80 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::page_error::PageError;
+
+
+const DATA_TYPE: &str = "page_info";
+const VERSION: u32 = 1;
+
+/// Describes the page a list-shaped chart response holds: `total_count` items match the request overall
+/// and `next_cursor`, when set, requests the page following this one.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = PageInfoDTO::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageInfoDTO {
+    next_cursor: Option<String>,
+    total_count: i64,
+}
+impl API for PageInfoDTO { }
+
+impl PageInfoDTO {
+    pub fn new(next_cursor: Option<&str>, total_count: i64) -> Self {
+        PageInfoDTO {
+            next_cursor: next_cursor.map(|next_cursor| next_cursor.into()),
+            total_count,
+        }
+    }
+
+    pub fn get_next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+
+    pub fn get_total_count(&self) -> i64 {
+        self.total_count
+    }
+
+    /// Checks the next cursor, if any, is not empty and the total count is not negative. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), PageError> {
+        if self.next_cursor.as_deref() == Some("") {
+            return Err(PageError::EmptyCursor);
+        }
+
+        match self.total_count < 0 {
+            true => Err(PageError::NegativeTotalCount(self.total_count)),
+            false => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::pagination::page_error::PageError;
+    use crate::api::pagination::page_info::PageInfoDTO;
+
+    #[test]
+    fn endec_page_info() {
+        let page = PageInfoDTO::new(Some("b2Zmc2V0OjUw"), 1234);
+        assert_eq!(page, PageInfoDTO::decode(&page.encode()));
+
+        let page = PageInfoDTO::new(None, 0);
+        assert_eq!(page, PageInfoDTO::decode(&page.encode()));
+    }
+
+    #[test]
+    fn invalid_page_info_is_not_decoded() {
+        assert_eq!(PageInfoDTO::new(Some(""), 10).validate(), Err(PageError::EmptyCursor));
+
+        let error = PageInfoDTO::try_decode(&PageInfoDTO::new(None, -1).encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(PageError::NegativeTotalCount(-1).to_string()));
+    }
+}
\ No newline at end of file
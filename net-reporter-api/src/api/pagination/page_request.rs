@@ -0,0 +1,165 @@
+use net_core_api::core::api::API;
+
+use net_codec_derive::IonDto;
+
+
+use super::page_error::PageError;
+use super::sort_direction::SortDirection;
+
+
+const DATA_TYPE: &str = "page_request";
+const VERSION: u32 = 1;
+
+/// Largest amount of items a page can be requested with.
+pub const MAX_PAGE_LIMIT: i64 = 1000;
+
+/// The page of a list-shaped chart a request asks for: at most `limit` items, starting at `offset`
+/// or right after the page `cursor` was returned with, sorted by `sort_key` in `sort_direction`.
+/// The first page is requested with neither an offset nor a cursor.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION, validate = PageRequestDTO::validate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageRequestDTO {
+    limit: i64,
+    offset: Option<i64>,
+    cursor: Option<String>,
+    sort_key: Option<String>,
+    #[ion(as_string)]
+    sort_direction: Option<SortDirection>,
+}
+impl API for PageRequestDTO { }
+
+impl PageRequestDTO {
+    pub fn new(
+        limit: i64,
+        offset: Option<i64>,
+        cursor: Option<&str>,
+        sort_key: Option<&str>,
+        sort_direction: Option<SortDirection>,
+    ) -> Self {
+        PageRequestDTO {
+            limit,
+            offset,
+            cursor: cursor.map(|cursor| cursor.into()),
+            sort_key: sort_key.map(|sort_key| sort_key.into()),
+            sort_direction,
+        }
+    }
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+
+    pub fn get_offset(&self) -> Option<i64> {
+        self.offset
+    }
+
+    pub fn get_cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    pub fn get_sort_key(&self) -> Option<&str> {
+        self.sort_key.as_deref()
+    }
+
+    /// The direction the items are sorted in, ascending unless told otherwise.
+    pub fn get_sort_direction(&self) -> SortDirection {
+        self.sort_direction.unwrap_or_default()
+    }
+
+    /// Checks the limit is between 1 and [`MAX_PAGE_LIMIT`], the page is located by an offset or a cursor at most
+    /// and the sort direction, if any, comes with a sort key. Decoding runs it as well.
+    pub fn validate(&self) -> Result<(), PageError> {
+        if !(1..=MAX_PAGE_LIMIT).contains(&self.limit) {
+            return Err(PageError::InvalidLimit { limit: self.limit, max_limit: MAX_PAGE_LIMIT });
+        }
+
+        match (self.offset, self.cursor.as_deref()) {
+            (Some(_), Some(_)) => return Err(PageError::CursorWithOffset),
+            (Some(offset), None) if offset < 0 => return Err(PageError::NegativeOffset(offset)),
+            (None, Some("")) => return Err(PageError::EmptyCursor),
+            _ => { },
+        }
+
+        match (&self.sort_key, self.sort_direction) {
+            (None, Some(_)) => Err(PageError::SortDirectionWithoutKey),
+            _ => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::pagination::page_error::PageError;
+    use crate::api::pagination::page_request::MAX_PAGE_LIMIT;
+    use crate::api::pagination::page_request::PageRequestDTO;
+    use crate::api::pagination::sort_direction::SortDirection;
+
+    #[test]
+    fn endec_page_request() {
+        let page = PageRequestDTO::new(50, None, Some("b2Zmc2V0OjUw"), Some("requests_amount"), Some(SortDirection::Descending));
+        assert_eq!(page, PageRequestDTO::decode(&page.encode()));
+
+        let page = PageRequestDTO::new(50, Some(100), None, None, None);
+        assert_eq!(page, PageRequestDTO::decode(&page.encode()));
+    }
+
+    #[test]
+    fn sort_direction_defaults_to_ascending() {
+        assert_eq!(PageRequestDTO::new(50, None, None, Some("date"), None).get_sort_direction(), SortDirection::Ascending);
+        assert_eq!(
+            PageRequestDTO::new(50, None, None, Some("date"), Some(SortDirection::Descending)).get_sort_direction(),
+            SortDirection::Descending,
+        );
+    }
+
+    #[test]
+    fn limit_has_to_be_positive_and_bounded() {
+        assert_eq!(PageRequestDTO::new(1, None, None, None, None).validate(), Ok(()));
+        assert_eq!(PageRequestDTO::new(MAX_PAGE_LIMIT, None, None, None, None).validate(), Ok(()));
+        assert_eq!(
+            PageRequestDTO::new(0, None, None, None, None).validate(),
+            Err(PageError::InvalidLimit { limit: 0, max_limit: MAX_PAGE_LIMIT }),
+        );
+        assert_eq!(
+            PageRequestDTO::new(MAX_PAGE_LIMIT + 1, None, None, None, None).validate(),
+            Err(PageError::InvalidLimit { limit: MAX_PAGE_LIMIT + 1, max_limit: MAX_PAGE_LIMIT }),
+        );
+    }
+
+    #[test]
+    fn page_is_located_by_an_offset_or_a_cursor() {
+        assert_eq!(PageRequestDTO::new(50, Some(-1), None, None, None).validate(), Err(PageError::NegativeOffset(-1)));
+        assert_eq!(PageRequestDTO::new(50, Some(0), Some("cursor"), None, None).validate(), Err(PageError::CursorWithOffset));
+        assert_eq!(PageRequestDTO::new(50, None, Some(""), None, None).validate(), Err(PageError::EmptyCursor));
+    }
+
+    #[test]
+    fn sort_direction_requires_a_sort_key() {
+        assert_eq!(
+            PageRequestDTO::new(50, None, None, None, Some(SortDirection::Descending)).validate(),
+            Err(PageError::SortDirectionWithoutKey),
+        );
+    }
+
+    #[test]
+    fn invalid_page_request_is_not_decoded() {
+        let error = PageRequestDTO::try_decode(&PageRequestDTO::new(0, None, None, None, None).encode()).unwrap_err();
+        assert_eq!(
+            error.get_kind(),
+            &DecodeErrorKind::Invalid(PageError::InvalidLimit { limit: 0, max_limit: MAX_PAGE_LIMIT }.to_string()),
+        );
+
+        let error = PageRequestDTO::try_decode(
+            "v1::{limit: 50, sort_key: \"date\", sort_direction: \"down\"}".as_bytes(),
+        ).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(PageError::UnknownSortDirection("down".into()).to_string()));
+    }
+}
\ No newline at end of file
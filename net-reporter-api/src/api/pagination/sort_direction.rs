@@ -0,0 +1,64 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::page_error::PageError;
+
+
+/// Order the items of a page are sorted in, written as `asc` or `desc`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "asc"))]
+    Ascending,
+    #[cfg_attr(feature = "serde", serde(rename = "desc"))]
+    Descending,
+}
+
+impl SortDirection {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "asc" => Some(SortDirection::Ascending),
+            "desc" => Some(SortDirection::Descending),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for SortDirection {
+    type Err = PageError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        SortDirection::from_name(name).ok_or_else(|| PageError::UnknownSortDirection(name.into()))
+    }
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::api::pagination::page_error::PageError;
+    use crate::api::pagination::sort_direction::SortDirection;
+
+    #[test]
+    fn directions_are_parsed_from_their_name() {
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            assert_eq!(SortDirection::from_name(direction.get_name()), Some(direction));
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        assert_eq!(SortDirection::from_name("ascending"), None);
+        assert_eq!("ascending".parse::<SortDirection>(), Err(PageError::UnknownSortDirection("ascending".into())));
+    }
+}
\ No newline at end of file
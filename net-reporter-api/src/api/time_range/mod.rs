@@ -0,0 +1,5 @@
+pub mod request_filters;
+pub mod request_options_error;
+pub mod time_range_error;
+pub mod time_range_request;
+pub mod time_unit;
\ No newline at end of file
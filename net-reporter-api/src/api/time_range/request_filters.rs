@@ -0,0 +1,26 @@
+use net_core_api::core::api::API;
+use net_core_api::core::encoder_api::Encoder;
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::ion_struct_api::IonStruct;
+
+use super::time_unit::TimeUnit;
+
+
+/// Filters of a chart request, which tell how the [`TimeRangeRequestDTO`](super::time_range_request::TimeRangeRequestDTO)
+/// carrying them is tagged and versioned and which response the reporter answers it with.
+pub trait RequestFilters : IonStruct {
+    const REQUEST_DATA_TYPE: &'static str;
+    /// Former data types of the request, see [`Aliased`](net_codec_api::core::aliased_api::Aliased).
+    const REQUEST_DATA_TYPE_ALIASES: &'static [&'static str] = &[];
+    const REQUEST_VERSION: u32;
+    const TIME_UNIT: TimeUnit;
+    /// Whether the chart returns a list, which requests may ask a page of.
+    const PAGED: bool = false;
+    /// Keys the items of a paged chart can be sorted by, named after the fields of the items.
+    const SORT_KEYS: &'static [&'static str] = &[];
+    /// Whether the chart is a time series, which requests may tell how to bucket.
+    const BUCKETED: bool = false;
+
+    type Response : API + Encoder + IonStruct + Typed;
+}
\ No newline at end of file
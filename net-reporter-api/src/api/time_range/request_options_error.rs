@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::api::bucketing::bucket_error::BucketError;
+use crate::api::pagination::page_error::PageError;
+
+use super::time_range_error::TimeRangeError;
+
+
+/// Describes why the range, the page or the bucketing a chart request asks for is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RequestOptionsError {
+    TimeRange(TimeRangeError),
+    Page(PageError),
+    Bucket(BucketError),
+}
+
+impl fmt::Display for RequestOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestOptionsError::TimeRange(error) => write!(f, "{error}"),
+            RequestOptionsError::Page(error) => write!(f, "{error}"),
+            RequestOptionsError::Bucket(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for RequestOptionsError { }
+
+impl From<TimeRangeError> for RequestOptionsError {
+    fn from(error: TimeRangeError) -> Self {
+        RequestOptionsError::TimeRange(error)
+    }
+}
+
+impl From<PageError> for RequestOptionsError {
+    fn from(error: PageError) -> Self {
+        RequestOptionsError::Page(error)
+    }
+}
+
+impl From<BucketError> for RequestOptionsError {
+    fn from(error: BucketError) -> Self {
+        RequestOptionsError::Bucket(error)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use net_codec_api::core::timestamp::Timestamp;
+
+
+/// Describes why the time range of a request is invalid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TimeRangeError {
+    /// The range does not end after it starts.
+    Empty { start_date_time: Timestamp, end_date_time: Timestamp },
+    /// The range spans more than the maximum span allowed.
+    TooLong { span: Duration, max_span: Duration },
+}
+
+impl fmt::Display for TimeRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeRangeError::Empty { start_date_time, end_date_time } => write!(
+                f,
+                "the time range has to end after it starts, it starts at {start_date_time} and ends at {end_date_time}",
+            ),
+            TimeRangeError::TooLong { span, max_span } => write!(
+                f,
+                "the time range spans {span:?}, more than the maximum of {max_span:?}",
+            ),
+        }
+    }
+}
+
+impl Error for TimeRangeError { }
\ No newline at end of file
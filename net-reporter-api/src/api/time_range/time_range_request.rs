@@ -0,0 +1,395 @@
+use std::fmt::Debug;
+use std::time::Duration;
+
+use net_core_api::core::api::API;
+
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_codec_derive::IonDto;
+
+use crate::api::bucketing::bucket_error::BucketError;
+use crate::api::bucketing::bucket_info::BucketInfoDTO;
+use crate::api::bucketing::bucket_interval::BucketIntervalDTO;
+use crate::api::bucketing::time_zone::validate_time_zone;
+use crate::api::pagination::page_error::PageError;
+use crate::api::pagination::page_request::PageRequestDTO;
+use crate::core::query_api::Query;
+
+use super::request_filters::RequestFilters;
+use super::request_options_error::RequestOptionsError;
+use super::time_range_error::TimeRangeError;
+use super::time_unit::TimeUnit;
+
+
+/// A chart request: the data between `start_date_time` and `end_date_time` narrowed down by `filters`.
+/// Both ends are written as Ion timestamps; an older request holding ints in `F::TIME_UNIT` is still read. Each chart request, e.g. `NetworkGraphRequestDTO`, is an alias of it.
+/// The request of a list-shaped chart may ask for a single `page` of the response, the whole response is returned when no page is set.
+/// The request of a time-series chart may tell how wide the buckets are and the time zone they are aligned in, e.g. for daily buckets
+/// to start at local midnight. The reporter picks the interval when none is set and aligns buckets in UTC when no time zone is.
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(
+    data_type = F::REQUEST_DATA_TYPE,
+    aliases = F::REQUEST_DATA_TYPE_ALIASES,
+    version = F::REQUEST_VERSION,
+    validate = Self::validate_options,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRangeRequestDTO<F: RequestFilters> {
+    #[ion(legacy_unit = F::TIME_UNIT)]
+    start_date_time: Timestamp,
+    #[ion(legacy_unit = F::TIME_UNIT)]
+    end_date_time: Timestamp,
+    filters: F,
+    page: Option<PageRequestDTO>,
+    bucket_interval: Option<BucketIntervalDTO>,
+    time_zone: Option<String>,
+}
+impl<F: RequestFilters + Debug> API for TimeRangeRequestDTO<F> { }
+
+impl<F: RequestFilters + Debug> Query for TimeRangeRequestDTO<F> {
+    type Response = F::Response;
+}
+
+impl<F: RequestFilters> TimeRangeRequestDTO<F> {
+    pub fn new(start_date_time: Timestamp, end_date_time: Timestamp, filters: F) -> Self {
+        TimeRangeRequestDTO {
+            start_date_time,
+            end_date_time,
+            filters,
+            page: None,
+            bucket_interval: None,
+            time_zone: None,
+        }
+    }
+
+    pub fn new_page(start_date_time: Timestamp, end_date_time: Timestamp, filters: F, page: PageRequestDTO) -> Self {
+        TimeRangeRequestDTO {
+            page: Some(page),
+            ..TimeRangeRequestDTO::new(start_date_time, end_date_time, filters)
+        }
+    }
+
+    pub fn new_bucketed(
+        start_date_time: Timestamp,
+        end_date_time: Timestamp,
+        filters: F,
+        bucket_interval: Option<BucketIntervalDTO>,
+        time_zone: Option<&str>,
+    ) -> Self {
+        TimeRangeRequestDTO {
+            bucket_interval,
+            time_zone: time_zone.map(|time_zone| time_zone.into()),
+            ..TimeRangeRequestDTO::new(start_date_time, end_date_time, filters)
+        }
+    }
+
+    pub fn get_start_date_time(&self) -> Timestamp {
+        self.start_date_time
+    }
+
+    pub fn get_end_date_time(&self) -> Timestamp {
+        self.end_date_time
+    }
+
+    pub fn get_filters(&self) -> &F {
+        &self.filters
+    }
+
+    pub fn get_page(&self) -> Option<&PageRequestDTO> {
+        self.page.as_ref()
+    }
+
+    pub fn get_sort_keys(&self) -> &'static [&'static str] {
+        F::SORT_KEYS
+    }
+
+    pub fn get_bucket_interval(&self) -> Option<&BucketIntervalDTO> {
+        self.bucket_interval.as_ref()
+    }
+
+    pub fn get_time_zone(&self) -> Option<&str> {
+        self.time_zone.as_deref()
+    }
+
+    pub fn get_time_unit(&self) -> TimeUnit {
+        F::TIME_UNIT
+    }
+
+    /// Resolves the bucket interval over the range of the request, falling back to `default_interval`
+    /// when none is set, and describes the buckets for the response to echo in `F::TIME_UNIT`.
+    pub fn get_bucket_info(&self, default_interval: &BucketIntervalDTO) -> BucketInfoDTO {
+        let span = F::TIME_UNIT.to_amount(self.end_date_time.abs_diff(self.start_date_time));
+        let span = u64::try_from(span).unwrap_or(u64::MAX);
+        let bucket_interval = self.bucket_interval.as_ref().unwrap_or(default_interval);
+        BucketInfoDTO::new(bucket_interval.resolve(span, F::TIME_UNIT), F::TIME_UNIT, self.get_time_zone())
+    }
+
+    /// Checks the range ends after it starts and, when `max_span` is set, does not span more than it.
+    pub fn validate(&self, max_span: Option<Duration>) -> Result<(), TimeRangeError> {
+        validate_time_range(self.start_date_time, self.end_date_time, max_span)
+    }
+
+    /// Checks the range ends after it starts and the page and the bucketing, if any, are valid. Decoding runs it as well,
+    /// leaving the maximum span, which depends on the reporter, to `validate`.
+    pub fn validate_options(&self) -> Result<(), RequestOptionsError> {
+        validate_time_range(self.start_date_time, self.end_date_time, None)?;
+        self.validate_page()?;
+        self.validate_buckets()?;
+        Ok(())
+    }
+
+    /// Checks the page, if any, is asked of a paged chart, is valid and is sorted by one of `F::SORT_KEYS`.
+    pub fn validate_page(&self) -> Result<(), PageError> {
+        let Some(page) = &self.page else {
+            return Ok(());
+        };
+        if !F::PAGED {
+            return Err(PageError::NotPaged { data_type: F::REQUEST_DATA_TYPE });
+        }
+        page.validate()?;
+
+        match page.get_sort_key() {
+            Some(sort_key) if !F::SORT_KEYS.contains(&sort_key) => Err(PageError::UnknownSortKey {
+                sort_key: sort_key.into(),
+                sort_keys: F::SORT_KEYS,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the bucket interval and the time zone, if any, are asked of a time-series chart and are valid.
+    pub fn validate_buckets(&self) -> Result<(), BucketError> {
+        if self.bucket_interval.is_none() && self.time_zone.is_none() {
+            return Ok(());
+        }
+        if !F::BUCKETED {
+            return Err(BucketError::NotBucketed { data_type: F::REQUEST_DATA_TYPE });
+        }
+
+        if let Some(bucket_interval) = &self.bucket_interval {
+            bucket_interval.validate()?;
+        }
+        self.time_zone.as_deref().map_or(Ok(()), validate_time_zone)
+    }
+}
+
+pub fn validate_time_range(
+    start_date_time: Timestamp,
+    end_date_time: Timestamp,
+    max_span: Option<Duration>,
+) -> Result<(), TimeRangeError> {
+    if start_date_time >= end_date_time {
+        return Err(TimeRangeError::Empty { start_date_time, end_date_time });
+    }
+
+    let span = end_date_time.abs_diff(start_date_time);
+    match max_span {
+        Some(max_span) if span > max_span => Err(TimeRangeError::TooLong { span, max_span }),
+        _ => Ok(()),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::decoder_api::Decoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::aliased_api::Aliased;
+    use net_codec_api::core::decode_error::DecodeErrorKind;
+    use net_codec_api::core::timestamp::Timestamp;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+    use net_codec_api::core::versioned_api::Versioned;
+
+    use crate::api::bucketing::bucket_error::BucketError;
+    use crate::api::bucketing::bucket_info::BucketInfoDTO;
+    use crate::api::bucketing::bucket_interval::BucketIntervalDTO;
+    use crate::api::http_clients::http_clients_filters::HttpClientsFiltersDTO;
+    use crate::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+    use crate::api::network_bandwidth::network_bandwidth_filters::NetworkBandwidthFiltersDTO;
+    use crate::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+    use crate::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+    use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+    use crate::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
+    use crate::api::pagination::page_error::PageError;
+    use crate::api::pagination::page_request::PageRequestDTO;
+    use crate::api::pagination::sort_direction::SortDirection;
+    use crate::api::time_range::time_range_error::TimeRangeError;
+    use crate::api::time_range::time_range_request::validate_time_range;
+    use crate::api::time_range::time_unit::TimeUnit;
+
+
+    const START_DATE_TIME: Timestamp = Timestamp::from_nanos(1_700_000_000_000_000_000);
+    const HOUR: i64 = 3_600_000_000_000;
+
+    fn at(offset: i64) -> Timestamp {
+        Timestamp::from_nanos(START_DATE_TIME.as_nanos() + offset)
+    }
+
+    fn get_request(start_date_time: Timestamp, end_date_time: Timestamp) -> NetworkGraphRequestDTO {
+        NetworkGraphRequestDTO::new(start_date_time, end_date_time, NetworkGraphFiltersDTO::new(&[], None, &[], None, None, None))
+    }
+
+    fn get_page(sort_key: &str) -> PageRequestDTO {
+        PageRequestDTO::new(50, None, Some("b2Zmc2V0OjUw"), Some(sort_key), Some(SortDirection::Descending))
+    }
+
+    fn get_paged_request(sort_key: &str) -> HttpClientsRequestDTO {
+        HttpClientsRequestDTO::new_page(START_DATE_TIME, at(HOUR), HttpClientsFiltersDTO::new(&[], None, &[], None, None, None), get_page(sort_key))
+    }
+
+    fn get_bucketed_request(bucket_interval: BucketIntervalDTO, time_zone: &str) -> NetworkBandwidthRequestDTO {
+        NetworkBandwidthRequestDTO::new_bucketed(
+            START_DATE_TIME,
+            at(10 * 60_000_000_000),
+            NetworkBandwidthFiltersDTO::new(&[], None, &[], None),
+            Some(bucket_interval),
+            Some(time_zone),
+        )
+    }
+
+    #[test]
+    fn request_ending_after_it_starts_is_valid() {
+        assert_eq!(get_request(START_DATE_TIME, at(HOUR)).validate(None), Ok(()));
+        assert_eq!(get_request(START_DATE_TIME, at(HOUR)).validate(Some(Duration::from_secs(3600))), Ok(()));
+    }
+
+    #[test]
+    fn request_not_ending_after_it_starts_is_invalid() {
+        assert_eq!(
+            get_request(START_DATE_TIME, START_DATE_TIME).validate(None),
+            Err(TimeRangeError::Empty { start_date_time: START_DATE_TIME, end_date_time: START_DATE_TIME }),
+        );
+        assert_eq!(
+            get_request(at(HOUR), START_DATE_TIME).validate(None),
+            Err(TimeRangeError::Empty { start_date_time: at(HOUR), end_date_time: START_DATE_TIME }),
+        );
+    }
+
+    #[test]
+    fn request_ending_before_it_starts_is_not_decoded() {
+        let error = NetworkGraphRequestDTO::try_decode(&get_request(at(HOUR), START_DATE_TIME).encode()).unwrap_err();
+        let time_range_error = TimeRangeError::Empty { start_date_time: at(HOUR), end_date_time: START_DATE_TIME };
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(time_range_error.to_string()));
+    }
+
+    #[test]
+    fn request_spanning_more_than_the_maximum_is_invalid() {
+        assert_eq!(
+            get_request(START_DATE_TIME, at(2 * HOUR)).validate(Some(Duration::from_secs(3600))),
+            Err(TimeRangeError::TooLong { span: Duration::from_secs(7200), max_span: Duration::from_secs(3600) }),
+        );
+    }
+
+    #[test]
+    fn span_is_computed_between_nanosecond_timestamps() {
+        assert_eq!(validate_time_range(at(0), at(2), Some(Duration::from_nanos(1))), Err(TimeRangeError::TooLong {
+            span: Duration::from_nanos(2),
+            max_span: Duration::from_nanos(1),
+        }));
+        assert_eq!(validate_time_range(at(0), at(2), Some(Duration::from_nanos(2))), Ok(()));
+        assert_eq!(validate_time_range(Timestamp::MIN, Timestamp::MAX, None), Ok(()));
+    }
+
+    #[test]
+    fn request_is_tagged_according_to_its_filters() {
+        assert_eq!(NetworkGraphRequestDTO::get_data_type(), "network_graph_request");
+        assert_eq!(NetworkGraphRequestDTO::get_version(), 5);
+        assert_eq!(get_request(START_DATE_TIME, at(HOUR)).get_time_unit(), TimeUnit::Milliseconds);
+        assert_eq!(NetworkBandwidthPerEndpointRequestDTO::get_data_type_aliases(), ["bandwidth-per-endpoint-request"]);
+    }
+
+    #[test]
+    fn endec_paged_request() {
+        let request = get_paged_request("requests_amount");
+        assert_eq!(request, HttpClientsRequestDTO::decode(&request.encode()));
+        assert_eq!(request.get_page(), Some(&get_page("requests_amount")));
+        assert_eq!(get_request(START_DATE_TIME, at(HOUR)).get_page(), None);
+    }
+
+    #[test]
+    fn paged_request_is_sorted_by_a_known_key() {
+        assert_eq!(get_paged_request("endpoint").validate_page(), Ok(()));
+        assert_eq!(
+            get_paged_request("bytes").validate_page(),
+            Err(PageError::UnknownSortKey { sort_key: "bytes".into(), sort_keys: &["endpoint", "user_agent", "requests_amount"] }),
+        );
+
+        let error = HttpClientsRequestDTO::try_decode(&get_paged_request("bytes").encode()).unwrap_err();
+        assert!(matches!(error.get_kind(), DecodeErrorKind::Invalid(_)));
+    }
+
+    #[test]
+    fn only_list_shaped_charts_are_paged() {
+        let request = NetworkGraphRequestDTO::new_page(
+            START_DATE_TIME,
+            at(HOUR),
+            NetworkGraphFiltersDTO::new(&[], None, &[], None, None, None),
+            get_page("bytes"),
+        );
+        assert_eq!(request.get_sort_keys(), &[] as &[&str]);
+
+        let error = NetworkGraphRequestDTO::try_decode(&request.encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(PageError::NotPaged { data_type: "network_graph_request" }.to_string()));
+    }
+
+    #[test]
+    fn endec_bucketed_request() {
+        let request = get_bucketed_request(BucketIntervalDTO::auto(100), "Europe/Kyiv");
+        assert_eq!(request, NetworkBandwidthRequestDTO::decode(&request.encode()));
+        assert_eq!(request.get_time_zone(), Some("Europe/Kyiv"));
+        assert_eq!(get_request(START_DATE_TIME, at(HOUR)).get_bucket_interval(), None);
+    }
+
+    #[test]
+    fn bucket_info_echoes_the_resolved_interval() {
+        assert_eq!(
+            get_bucketed_request(BucketIntervalDTO::auto(100), "+02:00").get_bucket_info(&BucketIntervalDTO::fixed(1, TimeUnit::Seconds)),
+            BucketInfoDTO::new(10_000, TimeUnit::Milliseconds, Some("+02:00")),
+        );
+        assert_eq!(
+            get_request(START_DATE_TIME, at(10 * 60_000_000_000)).get_bucket_info(&BucketIntervalDTO::fixed(1, TimeUnit::Seconds)),
+            BucketInfoDTO::new(1_000, TimeUnit::Milliseconds, None),
+        );
+    }
+
+    #[test]
+    fn decoded_interval_keeps_its_own_unit() {
+        let request = get_bucketed_request(BucketIntervalDTO::fixed(2, TimeUnit::Seconds), "UTC");
+        let request = NetworkBandwidthRequestDTO::decode(&request.encode());
+        assert_eq!(request.get_time_unit(), TimeUnit::Milliseconds);
+        assert_eq!(request.get_bucket_interval(), Some(&BucketIntervalDTO::fixed(2, TimeUnit::Seconds)));
+        assert_eq!(
+            request.get_bucket_info(&BucketIntervalDTO::auto(100)),
+            BucketInfoDTO::new(2_000, TimeUnit::Milliseconds, Some("UTC")),
+        );
+    }
+
+    #[test]
+    fn invalid_bucketed_request_is_not_decoded() {
+        assert_eq!(
+            get_bucketed_request(BucketIntervalDTO::fixed(-1, TimeUnit::Milliseconds), "UTC").validate_buckets(),
+            Err(BucketError::NonPositiveInterval(-1)),
+        );
+
+        let error = NetworkBandwidthRequestDTO::try_decode(&get_bucketed_request(BucketIntervalDTO::fixed(60_000, TimeUnit::Milliseconds), "Kyiv time").encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(BucketError::InvalidTimeZone("Kyiv time".into()).to_string()));
+    }
+
+    #[test]
+    fn only_time_series_charts_are_bucketed() {
+        let request = NetworkGraphRequestDTO::new_bucketed(
+            START_DATE_TIME,
+            at(HOUR),
+            NetworkGraphFiltersDTO::new(&[], None, &[], None, None, None),
+            None,
+            Some("UTC"),
+        );
+
+        let error = NetworkGraphRequestDTO::try_decode(&request.encode()).unwrap_err();
+        assert_eq!(error.get_kind(), &DecodeErrorKind::Invalid(BucketError::NotBucketed { data_type: "network_graph_request" }.to_string()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub use net_codec_api::core::time_unit::TimeUnit;
\ No newline at end of file
@@ -1,34 +1,35 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_api::core::timestamp::Timestamp;
 
-use ion_rs::ReaderBuilder;
+use net_codec_derive::IonDto;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
+use crate::api::time_range::time_unit::TimeUnit;
 
-const DATA_TYPE: &str = "bucket";
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+const DATA_TYPE: &str = "http_requests_bucket";
+const LEGACY_DATA_TYPES: &[&str] = &["bucket"];
+const VERSION: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, aliases = LEGACY_DATA_TYPES, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HttpRequestsBucketDTO {
-    bucket_timestamp: i64,
+    #[ion(legacy_unit = TimeUnit::Milliseconds)]
+    bucket_timestamp: Timestamp,
     total_requests: i64,
 }
 impl API for HttpRequestsBucketDTO { }
 
 impl HttpRequestsBucketDTO {
-    pub fn new(bucket_timestamp: i64, total_requests: i64) -> Self {
+    pub fn new(bucket_timestamp: Timestamp, total_requests: i64) -> Self {
         HttpRequestsBucketDTO {
             bucket_timestamp,
             total_requests,
         }
     }
 
-    pub fn get_bucket_timestamp(&self) -> i64 {
+    pub fn get_bucket_timestamp(&self) -> Timestamp {
         self.bucket_timestamp
     }
 
@@ -37,57 +38,6 @@ impl HttpRequestsBucketDTO {
     }
 }
 
-impl Encoder for HttpRequestsBucketDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new(); 
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-        
-        writer.set_field_name("bucket_timestamp");
-        writer.write_i64(self.bucket_timestamp).unwrap();
-
-        writer.set_field_name("total_requests");
-        writer.write_i64(self.total_requests).unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for HttpRequestsBucketDTO {
-    fn decode(data: &[u8]) -> Self {
-
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let bucket_timestamp = binary_user_reader.read_i64().unwrap();
-
-        binary_user_reader.next().unwrap();
-        let total_requests = binary_user_reader.read_i64().unwrap();
-
-        HttpRequestsBucketDTO::new(
-            bucket_timestamp,
-            total_requests
-        )
-    }
-}
-
-impl Typed for HttpRequestsBucketDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
-    }
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
-    }
-}
-
 
 #[cfg(test)]
 mod tests {
@@ -99,11 +49,13 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::total_http_requests::http_requests_bucket::HttpRequestsBucketDTO;
 
     #[test]
     fn reader_correctly_read_encoded_requests_bucket() {
-        const BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const TOTAL_REQUESTS: i64 = i64::MAX;
 
         let bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -115,9 +67,9 @@ mod tests {
         assert_eq!(StreamItem::Value(IonType::Struct), binary_user_reader.next().unwrap());
         binary_user_reader.step_in().unwrap();
         
-        assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
+        assert_eq!(StreamItem::Value(IonType::Timestamp), binary_user_reader.next().unwrap());
         assert_eq!("bucket_timestamp", binary_user_reader.field_name().unwrap());
-        assert_eq!(BUCKET_TIMESTAMP,  binary_user_reader.read_i64().unwrap());
+        assert_eq!(Ok(BUCKET_TIMESTAMP), Timestamp::try_from(binary_user_reader.read_timestamp().unwrap()));
 
         assert_eq!(StreamItem::Value(IonType::Int), binary_user_reader.next().unwrap());
         assert_eq!("total_requests", binary_user_reader.field_name().unwrap());
@@ -128,7 +80,7 @@ mod tests {
 
     #[test]
     fn endec_requests_bucket() {
-        const BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const TOTAL_REQUESTS: i64 = i64::MAX;
 
         let bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -1,25 +1,22 @@
-use ion_rs;
+use net_core_api::core::api::API;
 
-use ion_rs::IonReader;
-use ion_rs::IonType;
-use ion_rs::IonWriter;
+use net_codec_derive::IonDto;
 
-use ion_rs::ReaderBuilder;
-use ion_rs::element::reader::ElementReader;
+use crate::api::bucketing::bucket_info::BucketInfoDTO;
 
-use net_core_api::core::api::API;
-use net_core_api::core::encoder_api::Encoder;
-use net_core_api::core::decoder_api::Decoder;
-use net_core_api::core::typed_api::Typed;
 
 use super::http_requests_bucket::HttpRequestsBucketDTO;
 
 
 const DATA_TYPE: &str = "total_http_requests";
+const VERSION: u32 = 4;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, IonDto)]
+#[ion(data_type = DATA_TYPE, version = VERSION)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TotalHttpRequestsDTO {
     http_requests_buckets: Vec<HttpRequestsBucketDTO>,
+    bucket_info: Option<BucketInfoDTO>,
 }
 impl API for TotalHttpRequestsDTO { }
 
@@ -27,65 +24,24 @@ impl TotalHttpRequestsDTO {
     pub fn new(http_requests_buckets: &[HttpRequestsBucketDTO],) -> Self {
         TotalHttpRequestsDTO {
             http_requests_buckets: http_requests_buckets.to_vec(),
+            bucket_info: None,
         }
     }
 
-    pub fn get_http_requests_buckets(&self) -> &[HttpRequestsBucketDTO] {
-        &self.http_requests_buckets
-    }
-}
-
-impl Encoder for TotalHttpRequestsDTO {
-    fn encode(&self) -> Vec<u8> {
-        let buffer: Vec<u8> = Vec::new();
-
-        let binary_writer_builder = ion_rs::BinaryWriterBuilder::new();
-        let mut writer = binary_writer_builder.build(buffer.clone()).unwrap();
-        
-        writer.step_in(IonType::Struct).expect("Error while creating an ion struct");
-
-        writer.set_field_name("http_requests_buckets");
-        writer.step_in(IonType::List).expect("Error while entering an ion list");
-        self.http_requests_buckets.iter().for_each(|http_requests_bucket| {
-            let data = http_requests_bucket.encode();
-            writer.write_blob(data.as_slice()).unwrap();
-        });
-        writer.step_out().unwrap();
-
-        writer.step_out().unwrap();
-        writer.flush().unwrap();
-
-        writer.output().as_slice().into()
-    }
-}
-
-impl Decoder for TotalHttpRequestsDTO {
-    fn decode(data: &[u8]) -> Self {
-        let mut binary_user_reader = ReaderBuilder::new().build(data).unwrap();
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-
-        binary_user_reader.next().unwrap();
-        binary_user_reader.step_in().unwrap();
-        let total_http_requests_elements = binary_user_reader.read_all_elements().unwrap();
-        let mut http_requests_buckets = Vec::with_capacity(total_http_requests_elements.len());
-        total_http_requests_elements.iter().for_each(|element| {
-            let data = element.as_blob().unwrap();
-            let bucket = HttpRequestsBucketDTO::decode(data);
-            http_requests_buckets.push(bucket);
-        });
-
-        Self::new(&http_requests_buckets)
+    pub fn new_bucketed(http_requests_buckets: &[HttpRequestsBucketDTO], bucket_info: BucketInfoDTO) -> Self {
+        TotalHttpRequestsDTO {
+            http_requests_buckets: http_requests_buckets.to_vec(),
+            bucket_info: Some(bucket_info),
+        }
     }
-}
 
-impl Typed for TotalHttpRequestsDTO {
-    fn get_data_type() -> &'static str {
-        DATA_TYPE
+    pub fn get_http_requests_buckets(&self) -> &[HttpRequestsBucketDTO] {
+        &self.http_requests_buckets
     }
 
-    fn get_type(&self) -> &str {
-        Self::get_data_type()
+    /// How wide the buckets of the response are and the time zone they are aligned in, as resolved from the request.
+    pub fn get_bucket_info(&self) -> Option<&BucketInfoDTO> {
+        self.bucket_info.as_ref()
     }
 }
 
@@ -101,13 +57,15 @@ mod tests {
     use net_core_api::core::encoder_api::Encoder;
     use net_core_api::core::decoder_api::Decoder;
 
+    use net_codec_api::core::timestamp::Timestamp;
+
     use crate::api::total_http_requests::http_requests_bucket::HttpRequestsBucketDTO;
     use crate::api::total_http_requests::total_http_requests::TotalHttpRequestsDTO;
 
 
     #[test]
     fn reader_correctly_read_total_http_requests() {
-        const FIRST_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const FIRST_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const FIRST_TOTAL_REQUESTS: i64 = i64::MAX;
 
         let first_bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -115,7 +73,7 @@ mod tests {
             FIRST_TOTAL_REQUESTS
         );
 
-        const SECOND_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const SECOND_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const SECOND_TOTAL_REQUESTS: i64 = i64::MAX;
 
         let second_bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -143,7 +101,7 @@ mod tests {
         let elements = binary_user_reader.read_all_elements().unwrap();
         assert_eq!(elements.len(), bandwidth_buckets.len());
         for (element, bandwidth_bucket_core) in elements.iter().zip(bandwidth_buckets.as_slice()) {
-            let encoded_endpoint = HttpRequestsBucketDTO::decode(element.as_blob().unwrap());
+            let encoded_endpoint = HttpRequestsBucketDTO::decode(&element.to_binary().unwrap());
             assert_eq!(encoded_endpoint, *bandwidth_bucket_core);
         }
         binary_user_reader.step_out().unwrap();
@@ -153,7 +111,7 @@ mod tests {
 
     #[test]
     fn endec_total_http_requests() {
-        const FIRST_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const FIRST_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const FIRST_TOTAL_REQUESTS: i64 = i64::MAX;
 
         let first_bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -161,7 +119,7 @@ mod tests {
             FIRST_TOTAL_REQUESTS
         );
 
-        const SECOND_BUCKET_TIMESTAMP: i64 = i64::MAX;
+        const SECOND_BUCKET_TIMESTAMP: Timestamp = Timestamp::MAX;
         const SECOND_TOTAL_REQUESTS: i64 = i64::MAX;
 
         let second_bandwidth_bucket = HttpRequestsBucketDTO::new(
@@ -0,0 +1 @@
+pub mod query_api;
\ No newline at end of file
@@ -0,0 +1,64 @@
+use net_core_api::core::api::API;
+use net_core_api::core::encoder_api::Encoder;
+use net_core_api::core::typed_api::Typed;
+
+use net_codec_api::core::ion_struct_api::IonStruct;
+
+
+/// A request the reporter answers with a `Response`.
+///
+/// Binding each chart's request to its response lets code serving or sending requests be generic
+/// over the charts while the compiler checks the response matches the request.
+pub trait Query : API + Encoder + IonStruct + Typed {
+    type Response : API + Encoder + IonStruct + Typed;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use net_core_api::core::encoder_api::Encoder;
+    use net_core_api::core::typed_api::Typed;
+
+    use net_codec_api::core::decode_error::DecodeError;
+    use net_codec_api::core::timestamp::Timestamp;
+    use net_codec_api::core::try_decoder_api::TryDecoder;
+
+    use crate::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
+    use crate::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+    use crate::api::network_bandwidth::network_bandwidth_filters::NetworkBandwidthFiltersDTO;
+    use crate::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+    use crate::api::network_graph::network_graph::NetworkGraphDTO;
+    use crate::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+    use crate::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+    use crate::api::total_http_requests::total_http_requests::TotalHttpRequestsDTO;
+    use crate::core::query_api::Query;
+
+
+    /// Stands for an RPC layer sending `request` and decoding whatever comes back as its response.
+    fn send<Q: Query>(request: &Q, respond: impl Fn(&[u8]) -> Vec<u8>) -> Result<Q::Response, DecodeError> {
+        Q::Response::try_decode(&respond(&request.encode()))
+    }
+
+    fn get_response_data_type<Q: Query>() -> &'static str {
+        Q::Response::get_data_type()
+    }
+
+    #[test]
+    fn response_is_decoded_as_the_query_response() {
+        let request = NetworkBandwidthRequestDTO::new(Timestamp::UNIX_EPOCH, Timestamp::from_nanos(1), NetworkBandwidthFiltersDTO::new(&[], None, &[], None));
+        let response = NetworkBandwidthDTO::new(&[BandwidthBucketDTO::new(Timestamp::UNIX_EPOCH, 1024)]);
+
+        let received = send(&request, |payload| {
+            assert_eq!(NetworkBandwidthRequestDTO::try_decode(payload).unwrap(), request);
+            response.encode()
+        });
+        assert_eq!(received.unwrap(), response);
+    }
+
+    #[test]
+    fn requests_are_paired_with_their_chart_response() {
+        assert_eq!(get_response_data_type::<NetworkBandwidthRequestDTO>(), NetworkBandwidthDTO::get_data_type());
+        assert_eq!(get_response_data_type::<NetworkGraphRequestDTO>(), NetworkGraphDTO::get_data_type());
+        assert_eq!(get_response_data_type::<RequestTotalHttpRequestsDTO>(), TotalHttpRequestsDTO::get_data_type());
+    }
+}
\ No newline at end of file
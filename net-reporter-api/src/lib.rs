@@ -1 +1,2 @@
 pub mod api;
+pub mod core;
\ No newline at end of file
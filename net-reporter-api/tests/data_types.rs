@@ -0,0 +1,138 @@
+use net_codec_api::core::data_types::DataTypeCheck;
+
+use net_agent_api::api::agent_config::AgentConfigDTO;
+use net_agent_api::api::agent_config_ack::AgentConfigAckDTO;
+use net_agent_api::api::agent_health::AgentHealthDTO;
+use net_agent_api::api::agent_heartbeat::AgentHeartbeatDTO;
+use net_agent_api::api::capture_header::CaptureHeaderDTO;
+use net_agent_api::api::capture_stats::CaptureStatsDTO;
+use net_agent_api::api::config_error::ConfigErrorDTO;
+use net_agent_api::api::data_packet::DataPacketDTO;
+use net_agent_api::api::data_packet_ack::DataPacketAckDTO;
+use net_agent_api::api::data_packet_nack::DataPacketNackDTO;
+use net_agent_api::api::sequence_range::SequenceRangeDTO;
+
+use net_inserter_api::api::network_address::network_address::NetworkAddressDTO;
+use net_inserter_api::api::network_packet::network_packet::NetworkPacketDTO;
+use net_inserter_api::api::network_packet::packet_metadata::PacketMetadataDTO;
+use net_inserter_api::api::network_packet_batch::network_packet_batch::NetworkPacketBatchDTO;
+
+use net_reporter_api::api::bucketing::bucket_info::BucketInfoDTO;
+use net_reporter_api::api::bucketing::bucket_interval::BucketIntervalDTO;
+use net_reporter_api::api::envelope::typed_envelope::TypedEnvelopeDTO;
+use net_reporter_api::api::filter::filter_expression::FilterExpressionDTO;
+use net_reporter_api::api::http_clients::http_client::HttpClientDTO;
+use net_reporter_api::api::http_clients::http_clients::HttpClientsDTO;
+use net_reporter_api::api::http_clients::http_clients_filters::HttpClientsFiltersDTO;
+use net_reporter_api::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters::HttpOverviewDashboardFiltersDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters_request::HttpOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_method::HttpRequestMethodDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution::HttpRequestMethodsDistributionDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_filters::HttpRequestMethodsDisributionFiltersDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_request::HttpRequestMethodsDistributionRequestDTO;
+use net_reporter_api::api::http_responses::http_response::HttpResponseDTO;
+use net_reporter_api::api::http_responses::http_responses::HttpResponsesDTO;
+use net_reporter_api::api::http_responses::http_responses_filters::HttpResponsesFiltersDTO;
+use net_reporter_api::api::http_responses::http_responses_request::HttpResponsesRequestDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_disribution_filters::HttpResponsesDistributionFiltersDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution::HttpResponsesDistributionDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_bucket::HttpResponsesDistributionBucketDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_request::HttpResponsesDistributionRequestDTO;
+use net_reporter_api::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_filters::NetworkBandwidthFiltersDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::endpoint::EndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint::NetworkBandwidthPerEndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_filters::NetworkBandwidthPerEndpointFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol::NetworkBandwidthPerProtocolDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_filters::NetworkBandwidthPerProtocolFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_request::NetworkBandwidthPerProtocolRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::protocol::ProtocolDTO;
+use net_reporter_api::api::network_graph::graph_edge::GraphEdgeDTO;
+use net_reporter_api::api::network_graph::graph_node::GraphNodeDTO;
+use net_reporter_api::api::network_graph::network_graph::NetworkGraphDTO;
+use net_reporter_api::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+use net_reporter_api::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::filter_entry::FilterEntryDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashboard_filters_request::NetworkOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashbord_filters::NetworkOverviewDashboardFiltersDTO;
+use net_reporter_api::api::pagination::page_info::PageInfoDTO;
+use net_reporter_api::api::pagination::page_request::PageRequestDTO;
+use net_reporter_api::api::total_http_requests::http_requests_bucket::HttpRequestsBucketDTO;
+use net_reporter_api::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests::TotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests_filters::TotalHttpRequestsFiltersDTO;
+
+
+/// Data types and their aliases must not collide across the workspace, a consumer may receive the DTOs of every crate.
+#[test]
+fn data_types_are_canonical_and_unique() {
+    DataTypeCheck::new()
+        .add::<AgentConfigDTO>()
+        .add::<AgentConfigAckDTO>()
+        .add::<AgentHealthDTO>()
+        .add::<AgentHeartbeatDTO>()
+        .add::<CaptureHeaderDTO>()
+        .add::<CaptureStatsDTO>()
+        .add::<ConfigErrorDTO>()
+        .add::<DataPacketDTO>()
+        .add::<DataPacketAckDTO>()
+        .add::<DataPacketNackDTO>()
+        .add::<SequenceRangeDTO>()
+        .add::<NetworkAddressDTO>()
+        .add::<NetworkPacketDTO>()
+        .add::<PacketMetadataDTO>()
+        .add::<NetworkPacketBatchDTO>()
+        .add::<BucketInfoDTO>()
+        .add::<BucketIntervalDTO>()
+        .add::<TypedEnvelopeDTO>()
+        .add::<FilterExpressionDTO>()
+        .add::<HttpClientDTO>()
+        .add::<HttpClientsDTO>()
+        .add::<HttpClientsFiltersDTO>()
+        .add::<HttpClientsRequestDTO>()
+        .add::<HttpOverviewDashboardFiltersDTO>()
+        .add::<HttpOverviewDashboardFiltersRequestDTO>()
+        .add::<HttpRequestMethodDTO>()
+        .add::<HttpRequestMethodsDistributionDTO>()
+        .add::<HttpRequestMethodsDisributionFiltersDTO>()
+        .add::<HttpRequestMethodsDistributionRequestDTO>()
+        .add::<HttpResponseDTO>()
+        .add::<HttpResponsesDTO>()
+        .add::<HttpResponsesFiltersDTO>()
+        .add::<HttpResponsesRequestDTO>()
+        .add::<HttpResponsesDistributionFiltersDTO>()
+        .add::<HttpResponsesDistributionDTO>()
+        .add::<HttpResponsesDistributionBucketDTO>()
+        .add::<HttpResponsesDistributionRequestDTO>()
+        .add::<BandwidthBucketDTO>()
+        .add::<NetworkBandwidthDTO>()
+        .add::<NetworkBandwidthFiltersDTO>()
+        .add::<NetworkBandwidthRequestDTO>()
+        .add::<EndpointDTO>()
+        .add::<NetworkBandwidthPerEndpointDTO>()
+        .add::<NetworkBandwidthPerEndpointFiltersDTO>()
+        .add::<NetworkBandwidthPerEndpointRequestDTO>()
+        .add::<NetworkBandwidthPerProtocolDTO>()
+        .add::<NetworkBandwidthPerProtocolFiltersDTO>()
+        .add::<NetworkBandwidthPerProtocolRequestDTO>()
+        .add::<ProtocolDTO>()
+        .add::<GraphEdgeDTO>()
+        .add::<GraphNodeDTO>()
+        .add::<NetworkGraphDTO>()
+        .add::<NetworkGraphFiltersDTO>()
+        .add::<NetworkGraphRequestDTO>()
+        .add::<FilterEntryDTO>()
+        .add::<NetworkOverviewDashboardFiltersRequestDTO>()
+        .add::<NetworkOverviewDashboardFiltersDTO>()
+        .add::<PageInfoDTO>()
+        .add::<PageRequestDTO>()
+        .add::<HttpRequestsBucketDTO>()
+        .add::<RequestTotalHttpRequestsDTO>()
+        .add::<TotalHttpRequestsDTO>()
+        .add::<TotalHttpRequestsFiltersDTO>()
+        .check();
+}
\ No newline at end of file
@@ -0,0 +1,293 @@
+use net_codec_api::core::golden::check_golden;
+use net_codec_api::core::timestamp::Timestamp;
+
+use net_reporter_api::api::bucketing::bucket_info::BucketInfoDTO;
+use net_reporter_api::api::bucketing::bucket_interval::BucketIntervalDTO;
+use net_reporter_api::api::envelope::typed_envelope::TypedEnvelopeDTO;
+use net_reporter_api::api::filter::filter_expression::FilterExpressionDTO;
+use net_reporter_api::api::filter::filter_field::FilterField;
+use net_reporter_api::api::http_clients::http_client::HttpClientDTO;
+use net_reporter_api::api::http_clients::http_clients::HttpClientsDTO;
+use net_reporter_api::api::http_clients::http_clients_filters::HttpClientsFiltersDTO;
+use net_reporter_api::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters::HttpOverviewDashboardFiltersDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters_request::HttpOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_method::HttpRequestMethodDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution::HttpRequestMethodsDistributionDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_filters::HttpRequestMethodsDisributionFiltersDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_request::HttpRequestMethodsDistributionRequestDTO;
+use net_reporter_api::api::http_responses::http_response::HttpResponseDTO;
+use net_reporter_api::api::http_responses::http_responses::HttpResponsesDTO;
+use net_reporter_api::api::http_responses::http_responses_filters::HttpResponsesFiltersDTO;
+use net_reporter_api::api::http_responses::http_responses_request::HttpResponsesRequestDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_disribution_filters::HttpResponsesDistributionFiltersDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution::HttpResponsesDistributionDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_bucket::HttpResponsesDistributionBucketDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_request::HttpResponsesDistributionRequestDTO;
+use net_reporter_api::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_filters::NetworkBandwidthFiltersDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::endpoint::EndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint::NetworkBandwidthPerEndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_filters::NetworkBandwidthPerEndpointFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol::NetworkBandwidthPerProtocolDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_filters::NetworkBandwidthPerProtocolFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_request::NetworkBandwidthPerProtocolRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::protocol::ProtocolDTO;
+use net_reporter_api::api::network_graph::graph_edge::GraphEdgeDTO;
+use net_reporter_api::api::network_graph::graph_node::GraphNodeDTO;
+use net_reporter_api::api::network_graph::network_graph::NetworkGraphDTO;
+use net_reporter_api::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+use net_reporter_api::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::filter_entry::FilterEntryDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashboard_filters_request::NetworkOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashbord_filters::NetworkOverviewDashboardFiltersDTO;
+use net_reporter_api::api::pagination::page_info::PageInfoDTO;
+use net_reporter_api::api::pagination::page_request::PageRequestDTO;
+use net_reporter_api::api::pagination::sort_direction::SortDirection;
+use net_reporter_api::api::time_range::time_unit::TimeUnit;
+use net_reporter_api::api::total_http_requests::http_requests_bucket::HttpRequestsBucketDTO;
+use net_reporter_api::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests::TotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests_filters::TotalHttpRequestsFiltersDTO;
+
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+
+const START_DATE_TIME: Timestamp = Timestamp::from_nanos(1_700_000_000_000_000_000);
+const END_DATE_TIME: Timestamp = Timestamp::from_nanos(1_700_000_600_000_000_000);
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+fn get_http_client() -> HttpClientDTO {
+    HttpClientDTO::new("0.0.0.0", Some("Mozilla/5.0"), 123)
+}
+
+fn get_http_clients_filters() -> HttpClientsFiltersDTO {
+    HttpClientsFiltersDTO::new(&strings(&["GET", "POST"]), Some(true), &strings(&["0.0.0.0", "1.1.1.1"]), Some(false), Some(100), None)
+}
+
+fn get_http_request_method() -> HttpRequestMethodDTO {
+    HttpRequestMethodDTO::new("GET", 42)
+}
+
+fn get_http_request_methods_distribution_filters() -> HttpRequestMethodsDisributionFiltersDTO {
+    HttpRequestMethodsDisributionFiltersDTO::new(&strings(&["0.0.0.0"]), Some(true), None, Some(1000))
+}
+
+fn get_http_response() -> HttpResponseDTO {
+    HttpResponseDTO::new(START_DATE_TIME, "0.0.0.0", "1.1.1.1", 200)
+}
+
+fn get_http_responses_filters() -> HttpResponsesFiltersDTO {
+    HttpResponsesFiltersDTO::new(&[200, 404], Some(true), &[], None, Some(100), Some(1000))
+}
+
+fn get_http_responses_distribution_bucket() -> HttpResponsesDistributionBucketDTO {
+    HttpResponsesDistributionBucketDTO::new(START_DATE_TIME, 404, 7)
+}
+
+fn get_http_responses_distribution_filters() -> HttpResponsesDistributionFiltersDTO {
+    HttpResponsesDistributionFiltersDTO::new(&strings(&["0.0.0.0"]), Some(false), Some(100), None)
+}
+
+fn get_bandwidth_bucket() -> BandwidthBucketDTO {
+    BandwidthBucketDTO::new(START_DATE_TIME, 1024)
+}
+
+fn get_network_bandwidth_filters() -> NetworkBandwidthFiltersDTO {
+    NetworkBandwidthFiltersDTO::new(&strings(&["TCP", "UDP"]), Some(false), &strings(&["0.0.0.0"]), Some(true))
+}
+
+fn get_endpoint() -> EndpointDTO {
+    EndpointDTO::new("0.0.0.0", 2048, 1024)
+}
+
+fn get_network_bandwidth_per_endpoint_filters() -> NetworkBandwidthPerEndpointFiltersDTO {
+    NetworkBandwidthPerEndpointFiltersDTO::new(&strings(&["TCP"]), Some(true), &[], None, None, Some(1000))
+}
+
+fn get_protocol() -> ProtocolDTO {
+    ProtocolDTO::new("TCP", 4096)
+}
+
+fn get_network_bandwidth_per_protocol_filters() -> NetworkBandwidthPerProtocolFiltersDTO {
+    NetworkBandwidthPerProtocolFiltersDTO::new(&strings(&["0.0.0.0", "1.1.1.1"]), Some(true), Some(100), Some(1000))
+}
+
+fn get_graph_node() -> GraphNodeDTO {
+    GraphNodeDTO::new("0.0.0.0:5656")
+}
+
+fn get_graph_edge() -> GraphEdgeDTO {
+    GraphEdgeDTO::new("0.0.0.0:0000", "0.0.0.0:5656", 123)
+}
+
+fn get_network_graph_filters() -> NetworkGraphFiltersDTO {
+    NetworkGraphFiltersDTO::new(&strings(&["TCP", "UDP"]), Some(false), &strings(&["0.0.0.0", "1.1.1.1"]), Some(true), Some(100), Some(1000))
+}
+
+fn get_filter_entry() -> FilterEntryDTO {
+    FilterEntryDTO::new("0.0.0.0", &strings(&["TCP", "HTTP"]), 8192)
+}
+
+fn get_http_requests_bucket() -> HttpRequestsBucketDTO {
+    HttpRequestsBucketDTO::new(START_DATE_TIME, 17)
+}
+
+fn get_total_http_requests_filters() -> TotalHttpRequestsFiltersDTO {
+    TotalHttpRequestsFiltersDTO::new(&[], None, &strings(&["0.0.0.0"]), Some(true), None, None)
+}
+
+#[test]
+fn golden_bucketing() {
+    check_golden(GOLDEN_DIR, "bucket_info", &BucketInfoDTO::new(60_000, TimeUnit::Milliseconds, Some("Europe/Kyiv")));
+    check_golden(GOLDEN_DIR, "bucket_interval", &BucketIntervalDTO::auto(100));
+}
+
+#[test]
+fn golden_filter() {
+    check_golden(
+        GOLDEN_DIR,
+        "filter_expression",
+        &FilterExpressionDTO::and(vec![
+            FilterExpressionDTO::or(vec![
+                FilterExpressionDTO::cidr(FilterField::Endpoint, "10.0.0.0/8"),
+                FilterExpressionDTO::prefix(FilterField::Endpoint, "192.168."),
+            ]),
+            FilterExpressionDTO::negate(FilterExpressionDTO::is_in(FilterField::Protocol, ["TCP", "UDP"])),
+            FilterExpressionDTO::is_in(FilterField::ResponseCode, [200, 404]),
+            FilterExpressionDTO::equals(FilterField::HttpMethod, "GET"),
+            FilterExpressionDTO::range(FilterField::Bytes, Some(100), None),
+        ]),
+    );
+}
+
+#[test]
+fn golden_http_clients() {
+    check_golden(GOLDEN_DIR, "http_client", &get_http_client());
+    check_golden(GOLDEN_DIR, "http_clients", &HttpClientsDTO::new(&[get_http_client(), HttpClientDTO::new("1.1.1.1", None, 1)]));
+    check_golden(GOLDEN_DIR, "http_clients_filters", &get_http_clients_filters());
+    check_golden(GOLDEN_DIR, "http_clients_request", &HttpClientsRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_http_clients_filters()));
+}
+
+#[test]
+fn golden_http_overview_dashboard_filters() {
+    check_golden(
+        GOLDEN_DIR,
+        "http_overview_dashboard_filters",
+        &HttpOverviewDashboardFiltersDTO::new(&strings(&["0.0.0.0"]), &strings(&["GET", "POST"]), &strings(&["200", "404"])),
+    );
+    check_golden(GOLDEN_DIR, "http_overview_dashboard_filters_request", &HttpOverviewDashboardFiltersRequestDTO::new(START_DATE_TIME, END_DATE_TIME));
+}
+
+#[test]
+fn golden_http_request_methods_distribution() {
+    check_golden(GOLDEN_DIR, "http_request_method", &get_http_request_method());
+    check_golden(GOLDEN_DIR, "http_request_methods_distribution", &HttpRequestMethodsDistributionDTO::new(&[get_http_request_method()]));
+    check_golden(GOLDEN_DIR, "http_request_methods_distribution_filters", &get_http_request_methods_distribution_filters());
+    check_golden(
+        GOLDEN_DIR,
+        "http_request_methods_distribution_request",
+        &HttpRequestMethodsDistributionRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_http_request_methods_distribution_filters()),
+    );
+}
+
+#[test]
+fn golden_http_responses() {
+    check_golden(GOLDEN_DIR, "http_response", &get_http_response());
+    check_golden(GOLDEN_DIR, "http_responses", &HttpResponsesDTO::new(&[get_http_response()]));
+    check_golden(GOLDEN_DIR, "http_responses_filters", &get_http_responses_filters());
+    check_golden(GOLDEN_DIR, "http_responses_request", &HttpResponsesRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_http_responses_filters()));
+}
+
+#[test]
+fn golden_http_responses_distribution() {
+    check_golden(GOLDEN_DIR, "http_responses_distribution_bucket", &get_http_responses_distribution_bucket());
+    check_golden(GOLDEN_DIR, "http_responses_distribution", &HttpResponsesDistributionDTO::new(&[get_http_responses_distribution_bucket()]));
+    check_golden(GOLDEN_DIR, "http_responses_distribution_filters", &get_http_responses_distribution_filters());
+    check_golden(
+        GOLDEN_DIR,
+        "http_responses_distribution_request",
+        &HttpResponsesDistributionRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_http_responses_distribution_filters()),
+    );
+}
+
+#[test]
+fn golden_network_bandwidth() {
+    check_golden(GOLDEN_DIR, "bandwidth_bucket", &get_bandwidth_bucket());
+    check_golden(GOLDEN_DIR, "network_bandwidth", &NetworkBandwidthDTO::new(&[get_bandwidth_bucket()]));
+    check_golden(GOLDEN_DIR, "network_bandwidth_filters", &get_network_bandwidth_filters());
+    check_golden(GOLDEN_DIR, "network_bandwidth_request", &NetworkBandwidthRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_network_bandwidth_filters()));
+}
+
+#[test]
+fn golden_network_bandwidth_per_endpoint() {
+    check_golden(GOLDEN_DIR, "endpoint", &get_endpoint());
+    check_golden(GOLDEN_DIR, "network_bandwidth_per_endpoint", &NetworkBandwidthPerEndpointDTO::new(&[get_endpoint()]));
+    check_golden(GOLDEN_DIR, "network_bandwidth_per_endpoint_filters", &get_network_bandwidth_per_endpoint_filters());
+    check_golden(
+        GOLDEN_DIR,
+        "network_bandwidth_per_endpoint_request",
+        &NetworkBandwidthPerEndpointRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_network_bandwidth_per_endpoint_filters()),
+    );
+}
+
+#[test]
+fn golden_network_bandwidth_per_protocol() {
+    check_golden(GOLDEN_DIR, "protocol", &get_protocol());
+    check_golden(GOLDEN_DIR, "network_bandwidth_per_protocol", &NetworkBandwidthPerProtocolDTO::new(&[get_protocol()]));
+    check_golden(GOLDEN_DIR, "network_bandwidth_per_protocol_filters", &get_network_bandwidth_per_protocol_filters());
+    check_golden(
+        GOLDEN_DIR,
+        "network_bandwidth_per_protocol_request",
+        &NetworkBandwidthPerProtocolRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_network_bandwidth_per_protocol_filters()),
+    );
+}
+
+#[test]
+fn golden_network_graph() {
+    check_golden(GOLDEN_DIR, "graph_node", &get_graph_node());
+    check_golden(GOLDEN_DIR, "graph_edge", &get_graph_edge());
+    check_golden(GOLDEN_DIR, "network_graph", &NetworkGraphDTO::new(&[get_graph_node(), GraphNodeDTO::new("0.0.0.0:0000")], &[get_graph_edge()]));
+    check_golden(GOLDEN_DIR, "network_graph_filters", &get_network_graph_filters());
+    check_golden(GOLDEN_DIR, "network_graph_request", &NetworkGraphRequestDTO::new(START_DATE_TIME, END_DATE_TIME, get_network_graph_filters()));
+}
+
+#[test]
+fn golden_network_overview_dashboard_filters() {
+    check_golden(GOLDEN_DIR, "filter_entry", &get_filter_entry());
+    check_golden(GOLDEN_DIR, "network_overview_dashboard_filters", &NetworkOverviewDashboardFiltersDTO::new(&[get_filter_entry()]));
+    check_golden(GOLDEN_DIR, "network_overview_dashboard_filters_request", &NetworkOverviewDashboardFiltersRequestDTO::new(START_DATE_TIME, END_DATE_TIME));
+}
+
+#[test]
+fn golden_pagination() {
+    check_golden(GOLDEN_DIR, "page_info", &PageInfoDTO::new(Some("b2Zmc2V0OjUw"), 1234));
+    check_golden(
+        GOLDEN_DIR,
+        "page_request",
+        &PageRequestDTO::new(50, None, Some("b2Zmc2V0OjUw"), Some("requests_amount"), Some(SortDirection::Descending)),
+    );
+}
+
+#[test]
+fn golden_total_http_requests() {
+    check_golden(GOLDEN_DIR, "http_requests_bucket", &get_http_requests_bucket());
+    check_golden(GOLDEN_DIR, "total_http_requests", &TotalHttpRequestsDTO::new(&[get_http_requests_bucket()]));
+    check_golden(GOLDEN_DIR, "total_http_requests_filters", &get_total_http_requests_filters());
+    check_golden(GOLDEN_DIR, "request_total_http_requests", &RequestTotalHttpRequestsDTO::new(START_DATE_TIME, END_DATE_TIME, get_total_http_requests_filters()));
+}
+
+#[test]
+fn golden_envelope() {
+    check_golden(
+        GOLDEN_DIR,
+        "typed_envelope",
+        &TypedEnvelopeDTO::wrap(&get_graph_node(), Some("9b2e4c1a"), Some("tenant-1")),
+    );
+}
\ No newline at end of file
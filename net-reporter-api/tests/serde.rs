@@ -0,0 +1,213 @@
+#![cfg(feature = "serde")]
+
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+
+use net_core_api::core::decoder_api::Decoder;
+use net_core_api::core::encoder_api::Encoder;
+
+use net_codec_api::core::timestamp::Timestamp;
+use net_codec_api::core::try_decoder_api::TryDecoder;
+use net_codec_api::core::versioned_api::Versioned;
+
+use net_reporter_api::api::bucketing::bucket_info::BucketInfoDTO;
+use net_reporter_api::api::bucketing::bucket_interval::BucketIntervalDTO;
+use net_reporter_api::api::envelope::typed_envelope::TypedEnvelopeDTO;
+use net_reporter_api::api::filter::filter_expression::FilterExpressionDTO;
+use net_reporter_api::api::http_clients::http_client::HttpClientDTO;
+use net_reporter_api::api::http_clients::http_clients::HttpClientsDTO;
+use net_reporter_api::api::http_clients::http_clients_filters::HttpClientsFiltersDTO;
+use net_reporter_api::api::http_clients::http_clients_request::HttpClientsRequestDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters::HttpOverviewDashboardFiltersDTO;
+use net_reporter_api::api::http_overview_dashboard_filters::http_overview_dashboard_filters_request::HttpOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_method::HttpRequestMethodDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution::HttpRequestMethodsDistributionDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_filters::HttpRequestMethodsDisributionFiltersDTO;
+use net_reporter_api::api::http_request_methods_distribution::http_request_methods_distribution_request::HttpRequestMethodsDistributionRequestDTO;
+use net_reporter_api::api::http_responses::http_response::HttpResponseDTO;
+use net_reporter_api::api::http_responses::http_responses::HttpResponsesDTO;
+use net_reporter_api::api::http_responses::http_responses_filters::HttpResponsesFiltersDTO;
+use net_reporter_api::api::http_responses::http_responses_request::HttpResponsesRequestDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_disribution_filters::HttpResponsesDistributionFiltersDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution::HttpResponsesDistributionDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_bucket::HttpResponsesDistributionBucketDTO;
+use net_reporter_api::api::http_responses_distribution::http_responses_distribution_request::HttpResponsesDistributionRequestDTO;
+use net_reporter_api::api::network_bandwidth::bandwidth_bucket::BandwidthBucketDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth::NetworkBandwidthDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_filters::NetworkBandwidthFiltersDTO;
+use net_reporter_api::api::network_bandwidth::network_bandwidth_request::NetworkBandwidthRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::endpoint::EndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint::NetworkBandwidthPerEndpointDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_filters::NetworkBandwidthPerEndpointFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_endpoint::network_bandwidth_per_endpoint_request::NetworkBandwidthPerEndpointRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol::NetworkBandwidthPerProtocolDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_filters::NetworkBandwidthPerProtocolFiltersDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::network_bandwidth_per_protocol_request::NetworkBandwidthPerProtocolRequestDTO;
+use net_reporter_api::api::network_bandwidth_per_protocol::protocol::ProtocolDTO;
+use net_reporter_api::api::network_graph::graph_edge::GraphEdgeDTO;
+use net_reporter_api::api::network_graph::graph_node::GraphNodeDTO;
+use net_reporter_api::api::network_graph::network_graph::NetworkGraphDTO;
+use net_reporter_api::api::network_graph::network_graph_filters::NetworkGraphFiltersDTO;
+use net_reporter_api::api::network_graph::network_graph_request::NetworkGraphRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::filter_entry::FilterEntryDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashboard_filters_request::NetworkOverviewDashboardFiltersRequestDTO;
+use net_reporter_api::api::network_overview_dashboard_filters::network_overview_dashbord_filters::NetworkOverviewDashboardFiltersDTO;
+use net_reporter_api::api::pagination::page_info::PageInfoDTO;
+use net_reporter_api::api::pagination::page_request::PageRequestDTO;
+use net_reporter_api::api::total_http_requests::http_requests_bucket::HttpRequestsBucketDTO;
+use net_reporter_api::api::total_http_requests::request_total_http_requests::RequestTotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests::TotalHttpRequestsDTO;
+use net_reporter_api::api::total_http_requests::total_http_requests_filters::TotalHttpRequestsFiltersDTO;
+
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+
+/// Decodes the golden payload of the current version, then sends the DTO through JSON and back.
+fn check_json_round_trip<T>(name: &str)
+where T: TryDecoder + Versioned + Serialize + DeserializeOwned + PartialEq + Debug {
+    let path = Path::new(GOLDEN_DIR).join(name).join(format!("v{}.10n", T::get_version()));
+    let dto = T::try_decode(&fs::read(path).unwrap()).unwrap();
+
+    let json = serde_json::to_string(&dto).unwrap();
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), dto, "{name} does not survive a round trip through JSON");
+}
+
+#[test]
+fn json_round_trip_bucketing() {
+    check_json_round_trip::<BucketInfoDTO>("bucket_info");
+    check_json_round_trip::<BucketIntervalDTO>("bucket_interval");
+}
+
+#[test]
+fn json_round_trip_envelope() {
+    check_json_round_trip::<TypedEnvelopeDTO>("typed_envelope");
+}
+
+#[test]
+fn json_round_trip_filter() {
+    check_json_round_trip::<FilterExpressionDTO>("filter_expression");
+}
+
+#[test]
+fn json_round_trip_http_clients() {
+    check_json_round_trip::<HttpClientDTO>("http_client");
+    check_json_round_trip::<HttpClientsDTO>("http_clients");
+    check_json_round_trip::<HttpClientsFiltersDTO>("http_clients_filters");
+    check_json_round_trip::<HttpClientsRequestDTO>("http_clients_request");
+}
+
+#[test]
+fn json_round_trip_http_overview_dashboard_filters() {
+    check_json_round_trip::<HttpOverviewDashboardFiltersDTO>("http_overview_dashboard_filters");
+    check_json_round_trip::<HttpOverviewDashboardFiltersRequestDTO>("http_overview_dashboard_filters_request");
+}
+
+#[test]
+fn json_round_trip_http_request_methods_distribution() {
+    check_json_round_trip::<HttpRequestMethodDTO>("http_request_method");
+    check_json_round_trip::<HttpRequestMethodsDistributionDTO>("http_request_methods_distribution");
+    check_json_round_trip::<HttpRequestMethodsDisributionFiltersDTO>("http_request_methods_distribution_filters");
+    check_json_round_trip::<HttpRequestMethodsDistributionRequestDTO>("http_request_methods_distribution_request");
+}
+
+#[test]
+fn json_round_trip_http_responses() {
+    check_json_round_trip::<HttpResponseDTO>("http_response");
+    check_json_round_trip::<HttpResponsesDTO>("http_responses");
+    check_json_round_trip::<HttpResponsesFiltersDTO>("http_responses_filters");
+    check_json_round_trip::<HttpResponsesRequestDTO>("http_responses_request");
+}
+
+#[test]
+fn json_round_trip_http_responses_distribution() {
+    check_json_round_trip::<HttpResponsesDistributionBucketDTO>("http_responses_distribution_bucket");
+    check_json_round_trip::<HttpResponsesDistributionDTO>("http_responses_distribution");
+    check_json_round_trip::<HttpResponsesDistributionFiltersDTO>("http_responses_distribution_filters");
+    check_json_round_trip::<HttpResponsesDistributionRequestDTO>("http_responses_distribution_request");
+}
+
+#[test]
+fn json_round_trip_network_bandwidth() {
+    check_json_round_trip::<BandwidthBucketDTO>("bandwidth_bucket");
+    check_json_round_trip::<NetworkBandwidthDTO>("network_bandwidth");
+    check_json_round_trip::<NetworkBandwidthFiltersDTO>("network_bandwidth_filters");
+    check_json_round_trip::<NetworkBandwidthRequestDTO>("network_bandwidth_request");
+}
+
+#[test]
+fn json_round_trip_network_bandwidth_per_endpoint() {
+    check_json_round_trip::<EndpointDTO>("endpoint");
+    check_json_round_trip::<NetworkBandwidthPerEndpointDTO>("network_bandwidth_per_endpoint");
+    check_json_round_trip::<NetworkBandwidthPerEndpointFiltersDTO>("network_bandwidth_per_endpoint_filters");
+    check_json_round_trip::<NetworkBandwidthPerEndpointRequestDTO>("network_bandwidth_per_endpoint_request");
+}
+
+#[test]
+fn json_round_trip_network_bandwidth_per_protocol() {
+    check_json_round_trip::<ProtocolDTO>("protocol");
+    check_json_round_trip::<NetworkBandwidthPerProtocolDTO>("network_bandwidth_per_protocol");
+    check_json_round_trip::<NetworkBandwidthPerProtocolFiltersDTO>("network_bandwidth_per_protocol_filters");
+    check_json_round_trip::<NetworkBandwidthPerProtocolRequestDTO>("network_bandwidth_per_protocol_request");
+}
+
+#[test]
+fn json_round_trip_network_graph() {
+    check_json_round_trip::<GraphNodeDTO>("graph_node");
+    check_json_round_trip::<GraphEdgeDTO>("graph_edge");
+    check_json_round_trip::<NetworkGraphDTO>("network_graph");
+    check_json_round_trip::<NetworkGraphFiltersDTO>("network_graph_filters");
+    check_json_round_trip::<NetworkGraphRequestDTO>("network_graph_request");
+}
+
+#[test]
+fn json_round_trip_network_overview_dashboard_filters() {
+    check_json_round_trip::<FilterEntryDTO>("filter_entry");
+    check_json_round_trip::<NetworkOverviewDashboardFiltersDTO>("network_overview_dashboard_filters");
+    check_json_round_trip::<NetworkOverviewDashboardFiltersRequestDTO>("network_overview_dashboard_filters_request");
+}
+
+#[test]
+fn json_round_trip_pagination() {
+    check_json_round_trip::<PageInfoDTO>("page_info");
+    check_json_round_trip::<PageRequestDTO>("page_request");
+}
+
+#[test]
+fn json_round_trip_total_http_requests() {
+    check_json_round_trip::<HttpRequestsBucketDTO>("http_requests_bucket");
+    check_json_round_trip::<TotalHttpRequestsDTO>("total_http_requests");
+    check_json_round_trip::<TotalHttpRequestsFiltersDTO>("total_http_requests_filters");
+    check_json_round_trip::<RequestTotalHttpRequestsDTO>("request_total_http_requests");
+}
+
+#[test]
+fn json_field_names_match_ion_field_names() {
+    let filters = NetworkGraphFiltersDTO::new(&["TCP".to_string()], Some(true), &[], None, None, Some(1000));
+    let request = NetworkGraphRequestDTO::new(
+        Timestamp::from_millis(1_700_000_000_000).unwrap(),
+        Timestamp::from_millis(1_700_000_600_000).unwrap(),
+        filters,
+    );
+
+    let json = serde_json::to_value(NetworkGraphRequestDTO::decode(&request.encode())).unwrap();
+    assert_eq!(json, json!({
+        "start_date_time": "2023-11-14T22:13:20Z",
+        "end_date_time": "2023-11-14T22:23:20Z",
+        "filters": {
+            "protocols": ["TCP"],
+            "include_protocols_mode": true,
+            "endpoints": [],
+            "include_endpoints_mode": null,
+            "bytes_lower_bound": null,
+            "bytes_upper_bound": 1000,
+        },
+        "page": null,
+        "bucket_interval": null,
+        "time_zone": null,
+    }));
+}
\ No newline at end of file